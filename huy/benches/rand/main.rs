@@ -1,5 +1,6 @@
 mod float;
 mod integer;
+mod normal;
 mod random;
 mod rng;
 
@@ -0,0 +1,111 @@
+#[divan::bench_group]
+mod normal_f64 {
+    use divan::{counter::ItemsCount, Bencher};
+
+    const SIZE: usize = 1 << 17; // 1MB of f64
+
+    #[divan::bench]
+    fn huy(bencher: Bencher) {
+        use huy::rand::{Normal, Rng};
+
+        bencher
+            .counter(ItemsCount::new(SIZE))
+            .with_inputs(|| {
+                (
+                    Rng::from_random_state(),
+                    Normal::new(1.0, 2.0),
+                    vec![0.0f64; SIZE],
+                )
+            })
+            .bench_local_values(|(mut rng, dist, mut data)| {
+                data.iter_mut().for_each(|x| {
+                    *x = dist.sample(&mut rng);
+                })
+            });
+    }
+
+    #[divan::bench]
+    fn huy_fill(bencher: Bencher) {
+        use huy::rand::{Normal, Rng};
+
+        bencher
+            .counter(ItemsCount::new(SIZE))
+            .with_inputs(|| {
+                (
+                    Rng::from_random_state(),
+                    Normal::new(1.0, 2.0),
+                    vec![0.0f64; SIZE],
+                )
+            })
+            .bench_local_values(|(mut rng, dist, mut data)| dist.fill(&mut rng, &mut data));
+    }
+
+    #[divan::bench]
+    fn rand_distr(bencher: Bencher) {
+        use rand::{rngs::SmallRng, Rng, SeedableRng};
+        use rand_distr::Normal;
+
+        bencher
+            .counter(ItemsCount::new(SIZE))
+            .with_inputs(|| {
+                (
+                    SmallRng::from_os_rng(),
+                    Normal::new(1.0, 2.0).unwrap(),
+                    vec![0.0f64; SIZE],
+                )
+            })
+            .bench_local_values(|(mut rng, dist, mut data)| {
+                data.iter_mut().for_each(|x| {
+                    *x = rng.sample(dist);
+                })
+            });
+    }
+}
+
+#[divan::bench_group]
+mod normal_f32 {
+    use divan::{counter::ItemsCount, Bencher};
+
+    const SIZE: usize = 1 << 18; // 1MB of f32
+
+    #[divan::bench]
+    fn huy(bencher: Bencher) {
+        use huy::rand::{Normal, Rng};
+
+        bencher
+            .counter(ItemsCount::new(SIZE))
+            .with_inputs(|| {
+                (
+                    Rng::from_random_state(),
+                    Normal::new(1.0, 2.0),
+                    vec![0.0f32; SIZE],
+                )
+            })
+            .bench_local_values(|(mut rng, dist, mut data)| {
+                data.iter_mut().for_each(|x| {
+                    *x = dist.sample(&mut rng);
+                })
+            });
+    }
+
+    #[divan::bench]
+    fn rand_distr(bencher: Bencher) {
+        use rand::{rngs::SmallRng, Rng, SeedableRng};
+        use rand_distr::Normal;
+
+        bencher
+            .counter(ItemsCount::new(SIZE))
+            .with_inputs(|| {
+                (
+                    SmallRng::from_os_rng(),
+                    Normal::new(1.0, 2.0).unwrap(),
+                    vec![0.0f32; SIZE],
+                )
+            })
+            .bench_local_values(|(mut rng, dist, mut data)| {
+                data.iter_mut().for_each(|x| {
+                    *x = rng.sample(dist);
+                })
+            });
+    }
+}
@@ -34,3 +34,57 @@ pub mod fill_bytes {
             .bench_local_values(|(mut rng, mut data)| rng.fill_bytes(&mut data[..]));
     }
 }
+
+#[divan::bench_group]
+pub mod next_u64 {
+    use divan::{black_box, Bencher};
+    use huy::rand::{
+        ChaCha20Rng, ChaCha8Rng, Pcg64Dxsm, RandomSource, Rng, SplitMix64, WyRand,
+        Xoshiro128PlusPlus,
+    };
+
+    const SAMPLES: usize = 1024;
+
+    fn bench<R: RandomSource>(bencher: Bencher, mut rng: R) {
+        bencher.bench_local(|| {
+            for _ in 0..SAMPLES {
+                black_box(rng.next_u64());
+            }
+        });
+    }
+
+    #[divan::bench]
+    fn xoshiro256plusplus(bencher: Bencher) {
+        bench(bencher, Rng::seed_from_u64(0x5eed));
+    }
+
+    #[divan::bench]
+    fn xoshiro128plusplus(bencher: Bencher) {
+        bench(bencher, Xoshiro128PlusPlus::seed_from_u64(0x5eed));
+    }
+
+    #[divan::bench]
+    fn pcg64dxsm(bencher: Bencher) {
+        bench(bencher, Pcg64Dxsm::seed_from_u64(0x5eed));
+    }
+
+    #[divan::bench]
+    fn splitmix64(bencher: Bencher) {
+        bench(bencher, SplitMix64::seed_from_u64(0x5eed));
+    }
+
+    #[divan::bench]
+    fn wyrand(bencher: Bencher) {
+        bench(bencher, WyRand::seed_from_u64(0x5eed));
+    }
+
+    #[divan::bench]
+    fn chacha8(bencher: Bencher) {
+        bench(bencher, ChaCha8Rng::seed_from_u64(0x5eed));
+    }
+
+    #[divan::bench]
+    fn chacha20(bencher: Bencher) {
+        bench(bencher, ChaCha20Rng::seed_from_u64(0x5eed));
+    }
+}
@@ -1,12 +1,23 @@
 //! A collection of tools for mathematical computation.
 
-pub use self::{angle::*, complex::*, point2::*, point3::*, traits::*, vector2::*, vector3::*};
+pub use self::{
+    angle::*, complex::*, dual::*, matrix2::*, matrix3::*, matrix4::*, point2::*, point3::*,
+    quaternion::*, traits::*, vector2::*, vector3::*, vector4::*,
+};
+
+pub mod conformance;
 
 mod angle;
 mod complex;
+mod dual;
 mod macros;
+mod matrix2;
+mod matrix3;
+mod matrix4;
 mod point2;
 mod point3;
+mod quaternion;
 mod traits;
 mod vector2;
 mod vector3;
+mod vector4;
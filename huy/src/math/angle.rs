@@ -99,6 +99,12 @@ impl_vector_space! {
             Self::radians(cos.acos())
         }
 
+        /// Simultaneously computes the sine and cosine of the angle.
+        #[inline]
+        pub fn sin_cos(self) -> (T, T) {
+            self.radians.sin_cos()
+        }
+
         /// Computes the secant of the angle.
         #[inline]
         pub fn sec(self) -> T {
@@ -259,7 +265,7 @@ mod tests {
 
                 pub const _sin00: $ty = 0.0;
                 pub const _sin30: $ty = 0.5;
-                pub const _sin45: $ty = 0.70710678118654752440084436210484903928483;
+                pub const _sin45: $ty = core::$ty::consts::FRAC_1_SQRT_2;
                 pub const _sin60: $ty = 0.86602540378443864676372317075293618347140;
                 pub const _sin90: $ty = 1.0;
             }
@@ -356,6 +362,15 @@ mod tests {
                 assert_abs_diff_eq!(c::_deg90.cos(), c::_sin00);
             }
 
+            #[test]
+            fn sin_cos() {
+                use crate::assert_abs_diff_eq;
+
+                let (sin, cos) = c::_deg30.sin_cos();
+                assert_abs_diff_eq!(sin, c::_sin30);
+                assert_abs_diff_eq!(cos, c::_sin60);
+            }
+
             #[test]
             fn acos() {
                 use crate::assert_abs_diff_eq;
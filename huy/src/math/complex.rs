@@ -36,13 +36,15 @@ impl_vector_space! {
         /// See [cis (mathematics)](https://en.wikipedia.org/wiki/Cis_(mathematics)).
         #[inline]
         pub fn cis(arg: Angle<T>) -> Self {
-            Self::new(arg.cos(), arg.sin())
+            let (sin, cos) = arg.sin_cos();
+            Self::new(cos, sin)
         }
 
         /// Creates a new complex number from its polar representation.
         #[inline]
         pub fn from_polar(abs: T, arg: Angle<T>) -> Self {
-            Self::new(abs * arg.cos(), abs * arg.sin())
+            let (sin, cos) = arg.sin_cos();
+            Self::new(abs * cos, abs * sin)
         }
 
         /// Performs a linear interpolation between `self`` and `rhs`.
@@ -93,13 +95,78 @@ impl_multiplicative_group! {
         }
 
         fn div(self, rhs: Self) -> Self {
-            let num = self * rhs.conj();
-            let den = rhs.real * rhs.real + rhs.imag * rhs.imag;
-            num / den
+            robust_div(self, rhs)
         }
     }
 }
 
+/// Computes `x / y` avoiding overflows and underflows of the intermediate values.
+///
+/// See M. Baudin, R. L. Smith, "A Robust Complex Division in Scilab".
+fn robust_div<T: RealField>(x: Complex<T>, y: Complex<T>) -> Complex<T> {
+    let two = T::ONE + T::ONE;
+    let (mut a, mut b) = (x.real, x.imag);
+    let (mut c, mut d) = (y.real, y.imag);
+    let ab = a.abs().max(b.abs());
+    let cd = c.abs().max(d.abs());
+
+    // scale the operands when they are close to the overflow or underflow thresholds
+    let half_max = T::MAX * T::FRAC_1_2;
+    let small = T::MIN_POSITIVE * two / T::EPSILON;
+    let big = two / (T::EPSILON * T::EPSILON);
+    let mut scale = T::ONE;
+    if ab >= half_max {
+        (a, b) = (a * T::FRAC_1_2, b * T::FRAC_1_2);
+        scale = scale * two;
+    }
+    if cd >= half_max {
+        (c, d) = (c * T::FRAC_1_2, d * T::FRAC_1_2);
+        scale = scale * T::FRAC_1_2;
+    }
+    if ab <= small {
+        (a, b) = (a * big, b * big);
+        scale = scale / big;
+    }
+    if cd <= small {
+        (c, d) = (c * big, d * big);
+        scale = scale * big;
+    }
+
+    let (e, f) = if d.abs() <= c.abs() {
+        robust_div_internal(a, b, c, d)
+    } else {
+        let (e, f) = robust_div_internal(b, a, d, c);
+        (e, -f)
+    };
+    Complex::new(e * scale, f * scale)
+}
+
+/// Computes `(a + ib) / (c + id)` assuming `|d| <= |c|`.
+#[inline]
+fn robust_div_internal<T: RealField>(a: T, b: T, c: T, d: T) -> (T, T) {
+    let r = d / c;
+    let t = (c + d * r).recip();
+    if r != T::ZERO {
+        (
+            robust_div_component(a, b, r, t),
+            robust_div_component(b, -a, r, t),
+        )
+    } else {
+        ((a + d * (b / c)) * t, (b - d * (a / c)) * t)
+    }
+}
+
+/// Computes `(a + b r) t`, `r` is not zero.
+#[inline]
+fn robust_div_component<T: RealField>(a: T, b: T, r: T, t: T) -> T {
+    let br = b * r;
+    if br != T::ZERO {
+        (a + br) * t
+    } else {
+        a * t + (b * t) * r
+    }
+}
+
 impl<T: RealField> Add<T> for Complex<T> {
     type Output = Complex<T>;
 
@@ -155,6 +222,394 @@ impl<T: RealField> From<T> for Complex<T> {
     }
 }
 
+impl<T: RealField> Complex<T> {
+    /// Returns `e^(self)`.
+    pub fn exp(self) -> Self {
+        let Self { real: x, imag: y } = self;
+
+        if y.is_exact_zero() {
+            return Self::new(x.exp(), y);
+        }
+        if x.is_infinite() && !y.is_finite() {
+            return if x < T::ZERO {
+                Self::ZERO
+            } else {
+                Self::new(x, T::NAN)
+            };
+        }
+
+        let exp = x.exp();
+        if exp.is_infinite() && x.is_finite() {
+            // the result can be finite even if `e^x` overflows
+            let half = (x * T::FRAC_1_2).exp();
+            return Self::new(half * y.cos() * half, half * y.sin() * half);
+        }
+        Self::new(exp * y.cos(), exp * y.sin())
+    }
+
+    /// Returns the principal value of the natural logarithm.
+    ///
+    /// The branch cut is on the negative real axis, the imaginary part belongs to the
+    /// range [-π, π].
+    #[inline]
+    pub fn ln(self) -> Self {
+        Self::new(self.abs().ln(), self.arg().to_radians())
+    }
+
+    /// Returns the principal value of the square root.
+    ///
+    /// The branch cut is on the negative real axis, the real part is non-negative.
+    pub fn sqrt(self) -> Self {
+        let Self { real: x, imag: y } = self;
+
+        if x.is_exact_zero() && y.is_exact_zero() {
+            return Self::new(T::ZERO, y);
+        }
+        if y.is_infinite() {
+            return Self::new(T::INFINITY, y);
+        }
+        if x.is_nan() {
+            return Self::new(x, x);
+        }
+        if x.is_infinite() {
+            return match (x > T::ZERO, y.is_nan()) {
+                (true, true) => Self::new(x, y),
+                (true, false) => Self::new(x, T::ZERO.copysign(y)),
+                (false, true) => Self::new(y, x),
+                (false, false) => Self::new(T::ZERO, T::INFINITY.copysign(y)),
+            };
+        }
+        if y.is_nan() {
+            return Self::new(y, y);
+        }
+
+        let abs = self.abs();
+        if abs.is_infinite() {
+            // `abs` overflows, scale the argument by 1/4
+            let two = T::ONE + T::ONE;
+            return (self * T::FRAC_1_2 * T::FRAC_1_2).sqrt() * two;
+        }
+
+        let t = ((x.abs() + abs) * T::FRAC_1_2).sqrt();
+        let s = y.abs() * T::FRAC_1_2 / t;
+        if x >= T::ZERO {
+            Self::new(t, s.copysign(y))
+        } else {
+            Self::new(s, t.copysign(y))
+        }
+    }
+
+    /// Raises `self` to a complex power, using the principal value of the logarithm.
+    ///
+    /// Zero raised to zero is one, zero raised to a power with positive real part is
+    /// zero.
+    pub fn powc(self, exp: Self) -> Self {
+        if self == Self::ZERO {
+            if exp == Self::ZERO {
+                return Self::ONE;
+            }
+            if exp.real > T::ZERO {
+                return Self::ZERO;
+            }
+        }
+        (exp * self.ln()).exp()
+    }
+
+    /// Raises `self` to a real power, using the principal value of the logarithm.
+    #[inline]
+    pub fn powf(self, exp: T) -> Self {
+        Self::from_polar(self.abs().powf(exp), self.arg() * exp)
+    }
+
+    /// Raises `self` to an integer power using repeated multiplications.
+    pub fn powi(self, exp: i32) -> Self {
+        let mut base = self;
+        let mut n = exp.unsigned_abs();
+        let mut acc = Self::ONE;
+        while n > 0 {
+            if n & 1 == 1 {
+                acc *= base;
+            }
+            base *= base;
+            n >>= 1;
+        }
+
+        if exp < 0 {
+            Self::ONE / acc
+        } else {
+            acc
+        }
+    }
+
+    /// Computes the sine of the complex number.
+    #[inline]
+    pub fn sin(self) -> Self {
+        // sin(z) = -i sinh(iz)
+        let w = Self::new(-self.imag, self.real).sinh();
+        Self::new(w.imag, -w.real)
+    }
+
+    /// Computes the cosine of the complex number.
+    #[inline]
+    pub fn cos(self) -> Self {
+        // cos(z) = cosh(iz)
+        Self::new(-self.imag, self.real).cosh()
+    }
+
+    /// Computes the tangent of the complex number.
+    #[inline]
+    pub fn tan(self) -> Self {
+        // tan(z) = -i tanh(iz)
+        let w = Self::new(-self.imag, self.real).tanh();
+        Self::new(w.imag, -w.real)
+    }
+
+    /// Computes the hyperbolic sine of the complex number.
+    pub fn sinh(self) -> Self {
+        let Self { real: x, imag: y } = self;
+
+        if y.is_exact_zero() {
+            return Self::new(x.sinh(), y);
+        }
+        if (x == T::ZERO || x.is_infinite()) && !y.is_finite() {
+            return Self::new(x, T::NAN);
+        }
+        Self::new(x.sinh() * y.cos(), x.cosh() * y.sin())
+    }
+
+    /// Computes the hyperbolic cosine of the complex number.
+    pub fn cosh(self) -> Self {
+        let Self { real: x, imag: y } = self;
+
+        if y.is_exact_zero() {
+            return Self::new(x.cosh(), T::ZERO.copysign(x) * y);
+        }
+        if x == T::ZERO && !y.is_finite() {
+            return Self::new(T::NAN, T::ZERO);
+        }
+        if x.is_infinite() && !y.is_finite() {
+            return Self::new(T::INFINITY, T::NAN);
+        }
+        Self::new(x.cosh() * y.cos(), x.sinh() * y.sin())
+    }
+
+    /// Computes the hyperbolic tangent of the complex number.
+    pub fn tanh(self) -> Self {
+        let Self { real: x, imag: y } = self;
+
+        if y.is_exact_zero() {
+            return Self::new(x.tanh(), y);
+        }
+        if x == T::ZERO && !y.is_finite() {
+            return Self::new(x, T::NAN);
+        }
+        if x.is_infinite() {
+            let imag = if y.is_finite() {
+                T::ZERO * y.sin() * y.cos()
+            } else {
+                T::ZERO
+            };
+            return Self::new(T::ONE.copysign(x), imag);
+        }
+
+        // tanh(x + iy) = (sinh(2x) + i sin(2y)) / (cosh(2x) + cos(2y)), rewritten in terms of
+        // e^(-2|x|) to avoid overflows and cancellations
+        let two = T::ONE + T::ONE;
+        let four = two * two;
+        let e = (-two * x.abs()).exp();
+        let m = (-two * x.abs()).exp_m1();
+        let (sin, cos) = (y.sin(), y.cos());
+
+        let den = m * m + four * e * cos * cos;
+        let real = -m * (two + m) / den;
+        let imag = four * e * sin * cos / den;
+        Self::new(real.copysign(x), imag)
+    }
+
+    /// Computes the principal value of the arc-sine of the complex number.
+    ///
+    /// The branch cuts are on the real axis outside the interval [-1, 1].
+    #[inline]
+    pub fn asin(self) -> Self {
+        // asin(z) = -i asinh(iz)
+        let w = Self::new(-self.imag, self.real).asinh();
+        Self::new(w.imag, -w.real)
+    }
+
+    /// Computes the principal value of the arc-cosine of the complex number.
+    ///
+    /// The branch cuts are on the real axis outside the interval [-1, 1].
+    pub fn acos(self) -> Self {
+        let Self { real: x, imag: y } = self;
+        let b = y.abs();
+
+        // the function is computed in the upper half plane, then the conjugation symmetry
+        // is applied
+        let w = if b.is_infinite() {
+            let real = if x.is_nan() {
+                x
+            } else if x.is_infinite() {
+                let quarter = T::FRAC_PI_2 * T::FRAC_1_2;
+                if x > T::ZERO {
+                    quarter
+                } else {
+                    T::PI - quarter
+                }
+            } else {
+                T::FRAC_PI_2
+            };
+            Self::new(real, -T::INFINITY)
+        } else if x.is_infinite() {
+            if b.is_nan() {
+                Self::new(b, -T::INFINITY)
+            } else if x > T::ZERO {
+                Self::new(T::ZERO, -T::INFINITY)
+            } else {
+                Self::new(T::PI, -T::INFINITY)
+            }
+        } else if x.is_nan() {
+            Self::new(x, x)
+        } else if b.is_nan() {
+            Self::new(if x == T::ZERO { T::FRAC_PI_2 } else { b }, b)
+        } else {
+            // W. Kahan, "Branch Cuts for Complex Elementary Functions"
+            let z = Self::new(x, b);
+            let xi = Self::new(T::ONE - z.real, -z.imag).sqrt();
+            let eta = (z + T::ONE).sqrt();
+            Self::new(
+                (T::ONE + T::ONE) * T::atan2(xi.real, eta.real),
+                (eta.real * xi.imag - eta.imag * xi.real).asinh(),
+            )
+        };
+        Self::new(w.real, -w.imag.abs().copysign(y))
+    }
+
+    /// Computes the principal value of the arc-tangent of the complex number.
+    ///
+    /// The branch cuts are on the imaginary axis outside the interval [-i, i].
+    #[inline]
+    pub fn atan(self) -> Self {
+        // atan(z) = -i atanh(iz)
+        let w = Self::new(-self.imag, self.real).atanh();
+        Self::new(w.imag, -w.real)
+    }
+
+    /// Computes the principal value of the inverse hyperbolic sine of the complex number.
+    ///
+    /// The branch cuts are on the imaginary axis outside the interval [-i, i].
+    pub fn asinh(self) -> Self {
+        let Self { real: x, imag: y } = self;
+        let (a, b) = (x.abs(), y.abs());
+
+        // the function is odd and conjugation symmetric, it is computed in the first
+        // quadrant
+        let w = if b.is_infinite() {
+            let imag = if a.is_nan() {
+                a
+            } else if a.is_infinite() {
+                T::FRAC_PI_2 * T::FRAC_1_2
+            } else {
+                T::FRAC_PI_2
+            };
+            Self::new(T::INFINITY, imag)
+        } else if a.is_infinite() {
+            Self::new(a, if b.is_nan() { b } else { T::ZERO })
+        } else if a.is_nan() {
+            Self::new(a, if b == T::ZERO { b } else { a })
+        } else if b.is_nan() {
+            Self::new(b, b)
+        } else {
+            // asinh(z) = i asin(-iz), see W. Kahan, "Branch Cuts for Complex Elementary
+            // Functions"
+            let z = Self::new(b, -a);
+            let xi = Self::new(T::ONE - z.real, -z.imag).sqrt();
+            let eta = (z + T::ONE).sqrt();
+            Self::new(
+                -(xi.real * eta.imag - xi.imag * eta.real).asinh(),
+                T::atan2(z.real, xi.real * eta.real - xi.imag * eta.imag),
+            )
+        };
+        Self::new(w.real.copysign(x), w.imag.copysign(y))
+    }
+
+    /// Computes the principal value of the inverse hyperbolic cosine of the complex
+    /// number.
+    ///
+    /// The branch cut is on the real axis less than 1, the real part is non-negative.
+    pub fn acosh(self) -> Self {
+        let Self { real: x, imag: y } = self;
+        let b = y.abs();
+
+        // the function is computed in the upper half plane, then the conjugation symmetry
+        // is applied
+        let w = if b.is_infinite() {
+            let imag = if x.is_nan() {
+                x
+            } else if x.is_infinite() {
+                let quarter = T::FRAC_PI_2 * T::FRAC_1_2;
+                if x > T::ZERO {
+                    quarter
+                } else {
+                    T::PI - quarter
+                }
+            } else {
+                T::FRAC_PI_2
+            };
+            Self::new(T::INFINITY, imag)
+        } else if x.is_infinite() {
+            let imag = if b.is_nan() {
+                b
+            } else if x > T::ZERO {
+                T::ZERO
+            } else {
+                T::PI
+            };
+            Self::new(T::INFINITY, imag)
+        } else if x.is_nan() || b.is_nan() {
+            Self::new(T::NAN, T::NAN)
+        } else {
+            // W. Kahan, "Branch Cuts for Complex Elementary Functions"
+            let z = Self::new(x, b);
+            let xi = (z - T::ONE).sqrt();
+            let eta = (z + T::ONE).sqrt();
+            Self::new(
+                (xi.real * eta.real + xi.imag * eta.imag).asinh(),
+                (T::ONE + T::ONE) * T::atan2(xi.imag, eta.real),
+            )
+        };
+        Self::new(w.real, w.imag.copysign(y))
+    }
+
+    /// Computes the principal value of the inverse hyperbolic tangent of the complex
+    /// number.
+    ///
+    /// The branch cuts are on the real axis outside the interval [-1, 1].
+    pub fn atanh(self) -> Self {
+        let Self { real: x, imag: y } = self;
+        let (a, b) = (x.abs(), y.abs());
+
+        // the function is odd and conjugation symmetric, it is computed in the first
+        // quadrant
+        let w = if b.is_infinite() {
+            Self::new(T::ZERO, T::FRAC_PI_2)
+        } else if a.is_infinite() {
+            Self::new(T::ZERO, if b.is_nan() { b } else { T::FRAC_PI_2 })
+        } else if a.is_nan() {
+            Self::new(a, a)
+        } else if b.is_nan() {
+            Self::new(if a == T::ZERO { a } else { b }, b)
+        } else {
+            // atanh(z) = 1/4 ln(1 + 4a / ((1 - a)^2 + b^2)) + i/2 atan2(2b, (1 - a)(1 + a) - b^2)
+            let four = (T::ONE + T::ONE) * (T::ONE + T::ONE);
+            let d = T::ONE - a;
+            let real = (a / (d * d + b * b) * four).ln_1p() * T::FRAC_1_2 * T::FRAC_1_2;
+            let imag = T::atan2(b, (d * (T::ONE + a) - b * b) * T::FRAC_1_2) * T::FRAC_1_2;
+            Self::new(real, imag)
+        };
+        Self::new(w.real.copysign(x), w.imag.copysign(y))
+    }
+}
+
 macro_rules! impl_complex_for_float {
     ($float:ty) => {
         impl core::ops::Add<Complex<$float>> for $float {
@@ -198,9 +653,7 @@ macro_rules! impl_complex_for_float {
 
             #[inline]
             fn div(self, rhs: Complex<$float>) -> Self::Output {
-                let num = self * rhs.conj();
-                let den = rhs.real * rhs.real + rhs.imag * rhs.imag;
-                num / den
+                robust_div(Complex::from(self), rhs)
             }
         }
 
@@ -481,6 +934,32 @@ mod tests {
                 );
             }
 
+            #[test]
+            fn div_extreme_magnitudes() {
+                use crate::assert_almost_eq;
+
+                let big = $ty::MAX * 0.5;
+                let tiny = $ty::MIN_POSITIVE;
+
+                assert_almost_eq!(complex(big, big) / complex(big, big), Complex::ONE);
+                assert_almost_eq!(complex(tiny, tiny) / complex(tiny, tiny), Complex::ONE);
+                assert_almost_eq!(complex(big, -big) / complex(big, big), -Complex::I);
+                assert_almost_eq!(complex(tiny, 0.0) / complex(0.0, tiny), -Complex::I);
+                assert_almost_eq!(complex(big, big) / complex(1.0, 1.0), complex(big, 0.0));
+                assert_almost_eq!(
+                    complex(1.0, 1.0) / complex(big, big),
+                    complex(1.0 / big, 0.0)
+                );
+                assert_almost_eq!(
+                    (1.0 as $ty) / complex(big, big),
+                    complex(0.5 / big, -0.5 / big)
+                );
+                assert_almost_eq!(
+                    complex(tiny, tiny) / complex(big, big),
+                    complex(tiny / big, 0.0)
+                );
+            }
+
             #[test]
             fn add_assign() {
                 let mut c = Complex::<$ty>::new(1.0, 2.0);
@@ -586,6 +1065,340 @@ mod tests {
                 );
             }
 
+            #[test]
+            #[allow(clippy::excessive_precision)]
+            fn exp_and_ln() {
+                use crate::assert_almost_eq;
+
+                let z1 = Complex::<$ty>::new(0.5, 0.3);
+                let z2 = Complex::<$ty>::new(-1.5, 2.0);
+                let z3 = Complex::<$ty>::new(3.0, -0.7);
+
+                assert_almost_eq!(z1.exp(), complex(1.5750835902973683, 0.48723045064424825));
+                assert_almost_eq!(z2.exp(), complex(-0.09285491028402633, 0.2028916804701695));
+                assert_almost_eq!(z3.exp(), complex(15.36226599311421, -12.939458143583208));
+
+                assert_almost_eq!(z1.ln(), complex(-0.5394048306859651, 0.5404195002705842));
+                assert_almost_eq!(z2.ln(), complex(0.9162907318741551, 2.214297435588181));
+                assert_almost_eq!(z3.ln(), complex(1.1251193063109182, -0.22923193327699531));
+
+                assert_almost_eq!(z1.ln().exp(), z1);
+                assert_almost_eq!(z2.exp().ln(), z2);
+            }
+
+            #[test]
+            #[allow(clippy::excessive_precision)]
+            fn sqrt() {
+                use crate::assert_almost_eq;
+
+                let z1 = Complex::<$ty>::new(0.5, 0.3);
+                let z2 = Complex::<$ty>::new(-1.5, 2.0);
+                let z3 = Complex::<$ty>::new(3.0, -0.7);
+
+                assert_almost_eq!(z1.sqrt(), complex(0.7358991743046496, 0.2038322711011802));
+                assert_almost_eq!(
+                    z2.sqrt(),
+                    complex(core::$ty::consts::FRAC_1_SQRT_2, core::$ty::consts::SQRT_2)
+                );
+                assert_almost_eq!(z3.sqrt(), complex(1.743643363785994, -0.20072912114323696));
+
+                assert_almost_eq!(z2.sqrt() * z2.sqrt(), z2);
+                assert_eq!(Complex::<$ty>::new(-4.0, 0.0).sqrt(), complex(0.0, 2.0));
+                assert_eq!(Complex::<$ty>::new(-4.0, -0.0).sqrt(), complex(0.0, -2.0));
+            }
+
+            #[test]
+            #[allow(clippy::excessive_precision)]
+            fn trigonometric() {
+                use crate::assert_almost_eq;
+
+                let z1 = Complex::<$ty>::new(0.5, 0.3);
+                let z2 = Complex::<$ty>::new(-1.5, 2.0);
+                let z3 = Complex::<$ty>::new(3.0, -0.7);
+
+                assert_almost_eq!(z1.sin(), complex(0.5011619801599462, 0.2672416992709515));
+                assert_almost_eq!(z2.sin(), complex(-3.752771340479298, 0.2565539560904818));
+                assert_almost_eq!(z3.sin(), complex(0.1771294601911342, 0.7509921728645276));
+
+                assert_almost_eq!(z1.cos(), complex(0.917370851271881, -0.14599480570180629));
+                assert_almost_eq!(z2.cos(), complex(0.26612719531354573, 3.6177750739401375));
+                assert_almost_eq!(z3.cos(), complex(-1.2426078975400758, 0.10705133811767886));
+
+                assert_almost_eq!(z1.tan(), complex(0.48759231649213874, 0.3689103968255638));
+                assert_almost_eq!(z2.tan(), complex(-0.005362060922003057, 1.036920282100185));
+                assert_almost_eq!(z3.tan(), complex(-0.08981334726704182, -0.6121052452475354));
+            }
+
+            #[test]
+            #[allow(clippy::excessive_precision)]
+            fn hyperbolic() {
+                use crate::assert_almost_eq;
+
+                let z1 = Complex::<$ty>::new(0.5, 0.3);
+                let z2 = Complex::<$ty>::new(-1.5, 2.0);
+                let z3 = Complex::<$ty>::new(3.0, -0.7);
+
+                assert_almost_eq!(z1.sinh(), complex(0.4978213596502317, 0.3332362582744821));
+                assert_almost_eq!(z2.sinh(), complex(0.8860929093625314, 2.139040009980677));
+                assert_almost_eq!(z3.sinh(), complex(7.6620933714226265, -6.485765926810749));
+
+                assert_almost_eq!(z1.cosh(), complex(1.0772622306471364, 0.15399419236976608));
+                assert_almost_eq!(z2.cosh(), complex(-0.9789478196465577, -1.936148329510507));
+                assert_almost_eq!(z3.cosh(), complex(7.700172621691585, -6.453692216772459));
+
+                assert_almost_eq!(z1.tanh(), complex(0.4961970657735076, 0.23840508333812332));
+                assert_almost_eq!(z2.tanh(), complex(-1.064144399176537, -0.08039101531016819));
+                assert_almost_eq!(z3.tanh(), complex(0.999145823712599, -0.004881228349369699));
+            }
+
+            #[test]
+            #[allow(clippy::excessive_precision)]
+            fn inverse_trigonometric() {
+                use crate::assert_almost_eq;
+
+                let z1 = Complex::<$ty>::new(0.5, 0.3);
+                let z2 = Complex::<$ty>::new(-1.5, 2.0);
+                let z3 = Complex::<$ty>::new(3.0, -0.7);
+
+                assert_almost_eq!(z1.asin(), complex(0.4930392405856184, 0.3342998177749379));
+                assert_almost_eq!(z2.asin(), complex(-0.6065115181997547, 1.6224941488715938));
+                assert_almost_eq!(z3.asin(), complex(1.3290151565102173, -1.7939996908211533));
+
+                assert_almost_eq!(z1.acos(), complex(1.0777570862092782, -0.3342998177749379));
+                assert_almost_eq!(z2.acos(), complex(2.1773078449946515, -1.6224941488715938));
+                assert_almost_eq!(z3.acos(), complex(0.24178117028467927, 1.7939996908211533));
+
+                assert_almost_eq!(z1.atan(), complex(0.49371165990052035, 0.24094826646478956));
+                assert_almost_eq!(z2.atan(), complex(-1.311223269671635, 0.3104282830771958));
+                assert_almost_eq!(z3.atan(), complex(1.263187496819826, -0.06713070062832574));
+
+                assert_almost_eq!(z1.sin().asin(), z1);
+                assert_almost_eq!(z1.cos().acos(), z1);
+                assert_almost_eq!(z1.tan().atan(), z1);
+            }
+
+            #[test]
+            #[allow(clippy::excessive_precision)]
+            fn inverse_hyperbolic() {
+                use crate::assert_almost_eq;
+
+                let z1 = Complex::<$ty>::new(0.5, 0.3);
+                let z2 = Complex::<$ty>::new(-1.5, 2.0);
+                let z3 = Complex::<$ty>::new(3.0, -0.7);
+
+                assert_almost_eq!(z1.asinh(), complex(0.4979029428302877, 0.26955564142495025));
+                assert_almost_eq!(z2.asinh(), complex(-1.6004100552346137, 0.887765146183905));
+                assert_almost_eq!(z3.asinh(), complex(1.8412691564548724, -0.2183433683782852));
+
+                assert_almost_eq!(z1.acosh(), complex(0.3342998177749379, 1.0777570862092782));
+                assert_almost_eq!(z2.acosh(), complex(1.6224941488715938, 2.1773078449946515));
+                assert_almost_eq!(
+                    z3.acosh(),
+                    complex(1.7939996908211533, -0.24178117028467927)
+                );
+
+                assert_almost_eq!(z1.atanh(), complex(0.48224014768538503, 0.3689075300602324));
+                assert_almost_eq!(
+                    z2.atanh(),
+                    complex(-0.22008968066202295, 1.2452579660726568)
+                );
+                assert_almost_eq!(
+                    z3.atanh(),
+                    complex(0.32522535870433616, -1.4890817503277156)
+                );
+
+                assert_almost_eq!(z1.sinh().asinh(), z1);
+                assert_almost_eq!(z1.cosh().acosh(), z1);
+                assert_almost_eq!(z1.tanh().atanh(), z1);
+            }
+
+            #[test]
+            #[allow(clippy::excessive_precision)]
+            fn pow() {
+                use crate::{assert_almost_eq, assert_relative_eq};
+
+                let z1 = Complex::<$ty>::new(0.5, 0.3);
+                let z2 = Complex::<$ty>::new(-1.5, 2.0);
+                let z3 = Complex::<$ty>::new(3.0, -0.7);
+
+                assert_almost_eq!(z2.powc(z1), complex(0.1526868485122011, 0.7992624472215155));
+                assert_almost_eq!(
+                    z1.powc(z3),
+                    complex(-0.12013101228283003, 0.26329645733308915)
+                );
+                assert_relative_eq!(
+                    z1.powf(-2.5),
+                    complex(0.8396040590013027, -3.7590667418922368),
+                    4.0 * $ty::EPSILON
+                );
+                assert_eq!(z2.powi(5), complex(7.40625, -97.375));
+                assert_almost_eq!(z2.powi(-3), complex(0.059904, -0.022528));
+                assert_eq!(z2.powi(0), Complex::ONE);
+
+                assert_eq!(Complex::<$ty>::ZERO.powc(Complex::ZERO), Complex::ONE);
+                assert_eq!(Complex::<$ty>::ZERO.powc(z1), Complex::ZERO);
+                assert_eq!(Complex::<$ty>::ZERO.powf(2.0), Complex::ZERO);
+            }
+
+            #[test]
+            #[allow(clippy::excessive_precision)]
+            fn branch_cuts() {
+                use crate::assert_almost_eq;
+
+                let pi = core::$ty::consts::PI;
+                let frac_pi_2 = core::$ty::consts::FRAC_PI_2;
+                let acosh_2 = 1.3169578969248166;
+                let atanh_1_2 = 0.5493061443340549;
+
+                assert_eq!(complex(-1.0 as $ty, 0.0).ln(), complex(0.0, pi));
+                assert_eq!(complex(-1.0 as $ty, -0.0).ln(), complex(0.0, -pi));
+
+                assert_almost_eq!(complex(2.0 as $ty, 0.0).asin(), complex(frac_pi_2, acosh_2));
+                assert_almost_eq!(
+                    complex(2.0 as $ty, -0.0).asin(),
+                    complex(frac_pi_2, -acosh_2)
+                );
+                assert_almost_eq!(complex(2.0 as $ty, 0.0).acos(), complex(0.0, -acosh_2));
+                assert_almost_eq!(complex(2.0 as $ty, -0.0).acos(), complex(0.0, acosh_2));
+                assert_almost_eq!(
+                    complex(0.0 as $ty, 2.0).atan(),
+                    complex(frac_pi_2, atanh_1_2)
+                );
+                assert_almost_eq!(
+                    complex(-0.0 as $ty, 2.0).atan(),
+                    complex(-frac_pi_2, atanh_1_2)
+                );
+
+                assert_almost_eq!(
+                    complex(0.0 as $ty, 2.0).asinh(),
+                    complex(acosh_2, frac_pi_2)
+                );
+                assert_almost_eq!(
+                    complex(-0.0 as $ty, 2.0).asinh(),
+                    complex(-acosh_2, frac_pi_2)
+                );
+                assert_almost_eq!(complex(-2.0 as $ty, 0.0).acosh(), complex(acosh_2, pi));
+                assert_almost_eq!(complex(-2.0 as $ty, -0.0).acosh(), complex(acosh_2, -pi));
+                assert_almost_eq!(
+                    complex(2.0 as $ty, 0.0).atanh(),
+                    complex(atanh_1_2, frac_pi_2)
+                );
+                assert_almost_eq!(
+                    complex(2.0 as $ty, -0.0).atanh(),
+                    complex(atanh_1_2, -frac_pi_2)
+                );
+            }
+
+            #[test]
+            fn special_values() {
+                use crate::assert_almost_eq;
+
+                #[track_caller]
+                fn assert_identical(actual: Complex<$ty>, expected: Complex<$ty>) {
+                    let same = |a: $ty, b: $ty| {
+                        (a.is_nan() && b.is_nan())
+                            || (a == b && a.is_sign_negative() == b.is_sign_negative())
+                    };
+                    assert!(
+                        same(actual.real, expected.real) && same(actual.imag, expected.imag),
+                        "{actual:?} is not identical to {expected:?}"
+                    );
+                }
+
+                let inf = $ty::INFINITY;
+                let nan = $ty::NAN;
+                let frac_pi_2 = core::$ty::consts::FRAC_PI_2;
+                let frac_pi_4 = core::$ty::consts::FRAC_PI_4;
+                let pi = core::$ty::consts::PI;
+
+                assert_identical(complex(0.0, 0.0).exp(), complex(1.0, 0.0));
+                assert_identical(complex(-0.0, -0.0).exp(), complex(1.0, -0.0));
+                assert_identical(complex(1.0, inf).exp(), complex(nan, nan));
+                assert_identical(complex(inf, 0.0).exp(), complex(inf, 0.0));
+                assert_identical(complex(-inf, 1.0).exp(), complex(0.0, 0.0));
+                assert_identical(complex(inf, inf).exp(), complex(inf, nan));
+                assert_identical(complex(nan, -0.0).exp(), complex(nan, -0.0));
+                assert_identical(complex(nan, 1.0).exp(), complex(nan, nan));
+                let w = complex($ty::MAX.ln() + 0.25, 0.8).exp();
+                assert!(w.real.is_finite() && w.imag.is_finite());
+                assert_almost_eq!(w.imag / w.real, (0.8 as $ty).tan());
+
+                assert_identical(complex(0.0, 0.0).ln(), complex(-inf, 0.0));
+                assert_identical(complex(-0.0, 0.0).ln(), complex(-inf, pi));
+                assert_identical(complex(1.0, inf).ln(), complex(inf, frac_pi_2));
+                assert_identical(complex(inf, 1.0).ln(), complex(inf, 0.0));
+                assert_identical(complex(inf, inf).ln(), complex(inf, frac_pi_4));
+                assert_identical(complex(-inf, nan).ln(), complex(inf, nan));
+                assert_identical(complex(nan, 1.0).ln(), complex(nan, nan));
+
+                assert_identical(complex(0.0, 0.0).sqrt(), complex(0.0, 0.0));
+                assert_identical(complex(-0.0, -0.0).sqrt(), complex(0.0, -0.0));
+                assert_identical(complex(nan, inf).sqrt(), complex(inf, inf));
+                assert_identical(complex(1.0, -inf).sqrt(), complex(inf, -inf));
+                assert_identical(complex(-inf, 1.0).sqrt(), complex(0.0, inf));
+                assert_identical(complex(inf, -1.0).sqrt(), complex(inf, -0.0));
+                assert_identical(complex(inf, nan).sqrt(), complex(inf, nan));
+                assert_identical(complex(1.0, nan).sqrt(), complex(nan, nan));
+                assert_almost_eq!(
+                    complex($ty::MAX, $ty::MAX).sqrt().abs(),
+                    (2.0 as $ty).sqrt().sqrt() * $ty::MAX.sqrt()
+                );
+
+                assert_identical(complex(0.0, 0.0).sinh(), complex(0.0, 0.0));
+                assert_identical(complex(0.0, inf).sinh(), complex(0.0, nan));
+                assert_identical(complex(inf, 0.0).sinh(), complex(inf, 0.0));
+                assert_identical(complex(inf, -1.0).sinh(), complex(inf, -inf));
+                assert_identical(complex(nan, -0.0).sinh(), complex(nan, -0.0));
+
+                assert_identical(complex(0.0, 0.0).cosh(), complex(1.0, 0.0));
+                assert_identical(complex(-0.0, 0.0).cosh(), complex(1.0, -0.0));
+                assert_identical(complex(inf, 0.0).cosh(), complex(inf, 0.0));
+                assert_identical(complex(inf, inf).cosh(), complex(inf, nan));
+                assert_identical(complex(nan, 0.0).cosh(), complex(nan, 0.0));
+
+                assert_identical(complex(0.0, 0.0).tanh(), complex(0.0, 0.0));
+                assert_identical(complex(-0.0, nan).tanh(), complex(-0.0, nan));
+                assert_identical(complex(inf, 1.0).tanh(), complex(1.0, 0.0));
+                assert_identical(complex(-inf, 2.0).tanh(), complex(-1.0, -0.0));
+                assert_identical(complex(inf, nan).tanh(), complex(1.0, 0.0));
+                assert_identical(complex(1.0, inf).tanh(), complex(nan, nan));
+                assert_identical(complex(nan, -0.0).tanh(), complex(nan, -0.0));
+                assert_identical(complex(800.0, 1.0).tanh(), complex(1.0, 0.0));
+
+                assert_identical(complex(0.0, 0.0).asinh(), complex(0.0, 0.0));
+                assert_identical(complex(-1.0, inf).asinh(), complex(-inf, frac_pi_2));
+                assert_identical(complex(inf, -1.0).asinh(), complex(inf, -0.0));
+                assert_identical(complex(inf, inf).asinh(), complex(inf, frac_pi_4));
+                assert_identical(complex(nan, 0.0).asinh(), complex(nan, 0.0));
+
+                assert_identical(complex(-0.0, 0.0).acosh(), complex(0.0, frac_pi_2));
+                assert_identical(complex(1.0, -inf).acosh(), complex(inf, -frac_pi_2));
+                assert_identical(complex(-inf, 1.0).acosh(), complex(inf, pi));
+                assert_identical(complex(inf, 1.0).acosh(), complex(inf, 0.0));
+                assert_identical(complex(inf, inf).acosh(), complex(inf, frac_pi_4));
+                assert_identical(complex(nan, inf).acosh(), complex(inf, nan));
+
+                assert_identical(complex(0.0, 0.0).atanh(), complex(0.0, 0.0));
+                assert_identical(complex(1.0, 0.0).atanh(), complex(inf, 0.0));
+                assert_identical(complex(-1.0, inf).atanh(), complex(-0.0, frac_pi_2));
+                assert_identical(complex(inf, -1.0).atanh(), complex(0.0, -frac_pi_2));
+                assert_identical(complex(nan, inf).atanh(), complex(0.0, frac_pi_2));
+                assert_identical(complex(0.0, nan).atanh(), complex(0.0, nan));
+
+                assert_identical(complex(0.0, 0.0).acos(), complex(frac_pi_2, -0.0));
+                assert_identical(complex(-0.0, nan).acos(), complex(frac_pi_2, nan));
+                assert_identical(complex(1.0, inf).acos(), complex(frac_pi_2, -inf));
+                assert_identical(complex(inf, -1.0).acos(), complex(0.0, inf));
+                assert_identical(complex(-inf, 1.0).acos(), complex(pi, -inf));
+                assert_identical(complex(inf, inf).acos(), complex(frac_pi_4, -inf));
+
+                assert_identical(complex(0.0, -0.0).asin(), complex(0.0, -0.0));
+                assert_identical(complex(-0.0, 0.0).atan(), complex(-0.0, 0.0));
+                assert_identical(complex(0.0, 0.0).sin(), complex(0.0, 0.0));
+                assert_identical(complex(0.0, -0.0).cos(), complex(1.0, 0.0));
+                assert_identical(complex(-0.0, 0.0).tan(), complex(-0.0, 0.0));
+            }
+
             // ----------------------------------------------------------------
             // almost_eq
             #[test]
@@ -850,5 +1663,55 @@ mod tests {
         fn cast_to_f32() {
             assert_eq!(complex(1.0f64, 2.0f64).to_f32(), complex(1.0f32, 2.0f32));
         }
+
+        #[test]
+        #[allow(clippy::excessive_precision)]
+        fn div_baudin_smith() {
+            use crate::assert_almost_eq;
+
+            // M. Baudin, R. L. Smith, "A Robust Complex Division in Scilab", table 6
+            let p = |n: i32| 2.0f64.powi(n / 2) * 2.0f64.powi(n - n / 2);
+
+            assert_eq!(
+                complex(1.0, 1.0) / complex(1.0, p(1023)),
+                complex(p(-1023), -p(-1023))
+            );
+            assert_eq!(
+                complex(1.0, 1.0) / complex(p(-1023), p(-1023)),
+                complex(p(1023), 0.0)
+            );
+            assert_eq!(
+                complex(p(1023), p(-1023)) / complex(p(677), p(-677)),
+                complex(p(346), -p(-1008))
+            );
+            assert_eq!(
+                complex(p(1023), p(1023)) / complex(1.0, 1.0),
+                complex(p(1023), 0.0)
+            );
+            assert_eq!(
+                complex(p(1020), p(-844)) / complex(p(656), p(-780)),
+                complex(p(364), -p(-1072))
+            );
+            assert_eq!(
+                complex(p(-71), p(1021)) / complex(p(1001), p(-323)),
+                complex(p(-1072), p(20))
+            );
+            assert_almost_eq!(
+                complex(p(-347), p(-54)) / complex(p(-1037), p(-1058)),
+                complex(3.898125604559113300e289, 8.174961907852353577e295)
+            );
+            assert_almost_eq!(
+                complex(p(-1074), p(-1074)) / complex(p(-1073), p(-1074)),
+                complex(0.6, 0.2),
+            );
+            assert_eq!(
+                complex(p(1015), p(-989)) / complex(p(1023), p(1023)),
+                complex(0.001953125, -0.001953125)
+            );
+            assert_almost_eq!(
+                complex(p(-622), p(-1071)) / complex(p(-343), p(-798)),
+                complex(1.02951151789360578e-84, 6.97145987515076231e-220)
+            );
+        }
     }
 }
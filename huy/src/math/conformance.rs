@@ -0,0 +1,226 @@
+//! Conformance checks for implementations of [`RealField`].
+//!
+//! Each function verifies a group of identities that generic code relies on, it
+//! panics if one of them does not hold. The `epsilon` is the relative tolerance used
+//! for the approximate comparisons.
+
+// the identities are checked on purpose with equal operands
+#![allow(clippy::eq_op)]
+
+use core::fmt::Debug;
+
+use super::RealField;
+use crate::{assert_relative_eq, ApproxEq};
+
+/// Checks all the identities required by [`RealField`].
+pub fn check_real_field<T>(epsilon: T::Epsilon)
+where
+    T: RealField + ApproxEq + Debug,
+    T::Epsilon: Debug,
+{
+    check_constants::<T>(epsilon);
+    check_arithmetic::<T>(epsilon);
+    check_trigonometric::<T>(epsilon);
+    check_hyperbolic::<T>(epsilon);
+    check_exponential::<T>(epsilon);
+    check_rounding::<T>();
+    check_special_values::<T>();
+}
+
+/// Checks the values of the constants.
+pub fn check_constants<T>(epsilon: T::Epsilon)
+where
+    T: RealField + ApproxEq + Debug,
+    T::Epsilon: Debug,
+{
+    let two = T::ONE + T::ONE;
+
+    assert_eq!(T::ZERO + T::ZERO, T::ZERO);
+    assert_eq!(T::ONE * T::ONE, T::ONE);
+    assert_eq!(T::FRAC_1_2 * two, T::ONE);
+    assert_relative_eq!(T::FRAC_PI_2 * two, T::PI, epsilon);
+    assert_relative_eq!(T::PI * two, T::TAU, epsilon);
+    assert_relative_eq!(T::ONE.exp(), T::E, epsilon);
+    assert_relative_eq!(two.ln(), T::LN_2, epsilon);
+    assert_relative_eq!(two.sqrt(), T::SQRT_2, epsilon);
+
+    assert!(T::EPSILON > T::ZERO);
+    assert!(T::ONE + T::EPSILON > T::ONE);
+    assert!(T::MIN_POSITIVE > T::ZERO);
+    assert!(T::MAX > T::ONE);
+    assert!(T::INFINITY > T::MAX);
+    assert!(T::NAN.is_nan());
+}
+
+/// Checks the field axioms and the basic arithmetic functions.
+pub fn check_arithmetic<T>(epsilon: T::Epsilon)
+where
+    T: RealField + ApproxEq + Debug,
+    T::Epsilon: Debug,
+{
+    let two = T::ONE + T::ONE;
+    let three = two + T::ONE;
+    let four = two * two;
+    let five = four + T::ONE;
+
+    for x in samples::<T>() {
+        assert_eq!(x + T::ZERO, x);
+        assert_eq!(x * T::ONE, x);
+        assert_eq!(x - x, T::ZERO);
+        assert_eq!(-(-x), x);
+        assert_eq!(x + two, two + x);
+        assert_eq!(x * three, three * x);
+        assert_relative_eq!(x / x, T::ONE, epsilon);
+        assert_relative_eq!(x * x.recip(), T::ONE, epsilon);
+        assert_relative_eq!((x + T::ONE) * two, x * two + two, epsilon);
+        assert_relative_eq!(x.mul_add(three, two), x * three + two, epsilon);
+
+        assert_eq!((-x).abs(), x.abs());
+        assert_eq!(x.signum() * x.abs(), x);
+        assert_eq!(x.abs().copysign(x), x);
+        assert_relative_eq!(x.abs().sqrt() * x.abs().sqrt(), x.abs(), epsilon);
+        assert_relative_eq!(x.cbrt() * x.cbrt() * x.cbrt(), x, epsilon);
+        assert_relative_eq!(x.powi(3), x * x * x, epsilon);
+        assert_relative_eq!(x.abs().powf(two), x * x, epsilon);
+    }
+
+    assert_relative_eq!(T::hypot(three, four), five, epsilon);
+    assert_relative_eq!((-T::ONE).rem_euclid(three), two, epsilon);
+    assert_eq!(T::ONE.min(two), T::ONE);
+    assert_eq!(T::ONE.max(two), two);
+    assert_eq!(five.clamp(T::ONE, two), two);
+    assert_eq!((-five).clamp(T::ONE, two), T::ONE);
+}
+
+/// Checks the trigonometric functions and their inverses.
+pub fn check_trigonometric<T>(epsilon: T::Epsilon)
+where
+    T: RealField + ApproxEq + Debug,
+    T::Epsilon: Debug,
+{
+    let quarter_pi = T::FRAC_PI_2 * T::FRAC_1_2;
+
+    assert_relative_eq!(T::FRAC_PI_2.sin(), T::ONE, epsilon);
+    assert_relative_eq!(T::PI.cos(), -T::ONE, epsilon);
+    assert_relative_eq!(quarter_pi.tan(), T::ONE, epsilon);
+    assert_relative_eq!(T::ONE.atan(), quarter_pi, epsilon);
+    assert_relative_eq!(T::atan2(T::ONE, -T::ONE), T::PI - quarter_pi, epsilon);
+    assert_relative_eq!(T::PI.to_degrees().to_radians(), T::PI, epsilon);
+
+    for x in samples::<T>() {
+        let (sin, cos) = x.sin_cos();
+        assert_eq!(sin, x.sin());
+        assert_eq!(cos, x.cos());
+        assert_relative_eq!(sin * sin + cos * cos, T::ONE, epsilon);
+        assert_relative_eq!(x.tan(), sin / cos, epsilon);
+        assert_relative_eq!((-x).sin(), -sin, epsilon);
+        assert_relative_eq!((-x).cos(), cos, epsilon);
+
+        let y = x * T::FRAC_1_2;
+        assert_relative_eq!(y.sin().asin(), y, epsilon);
+        assert_relative_eq!(y.abs().cos().acos(), y.abs(), epsilon);
+        assert_relative_eq!(y.tan().atan(), y, epsilon);
+        assert_relative_eq!(T::atan2(y.sin(), y.cos()), y, epsilon);
+    }
+}
+
+/// Checks the hyperbolic functions.
+pub fn check_hyperbolic<T>(epsilon: T::Epsilon)
+where
+    T: RealField + ApproxEq + Debug,
+    T::Epsilon: Debug,
+{
+    for x in samples::<T>() {
+        let (sinh, cosh) = (x.sinh(), x.cosh());
+        assert_relative_eq!(cosh + sinh.abs(), x.abs().exp(), epsilon);
+        assert_relative_eq!((-x).cosh(), cosh, epsilon);
+        assert_relative_eq!(x.tanh(), sinh / cosh, epsilon);
+        assert_relative_eq!(sinh.asinh(), x, epsilon);
+        assert_relative_eq!((x.exp() - (-x).exp()) * T::FRAC_1_2, sinh, epsilon);
+    }
+}
+
+/// Checks the exponential and logarithmic functions.
+pub fn check_exponential<T>(epsilon: T::Epsilon)
+where
+    T: RealField + ApproxEq + Debug,
+    T::Epsilon: Debug,
+{
+    let two = T::ONE + T::ONE;
+
+    assert_eq!(T::ZERO.exp(), T::ONE);
+    assert_eq!(T::ONE.ln(), T::ZERO);
+    assert_relative_eq!(two.log2(), T::ONE, epsilon);
+    assert_relative_eq!(T::EPSILON.exp_m1(), T::EPSILON, epsilon);
+    assert_relative_eq!(T::EPSILON.ln_1p(), T::EPSILON, epsilon);
+
+    for x in samples::<T>() {
+        assert_relative_eq!(x.exp().ln(), x, epsilon);
+        assert_relative_eq!(x.exp() * (-x).exp(), T::ONE, epsilon);
+        assert_relative_eq!(x.exp_m1(), x.exp() - T::ONE, epsilon);
+        assert_relative_eq!(x.abs().ln_1p().exp(), x.abs() + T::ONE, epsilon);
+        assert_relative_eq!(x.abs().ln(), x.abs().log2() * T::LN_2, epsilon);
+        assert_relative_eq!(x.abs().powf(x), (x * x.abs().ln()).exp(), epsilon);
+    }
+}
+
+/// Checks the rounding functions.
+pub fn check_rounding<T>()
+where
+    T: RealField + ApproxEq + Debug,
+    T::Epsilon: Debug,
+{
+    let one_half = T::ONE + T::FRAC_1_2;
+    let two = T::ONE + T::ONE;
+
+    assert_eq!(one_half.floor(), T::ONE);
+    assert_eq!(one_half.ceil(), two);
+    assert_eq!(one_half.round(), two);
+    assert_eq!(one_half.trunc(), T::ONE);
+    assert_eq!((-one_half).floor(), -two);
+    assert_eq!((-one_half).ceil(), -T::ONE);
+    assert_eq!((-one_half).round(), -two);
+    assert_eq!((-one_half).trunc(), -T::ONE);
+    assert_eq!(two.floor(), two);
+    assert_eq!(two.ceil(), two);
+}
+
+/// Checks the classification of the non finite values.
+pub fn check_special_values<T>()
+where
+    T: RealField + ApproxEq + Debug,
+    T::Epsilon: Debug,
+{
+    assert!(T::ONE.is_finite() && !T::ONE.is_infinite() && !T::ONE.is_nan());
+    assert!(T::MAX.is_finite() && !T::MAX.is_infinite());
+    assert!(!T::INFINITY.is_finite() && T::INFINITY.is_infinite());
+    assert!((-T::INFINITY).is_infinite() && -T::INFINITY < -T::MAX);
+    assert!(!T::NAN.is_finite() && !T::NAN.is_infinite());
+    assert!(T::NAN != T::NAN);
+    assert!((T::ZERO / T::ZERO).is_nan());
+    assert_eq!(T::ONE / T::ZERO, T::INFINITY);
+    assert_eq!((T::MAX * T::MAX), T::INFINITY);
+    assert_eq!(T::MIN_POSITIVE * T::MIN_POSITIVE, T::ZERO);
+}
+
+/// Returns the sample points used to check the identities, all of them are nonzero.
+fn samples<T: RealField>() -> [T; 6] {
+    let one_half = T::ONE + T::FRAC_1_2;
+    let two = T::ONE + T::ONE;
+    [T::FRAC_1_2, T::ONE, one_half, -T::FRAC_1_2, -two, T::E]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn f32() {
+        check_real_field::<f32>(4.0 * f32::EPSILON);
+    }
+
+    #[test]
+    fn f64() {
+        check_real_field::<f64>(4.0 * f64::EPSILON);
+    }
+}
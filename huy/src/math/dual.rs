@@ -0,0 +1,830 @@
+use core::{
+    cmp::Ordering,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use super::{Matrix3, RealField, Vector3};
+
+/// A dual number, used for the forward-mode automatic differentiation.
+///
+/// The number carries a value and its derivative with respect to a single variable,
+/// the comparisons only take into account the value.
+#[derive(Clone, Copy, Debug)]
+pub struct Dual<T: RealField> {
+    /// The value of the number.
+    pub value: T,
+    /// The derivative of the number.
+    pub deriv: T,
+}
+
+impl<T: RealField> Dual<T> {
+    /// Creates a new dual number from its value and derivative.
+    #[inline]
+    pub const fn new(value: T, deriv: T) -> Self {
+        Self { value, deriv }
+    }
+
+    /// Creates a new constant, its derivative is zero.
+    #[inline]
+    pub const fn constant(value: T) -> Self {
+        Self::new(value, T::ZERO)
+    }
+
+    /// Creates a new independent variable, its derivative is one.
+    #[inline]
+    pub const fn variable(value: T) -> Self {
+        Self::new(value, T::ONE)
+    }
+
+    /// Returns a number with the given value and derivative `a x' + b y'`.
+    #[inline]
+    fn lift(value: T, a: T, x: Self, b: T, y: Self) -> Self {
+        Self::new(value, lin_comb(a, x.deriv, b, y.deriv))
+    }
+
+    /// Returns `true` if the derivative is exactly zero.
+    #[inline]
+    fn is_deriv_exact_zero(&self) -> bool {
+        self.deriv.is_exact_zero()
+    }
+}
+
+/// A dual number carrying the partial derivatives with respect to `N` variables.
+///
+/// The comparisons only take into account the value.
+#[derive(Clone, Copy, Debug)]
+pub struct DualN<T: RealField, const N: usize> {
+    /// The value of the number.
+    pub value: T,
+    /// The partial derivatives of the number.
+    pub deriv: [T; N],
+}
+
+impl<T: RealField, const N: usize> DualN<T, N> {
+    /// Creates a new dual number from its value and partial derivatives.
+    #[inline]
+    pub const fn new(value: T, deriv: [T; N]) -> Self {
+        Self { value, deriv }
+    }
+
+    /// Creates a new constant, all its partial derivatives are zero.
+    #[inline]
+    pub const fn constant(value: T) -> Self {
+        Self::new(value, [T::ZERO; N])
+    }
+
+    /// Creates the independent variable with the given index, its partial derivative
+    /// with respect to itself is one, the others are zero.
+    ///
+    /// Panics if `index` is greater than or equal to `N`.
+    #[inline]
+    pub fn variable(value: T, index: usize) -> Self {
+        let mut deriv = [T::ZERO; N];
+        deriv[index] = T::ONE;
+        Self::new(value, deriv)
+    }
+
+    /// Returns a number with the given value and partial derivatives `a x' + b y'`.
+    #[inline]
+    fn lift(value: T, a: T, x: Self, b: T, y: Self) -> Self {
+        Self::new(
+            value,
+            core::array::from_fn(|i| lin_comb(a, x.deriv[i], b, y.deriv[i])),
+        )
+    }
+
+    /// Returns `true` if all the partial derivatives are exactly zero.
+    #[inline]
+    fn is_deriv_exact_zero(&self) -> bool {
+        self.deriv.iter().all(|x| x.is_exact_zero())
+    }
+}
+
+/// Computes `a da + b db`, the terms with a zero derivative are skipped so that
+/// constants do not propagate infinities or NaN.
+#[inline]
+fn lin_comb<T: RealField>(a: T, da: T, b: T, db: T) -> T {
+    match (da == T::ZERO, db == T::ZERO) {
+        (true, true) => T::ZERO,
+        (true, false) => b * db,
+        (false, true) => a * da,
+        (false, false) => a * da + b * db,
+    }
+}
+
+/// Converts an integer to a real number, without requiring a cast.
+#[inline]
+fn from_i32<T: RealField>(n: i32) -> T {
+    let abs = n.unsigned_abs();
+    let mut m = T::ZERO;
+    for bit in (0..u32::BITS - abs.leading_zeros()).rev() {
+        m = m + m;
+        if abs & (1 << bit) != 0 {
+            m = m + T::ONE;
+        }
+    }
+    if n < 0 {
+        -m
+    } else {
+        m
+    }
+}
+
+macro_rules! impl_dual {
+    ($name:ident<T $(, const $n:ident: usize)?>) => {
+        impl<T: RealField $(, const $n: usize)?> $name<T $(, $n)?> {
+            /// Returns a number with the given value and derivative `factor self'`.
+            #[inline]
+            fn chain(self, value: T, factor: T) -> Self {
+                Self::lift(value, factor, self, T::ZERO, Self::ZERO)
+            }
+        }
+
+        impl<T: RealField $(, const $n: usize)?> PartialEq for $name<T $(, $n)?> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.value == other.value
+            }
+        }
+
+        impl<T: RealField $(, const $n: usize)?> PartialOrd for $name<T $(, $n)?> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                self.value.partial_cmp(&other.value)
+            }
+        }
+
+        impl<T: RealField $(, const $n: usize)?> Neg for $name<T $(, $n)?> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                self.chain(-self.value, -T::ONE)
+            }
+        }
+
+        impl<T: RealField $(, const $n: usize)?> Add for $name<T $(, $n)?> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self::lift(self.value + rhs.value, T::ONE, self, T::ONE, rhs)
+            }
+        }
+
+        impl<T: RealField $(, const $n: usize)?> Sub for $name<T $(, $n)?> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self::lift(self.value - rhs.value, T::ONE, self, -T::ONE, rhs)
+            }
+        }
+
+        impl<T: RealField $(, const $n: usize)?> Mul for $name<T $(, $n)?> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                Self::lift(self.value * rhs.value, rhs.value, self, self.value, rhs)
+            }
+        }
+
+        impl<T: RealField $(, const $n: usize)?> Div for $name<T $(, $n)?> {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: Self) -> Self {
+                let value = self.value / rhs.value;
+                let recip = rhs.value.recip();
+                Self::lift(value, recip, self, -value * recip, rhs)
+            }
+        }
+
+        impl<T: RealField $(, const $n: usize)?> RealField for $name<T $(, $n)?> {
+            const ZERO: Self = Self::constant(T::ZERO);
+            const ONE: Self = Self::constant(T::ONE);
+            const FRAC_1_2: Self = Self::constant(T::FRAC_1_2);
+            const FRAC_PI_2: Self = Self::constant(T::FRAC_PI_2);
+            const PI: Self = Self::constant(T::PI);
+            const TAU: Self = Self::constant(T::TAU);
+            const E: Self = Self::constant(T::E);
+            const LN_2: Self = Self::constant(T::LN_2);
+            const SQRT_2: Self = Self::constant(T::SQRT_2);
+            const EPSILON: Self = Self::constant(T::EPSILON);
+            const MIN_POSITIVE: Self = Self::constant(T::MIN_POSITIVE);
+            const MAX: Self = Self::constant(T::MAX);
+            const INFINITY: Self = Self::constant(T::INFINITY);
+            const NAN: Self = Self::constant(T::NAN);
+
+            #[inline]
+            fn hypot(x: Self, y: Self) -> Self {
+                let value = T::hypot(x.value, y.value);
+                Self::lift(value, x.value / value, x, y.value / value, y)
+            }
+
+            #[inline]
+            fn abs(self) -> Self {
+                self.chain(self.value.abs(), self.value.signum())
+            }
+
+            #[inline]
+            fn copysign(self, sign: Self) -> Self {
+                let value = self.value.copysign(sign.value);
+                let factor = if (value < T::ZERO) == (self.value < T::ZERO) {
+                    T::ONE
+                } else {
+                    -T::ONE
+                };
+                self.chain(value, factor)
+            }
+
+            #[inline]
+            fn signum(self) -> Self {
+                Self::constant(self.value.signum())
+            }
+
+            #[inline]
+            fn is_nan(self) -> bool {
+                self.value.is_nan()
+            }
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                self.value.is_finite()
+            }
+
+            #[inline]
+            fn is_infinite(self) -> bool {
+                self.value.is_infinite()
+            }
+
+            #[inline]
+            fn is_exact_zero(self) -> bool {
+                self.value.is_exact_zero() && self.is_deriv_exact_zero()
+            }
+
+            #[inline]
+            fn to_degrees(self) -> Self {
+                self.chain(self.value.to_degrees(), T::ONE.to_degrees())
+            }
+
+            #[inline]
+            fn to_radians(self) -> Self {
+                self.chain(self.value.to_radians(), T::ONE.to_radians())
+            }
+
+            #[inline]
+            fn sin(self) -> Self {
+                let (sin, cos) = self.value.sin_cos();
+                self.chain(sin, cos)
+            }
+
+            #[inline]
+            fn asin(self) -> Self {
+                let x = self.value;
+                self.chain(x.asin(), ((T::ONE - x) * (T::ONE + x)).sqrt().recip())
+            }
+
+            #[inline]
+            fn cos(self) -> Self {
+                let (sin, cos) = self.value.sin_cos();
+                self.chain(cos, -sin)
+            }
+
+            #[inline]
+            fn acos(self) -> Self {
+                let x = self.value;
+                self.chain(x.acos(), -((T::ONE - x) * (T::ONE + x)).sqrt().recip())
+            }
+
+            #[inline]
+            fn tan(self) -> Self {
+                let tan = self.value.tan();
+                self.chain(tan, T::ONE + tan * tan)
+            }
+
+            #[inline]
+            fn atan(self) -> Self {
+                let x = self.value;
+                self.chain(x.atan(), (T::ONE + x * x).recip())
+            }
+
+            #[inline]
+            fn atan2(y: Self, x: Self) -> Self {
+                let value = T::atan2(y.value, x.value);
+                let r2 = x.value * x.value + y.value * y.value;
+                Self::lift(value, x.value / r2, y, -y.value / r2, x)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                let (sin, cos) = self.value.sin_cos();
+                (self.chain(sin, cos), self.chain(cos, -sin))
+            }
+
+            #[inline]
+            fn sinh(self) -> Self {
+                self.chain(self.value.sinh(), self.value.cosh())
+            }
+
+            #[inline]
+            fn cosh(self) -> Self {
+                self.chain(self.value.cosh(), self.value.sinh())
+            }
+
+            #[inline]
+            fn tanh(self) -> Self {
+                let tanh = self.value.tanh();
+                self.chain(tanh, (T::ONE - tanh) * (T::ONE + tanh))
+            }
+
+            #[inline]
+            fn asinh(self) -> Self {
+                let x = self.value;
+                self.chain(x.asinh(), T::hypot(x, T::ONE).recip())
+            }
+
+            #[inline]
+            fn exp(self) -> Self {
+                let exp = self.value.exp();
+                self.chain(exp, exp)
+            }
+
+            #[inline]
+            fn exp_m1(self) -> Self {
+                self.chain(self.value.exp_m1(), self.value.exp())
+            }
+
+            #[inline]
+            fn ln(self) -> Self {
+                self.chain(self.value.ln(), self.value.recip())
+            }
+
+            #[inline]
+            fn ln_1p(self) -> Self {
+                self.chain(self.value.ln_1p(), (T::ONE + self.value).recip())
+            }
+
+            #[inline]
+            fn log2(self) -> Self {
+                self.chain(self.value.log2(), (self.value * T::LN_2).recip())
+            }
+
+            #[inline]
+            fn powf(self, n: Self) -> Self {
+                let (x, y) = (self.value, n.value);
+                let value = x.powf(y);
+                Self::lift(value, y * x.powf(y - T::ONE), self, value * x.ln(), n)
+            }
+
+            #[inline]
+            fn powi(self, n: i32) -> Self {
+                let x = self.value;
+                let factor = if n == 0 {
+                    T::ZERO
+                } else {
+                    from_i32::<T>(n) * x.powi(n - 1)
+                };
+                self.chain(x.powi(n), factor)
+            }
+
+            #[inline]
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                let value = self.value.mul_add(a.value, b.value);
+                let ax = Self::lift(value, a.value, self, self.value, a);
+                Self::lift(value, T::ONE, ax, T::ONE, b)
+            }
+
+            #[inline]
+            fn rem_euclid(self, rhs: Self) -> Self {
+                let value = self.value.rem_euclid(rhs.value);
+                let quot = ((self.value - value) / rhs.value).round();
+                Self::lift(value, T::ONE, self, -quot, rhs)
+            }
+
+            #[inline]
+            fn recip(self) -> Self {
+                let recip = self.value.recip();
+                self.chain(recip, -recip * recip)
+            }
+
+            #[inline]
+            fn sqrt(self) -> Self {
+                let sqrt = self.value.sqrt();
+                self.chain(sqrt, (sqrt + sqrt).recip())
+            }
+
+            #[inline]
+            fn cbrt(self) -> Self {
+                let cbrt = self.value.cbrt();
+                self.chain(cbrt, (cbrt * cbrt * (T::ONE + T::ONE + T::ONE)).recip())
+            }
+
+            #[inline]
+            fn floor(self) -> Self {
+                Self::constant(self.value.floor())
+            }
+
+            #[inline]
+            fn ceil(self) -> Self {
+                Self::constant(self.value.ceil())
+            }
+
+            #[inline]
+            fn round(self) -> Self {
+                Self::constant(self.value.round())
+            }
+
+            #[inline]
+            fn trunc(self) -> Self {
+                Self::constant(self.value.trunc())
+            }
+
+            #[inline]
+            fn min(self, other: Self) -> Self {
+                if other.value.is_nan() || self.value <= other.value {
+                    self
+                } else {
+                    other
+                }
+            }
+
+            #[inline]
+            fn max(self, other: Self) -> Self {
+                if other.value.is_nan() || self.value >= other.value {
+                    self
+                } else {
+                    other
+                }
+            }
+
+            #[inline]
+            fn clamp(self, min: Self, max: Self) -> Self {
+                assert!(min.value <= max.value, "min > max, or either was NaN");
+                if self.value < min.value {
+                    min
+                } else if self.value > max.value {
+                    max
+                } else {
+                    self
+                }
+            }
+        }
+    };
+}
+
+impl_dual!(Dual<T>);
+impl_dual!(DualN<T, const N: usize>);
+
+impl<T> crate::approx::ApproxEq for Dual<T>
+where
+    T: RealField + crate::approx::ApproxEq,
+{
+    type Epsilon = T::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn almost_eq(&self, other: &Self, max_ulps: usize) -> bool {
+        self.value.almost_eq(&other.value, max_ulps) && self.deriv.almost_eq(&other.deriv, max_ulps)
+    }
+
+    fn almost_ne(&self, other: &Self, max_ulps: usize) -> bool {
+        self.value.almost_ne(&other.value, max_ulps) || self.deriv.almost_ne(&other.deriv, max_ulps)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.value.relative_eq(&other.value, epsilon)
+            && self.deriv.relative_eq(&other.deriv, epsilon)
+    }
+
+    fn relative_ne(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.value.relative_ne(&other.value, epsilon)
+            || self.deriv.relative_ne(&other.deriv, epsilon)
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.value.abs_diff_eq(&other.value, epsilon)
+            && self.deriv.abs_diff_eq(&other.deriv, epsilon)
+    }
+
+    fn abs_diff_ne(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.value.abs_diff_ne(&other.value, epsilon)
+            || self.deriv.abs_diff_ne(&other.deriv, epsilon)
+    }
+}
+
+impl<T, const N: usize> crate::approx::ApproxEq for DualN<T, N>
+where
+    T: RealField + crate::approx::ApproxEq,
+{
+    type Epsilon = T::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn almost_eq(&self, other: &Self, max_ulps: usize) -> bool {
+        self.value.almost_eq(&other.value, max_ulps)
+            && (self.deriv.iter())
+                .zip(&other.deriv)
+                .all(|(a, b)| a.almost_eq(b, max_ulps))
+    }
+
+    fn almost_ne(&self, other: &Self, max_ulps: usize) -> bool {
+        self.value.almost_ne(&other.value, max_ulps)
+            || (self.deriv.iter())
+                .zip(&other.deriv)
+                .any(|(a, b)| a.almost_ne(b, max_ulps))
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.value.relative_eq(&other.value, epsilon)
+            && (self.deriv.iter())
+                .zip(&other.deriv)
+                .all(|(a, b)| a.relative_eq(b, epsilon))
+    }
+
+    fn relative_ne(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.value.relative_ne(&other.value, epsilon)
+            || (self.deriv.iter())
+                .zip(&other.deriv)
+                .any(|(a, b)| a.relative_ne(b, epsilon))
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.value.abs_diff_eq(&other.value, epsilon)
+            && (self.deriv.iter())
+                .zip(&other.deriv)
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+
+    fn abs_diff_ne(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.value.abs_diff_ne(&other.value, epsilon)
+            || (self.deriv.iter())
+                .zip(&other.deriv)
+                .any(|(a, b)| a.abs_diff_ne(b, epsilon))
+    }
+}
+
+/// Computes the derivative of `f` at `x`.
+#[inline]
+pub fn derivative<T, F>(f: F, x: T) -> T
+where
+    T: RealField,
+    F: Fn(Dual<T>) -> Dual<T>,
+{
+    f(Dual::variable(x)).deriv
+}
+
+/// Computes the gradient of the scalar field `f` at `point`.
+pub fn gradient<T, F>(f: F, point: Vector3<T>) -> Vector3<T>
+where
+    T: RealField,
+    F: Fn(Vector3<Dual<T>>) -> Dual<T>,
+{
+    let [dx, dy, dz] = seeds(point).map(|seed| f(seed).deriv);
+    Vector3::new(dx, dy, dz)
+}
+
+/// Computes the jacobian matrix of the vector field `f` at `point`.
+///
+/// The entry in row `i` and column `j` is the partial derivative of the `i`-th
+/// component with respect to the `j`-th variable.
+pub fn jacobian<T, F>(f: F, point: Vector3<T>) -> Matrix3<T>
+where
+    T: RealField,
+    F: Fn(Vector3<Dual<T>>) -> Vector3<Dual<T>>,
+{
+    let [dx, dy, dz] = seeds(point).map(|seed| {
+        let v = f(seed);
+        Vector3::new(v.x.deriv, v.y.deriv, v.z.deriv)
+    });
+    Matrix3::from_cols(dx, dy, dz)
+}
+
+/// Returns the three points used to compute the partial derivatives at `point`.
+#[inline]
+fn seeds<T: RealField>(point: Vector3<T>) -> [Vector3<Dual<T>>; 3] {
+    let (x, y, z) = (point.x, point.y, point.z);
+    [
+        Vector3::new(Dual::variable(x), Dual::constant(y), Dual::constant(z)),
+        Vector3::new(Dual::constant(x), Dual::variable(y), Dual::constant(z)),
+        Vector3::new(Dual::constant(x), Dual::constant(y), Dual::variable(z)),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! test_suite {
+        ($ty:ident) => {
+            use crate::{
+                assert_almost_eq, assert_relative_eq,
+                math::{complex, mat3, vec3, Angle, Complex},
+            };
+
+            #[test]
+            fn constructors() {
+                let x = Dual::<$ty>::variable(2.0);
+                assert_eq!((x.value, x.deriv), (2.0, 1.0));
+                let c = Dual::<$ty>::constant(2.0);
+                assert_eq!((c.value, c.deriv), (2.0, 0.0));
+
+                let y = DualN::<$ty, 3>::variable(2.0, 1);
+                assert_eq!((y.value, y.deriv), (2.0, [0.0, 1.0, 0.0]));
+                let c = DualN::<$ty, 3>::constant(2.0);
+                assert_eq!((c.value, c.deriv), (2.0, [0.0; 3]));
+            }
+
+            #[test]
+            fn comparison_uses_value() {
+                assert_eq!(Dual::<$ty>::new(1.0, 2.0), Dual::new(1.0, 3.0));
+                assert!(Dual::<$ty>::new(1.0, 5.0) < Dual::new(2.0, 0.0));
+                assert_eq!(DualN::<$ty, 2>::new(1.0, [1.0, 2.0]), DualN::constant(1.0));
+            }
+
+            #[test]
+            fn arithmetic() {
+                let x = Dual::<$ty>::variable(3.0);
+                let c = Dual::<$ty>::constant(2.0);
+
+                assert_eq!((x + c).deriv, 1.0);
+                assert_eq!((x - c).deriv, 1.0);
+                assert_eq!((c - x).deriv, -1.0);
+                assert_eq!((x * x).deriv, 6.0);
+                assert_eq!((x * c).deriv, 2.0);
+                assert_almost_eq!((c / x).deriv, -2.0 / 9.0);
+                assert_eq!((-x).deriv, -1.0);
+            }
+
+            #[test]
+            #[allow(clippy::excessive_precision)]
+            fn elementary_functions() {
+                let d = |f: fn(Dual<$ty>) -> Dual<$ty>, x: $ty| derivative(f, x);
+                let x: $ty = 0.3;
+
+                assert_almost_eq!(d(|x| x.sin(), x), x.cos());
+                assert_almost_eq!(d(|x| x.cos(), x), -x.sin());
+                assert_almost_eq!(d(|x| x.tan(), x), 1.0 / (x.cos() * x.cos()));
+                assert_almost_eq!(d(|x| x.asin(), x), 1.0 / (1.0 - x * x).sqrt());
+                assert_almost_eq!(d(|x| x.acos(), x), -1.0 / (1.0 - x * x).sqrt());
+                assert_almost_eq!(d(|x| x.atan(), x), 1.0 / (1.0 + x * x));
+                assert_almost_eq!(d(|x| x.sinh(), x), x.cosh());
+                assert_almost_eq!(d(|x| x.cosh(), x), x.sinh());
+                assert_almost_eq!(d(|x| x.tanh(), x), 1.0 / (x.cosh() * x.cosh()));
+                assert_almost_eq!(d(|x| x.asinh(), x), 1.0 / (1.0 + x * x).sqrt());
+                assert_almost_eq!(d(|x| x.exp(), x), x.exp());
+                assert_almost_eq!(d(|x| x.exp_m1(), x), x.exp());
+                assert_almost_eq!(d(|x| x.ln(), x), 1.0 / x);
+                assert_almost_eq!(d(|x| x.ln_1p(), x), 1.0 / (1.0 + x));
+                assert_almost_eq!(d(|x| x.log2(), x), 1.0 / (x * core::$ty::consts::LN_2));
+                assert_almost_eq!(d(|x| x.sqrt(), x), 0.5 / x.sqrt());
+                assert_almost_eq!(d(|x| x.cbrt(), x), 1.0 / (3.0 * x.cbrt() * x.cbrt()));
+                assert_almost_eq!(d(|x| x.recip(), x), -1.0 / (x * x));
+                assert_almost_eq!(d(|x| x.powi(3), x), 3.0 * x * x);
+                assert_almost_eq!(d(|x| x.powi(-2), x), -2.0 / (x * x * x));
+                assert_eq!(d(|x| x.powi(0), x), 0.0);
+                assert_almost_eq!(d(|x| x.powf(Dual::constant(2.5)), x), 2.5 * x.powf(1.5));
+                assert_almost_eq!(
+                    d(|x| Dual::constant(2.0).powf(x), x),
+                    (2.0 as $ty).powf(x) * core::$ty::consts::LN_2
+                );
+                assert_almost_eq!(d(|x| x.to_degrees(), x), (1.0 as $ty).to_degrees());
+                assert_eq!(d(|x| x.abs(), -x), -1.0);
+                assert_eq!(d(|x| x.copysign(Dual::constant(-1.0)), x), -1.0);
+                assert_eq!(d(|x| x.floor(), x), 0.0);
+                assert_eq!(d(|x| x.signum(), x), 0.0);
+            }
+
+            #[test]
+            fn binary_functions() {
+                let x = Dual::<$ty>::variable(3.0);
+                let c = Dual::<$ty>::constant(4.0);
+
+                assert_almost_eq!(Dual::hypot(x, c).deriv, 0.6);
+                assert_almost_eq!(Dual::atan2(c, x).deriv, -0.16);
+                assert_almost_eq!(Dual::atan2(x, c).deriv, 0.16);
+                assert_eq!(x.mul_add(c, x).deriv, 5.0);
+                assert_eq!(x.rem_euclid(Dual::constant(2.0)).deriv, 1.0);
+                assert_eq!(Dual::constant(7.0).rem_euclid(x).deriv, -2.0);
+                assert_eq!(x.min(c).deriv, 1.0);
+                assert_eq!(x.max(c).deriv, 0.0);
+                assert_eq!(c.clamp(x, x + x).deriv, 0.0);
+                assert_eq!(Dual::constant(1.0).clamp(x, c).deriv, 1.0);
+            }
+
+            #[test]
+            fn constants_do_not_propagate_nan() {
+                let x = Dual::<$ty>::variable(2.0);
+                assert_eq!((x * Dual::INFINITY).deriv, $ty::INFINITY);
+                assert_eq!((x + Dual::constant(0.0).sqrt()).deriv, 1.0);
+                assert_eq!(
+                    Dual::<$ty>::constant(-8.0).powf(Dual::constant(2.0)).deriv,
+                    0.0
+                );
+                assert_eq!(x.powf(Dual::constant(2.0)).deriv, 4.0);
+            }
+
+            #[test]
+            fn multiple_derivatives() {
+                let x = DualN::<$ty, 2>::variable(3.0, 0);
+                let y = DualN::<$ty, 2>::variable(4.0, 1);
+
+                let f = x * x * y + y.sin();
+                assert_eq!(f.value, 36.0 + (4.0 as $ty).sin());
+                assert_almost_eq!(f.deriv[0], 24.0);
+                assert_almost_eq!(f.deriv[1], 9.0 + (4.0 as $ty).cos());
+
+                let r = DualN::hypot(x, y);
+                assert_almost_eq!(r.deriv[0], 0.6);
+                assert_almost_eq!(r.deriv[1], 0.8);
+            }
+
+            #[test]
+            fn gradient_of_norm() {
+                let p = vec3::<$ty>(1.0, 2.0, 2.0);
+                assert_almost_eq!(gradient(|v| v.norm(), p), p.unit());
+                assert_almost_eq!(gradient(|v| v.norm_square(), p), p * 2.0);
+            }
+
+            #[test]
+            fn jacobian_of_cross_product() {
+                let a = vec3::<$ty>(1.0, -2.0, 3.0);
+                let p = vec3::<$ty>(0.5, 4.0, -1.0);
+
+                let ad = vec3(
+                    Dual::constant(a.x),
+                    Dual::constant(a.y),
+                    Dual::constant(a.z),
+                );
+                let expected = mat3(
+                    a.cross(Vector3::X),
+                    a.cross(Vector3::Y),
+                    a.cross(Vector3::Z),
+                );
+                assert_eq!(jacobian(|v| ad.cross(v), p), expected);
+            }
+
+            #[test]
+            fn generic_code() {
+                let angle = |x: Dual<$ty>| Angle::radians(x * x).sin();
+                assert_almost_eq!(derivative(angle, 0.5), (0.25 as $ty).cos());
+
+                let z = |x: Dual<$ty>| {
+                    let z = Complex::new(x, Dual::ONE);
+                    (z * z / z.conj()).real
+                };
+                let w = complex(1.5 as $ty, 1.0);
+                let dw = complex(1.0, 0.0);
+                let expected = (2.0 * w * dw * w.conj() - w * w * dw) / (w.conj() * w.conj());
+                assert_relative_eq!(derivative(z, 1.5), expected.real, 8.0 * $ty::EPSILON);
+            }
+
+            #[test]
+            fn complex_functions_on_real_axis() {
+                // the imaginary part is zero but its derivative is not, the shortcuts for
+                // real arguments must not be taken
+                let f = |g: fn(Complex<Dual<$ty>>) -> Complex<Dual<$ty>>, x: $ty| {
+                    let w = g(Complex::new(Dual::constant(x), Dual::variable(0.0)));
+                    complex(w.real.deriv, w.imag.deriv)
+                };
+
+                // d/dy f(x + iy) = i f'(x + iy)
+                let x = 1.0 as $ty;
+                assert_almost_eq!(f(Complex::exp, x), complex(0.0, x.exp()));
+                assert_almost_eq!(f(Complex::sin, x), complex(0.0, x.cos()));
+                assert_almost_eq!(f(Complex::sqrt, 4.0), complex(0.0, 0.25));
+                assert_almost_eq!(f(Complex::sinh, x), complex(0.0, x.cosh()));
+                assert_almost_eq!(f(Complex::cosh, x), complex(0.0, x.sinh()));
+                assert_almost_eq!(f(Complex::tanh, x), complex(0.0, 1.0 - x.tanh() * x.tanh()));
+                assert_almost_eq!(f(Complex::sqrt, -4.0), complex(0.25, 0.0));
+
+                // the real derivative is still exact
+                let w = Complex::new(Dual::variable(x), Dual::constant(0.0)).exp();
+                assert_almost_eq!(complex(w.real.deriv, w.imag.deriv), complex(x.exp(), 0.0));
+            }
+
+            #[test]
+            fn conformance() {
+                crate::math::conformance::check_real_field::<Dual<$ty>>(4.0 * $ty::EPSILON);
+                crate::math::conformance::check_real_field::<DualN<$ty, 2>>(4.0 * $ty::EPSILON);
+            }
+        };
+    }
+
+    mod f32 {
+        use super::*;
+        test_suite!(f32);
+    }
+
+    mod f64 {
+        use super::*;
+        test_suite!(f64);
+    }
+}
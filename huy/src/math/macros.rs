@@ -219,10 +219,20 @@ macro_rules! impl_vector_norms {
             }
 
             /// Computes the norm of `self`.
+            ///
+            /// The components are scaled by the maximum norm before the accumulation, the
+            /// result does not overflow or underflow unless the norm itself is not
+            /// representable.
             #[inline]
             pub fn norm(self) -> T::Real {
+                if self.is_nan() {
+                    return <T::Real as RealField>::NAN;
+                }
                 let max = self.norm_linf();
-                max * ((self.$x0 / max).abs_square() $(+ (self.$xi / max).abs_square())*).sqrt()
+                if max == <T::Real as Field>::ZERO || max.is_infinite() {
+                    return max;
+                }
+                max * self.scale_by(max).norm_square().sqrt()
             }
 
             /// Compute the taxicab norm of `self`.
@@ -244,21 +254,18 @@ macro_rules! impl_vector_norms {
             /// Returns `self` with norm equal to 1.
             #[inline]
             pub fn unit(self) -> Self {
-                let norm = self.norm();
-                Self {
-                    $x0: self.$x0 / norm,
-                    $($xi: self.$xi / norm,)*
-                }
+                // the largest component of the scaled vector is 1, the norm is computed
+                // without overflows or loss of precision
+                let scaled = self.scale_by(self.norm_linf());
+                scaled.scale_by(scaled.norm_square().sqrt())
             }
 
             /// Returns `self` with norm equal to 1 if possible, else `None`.
             #[inline]
             pub fn try_unit(self) -> Option<Self> {
-                let norm = self.norm();
-                (norm > <T::Real as Field>::ZERO).then(|| Self {
-                    $x0: self.$x0 / norm,
-                    $($xi: self.$xi / norm,)*
-                })
+                let max = self.norm_linf();
+                (!self.is_nan() && max > <T::Real as Field>::ZERO && max.is_finite())
+                    .then(|| self.unit())
             }
 
             /// Returns `self` with norm equal to 1 if possible, else the fallback value.
@@ -273,6 +280,14 @@ macro_rules! impl_vector_norms {
                 self.try_unit().unwrap_or(Self::ZERO)
             }
 
+            /// Divides each component by the real number `s`.
+            #[inline]
+            fn scale_by(self, s: T::Real) -> Self {
+                Self {
+                    $x0: self.$x0 / s,
+                    $($xi: self.$xi / s,)*
+                }
+            }
         }
     };
 }
@@ -388,6 +403,105 @@ macro_rules! impl_vector_ops_for_float {
     };
 }
 
+macro_rules! impl_complex_matrix {
+    (
+        $name:ident { $($xi:ident),* $(,)? }
+    ) => {
+        impl<T: RealField> core::ops::Mul<T> for $name<Complex<T>> {
+            type Output = $name<Complex<T>>;
+
+            #[inline]
+            fn mul(self, rhs: T) -> Self::Output {
+                $name { $($xi: self.$xi * rhs),* }
+            }
+        }
+
+        impl<T: RealField> core::ops::Div<T> for $name<Complex<T>> {
+            type Output = $name<Complex<T>>;
+
+            #[inline]
+            fn div(self, rhs: T) -> Self::Output {
+                $name { $($xi: self.$xi / rhs),* }
+            }
+        }
+
+        impl<T: RealField> core::ops::MulAssign<T> for $name<Complex<T>> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: T) {
+                $(self.$xi = self.$xi * rhs;)*
+            }
+        }
+
+        impl<T: RealField> core::ops::DivAssign<T> for $name<Complex<T>> {
+            #[inline]
+            fn div_assign(&mut self, rhs: T) {
+                $(self.$xi = self.$xi / rhs;)*
+            }
+        }
+
+        impl<T: RealField> $name<Complex<T>> {
+            /// Returns a real matrix with the real part of each entry.
+            #[inline]
+            pub fn real(self) -> $name<T> {
+                $name { $($xi: self.$xi.real()),* }
+            }
+
+            /// Returns a real matrix with the imaginary part of each entry.
+            #[inline]
+            pub fn imag(self) -> $name<T> {
+                $name { $($xi: self.$xi.imag()),* }
+            }
+        }
+
+        impl<T: RealField> $name<T> {
+            /// Construct a new matrix with complex entries from a real one.
+            pub fn to_complex(self) -> $name<Complex<T>> {
+                $name { $($xi: self.$xi.to_complex()),* }
+            }
+        }
+    };
+}
+
+macro_rules! impl_matrix_ops_for_float {
+    (
+        $name:ident { $($xi:ident),* $(,)? }
+    ) => {
+        impl_matrix_ops_for_float!(@cast $name<f32, f64> { $($xi),* });
+        impl_matrix_ops_for_float!(@cast $name<Complex<f32>, Complex<f64>> { $($xi),* });
+    };
+    (
+        $name:ident<RealField> { $($xi:ident),* $(,)? }
+    ) => {
+        impl_matrix_ops_for_float!(@cast $name<f32, f64> { $($xi),* });
+    };
+    (
+        @cast $name:ident<$single:ty, $double:ty> { $($xi:ident),* }
+    ) => {
+        impl $name<$single> {
+            /// Cast to [`f64`].
+            #[inline]
+            pub fn to_f64(self) -> $name<$double> {
+                $name { $($xi: self.$xi.to_f64(),)* }
+            }
+        }
+
+        impl $name<$double> {
+            /// Cast to [`f32`].
+            #[inline]
+            pub fn to_f32(self) -> $name<$single> {
+                $name { $($xi: self.$xi.to_f32(),)* }
+            }
+        }
+
+        impl From<$name<$single>> for $name<$double> {
+            #[inline]
+            fn from(value: $name<$single>) -> Self {
+                value.to_f64()
+            }
+        }
+    };
+}
+
 macro_rules! impl_multiplicative_group {
     (
         impl $name:ident<$field:ident: $trait:ident> {
@@ -451,6 +565,22 @@ macro_rules! impl_multiplicative_group {
 }
 
 macro_rules! impl_aggregate_conversion {
+    (From<[[$fromTy:ident; $n:expr]; $m:expr]> for $name:ident<$field:ident: $trait:ident> { $($xi:ident),+ }) => {
+        impl<$field: $trait> From<[[$fromTy; $n]; $m]> for $name<$field> {
+            #[inline]
+            fn from(value: [[$fromTy; $n]; $m]) -> Self {
+                let [$($xi),+] = value;
+                $name{ $($xi: $xi.into()),+ }
+            }
+        }
+
+        impl<$field: $trait> From<$name<$field>> for [[$fromTy; $n]; $m] {
+            #[inline]
+            fn from(value: $name<$field>) -> Self {
+                [$(value.$xi.into()),+]
+            }
+        }
+    };
     (From<[$fromTy:ident; $n:expr]> for $name:ident<$field:ident: $trait:ident> { $($xi:ident),+ }) => {
         impl<$field: $trait> From<[$fromTy; $n]> for $name<$field> {
             #[inline]
@@ -601,6 +731,60 @@ macro_rules! impl_affine_space {
                 $(self.$xi = self.$xi - rhs.$xi;)*
             }
         }
+
+        impl<$field> $crate::approx::ApproxEq for $name<$field>
+        where
+            $field: $trait + $crate::approx::ApproxEq,
+        {
+            type Epsilon = $field::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> Self::Epsilon {
+                $field::default_epsilon()
+            }
+
+            fn almost_eq(&self, other: &Self, max_ulps: usize) -> bool {
+                if self.is_nan() || other.is_nan() {
+                    return false;
+                }
+                self.$x0.almost_eq(&other.$x0, max_ulps) $(&& self.$xi.almost_eq(&other.$xi, max_ulps))*
+            }
+
+            fn almost_ne(&self, other: &Self, max_ulps: usize) -> bool {
+                if self.is_nan() || other.is_nan() {
+                    return false;
+                }
+                self.$x0.almost_ne(&other.$x0, max_ulps) $(|| self.$xi.almost_ne(&other.$xi, max_ulps))*
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                if self.is_nan() || other.is_nan() {
+                    return false;
+                }
+                self.$x0.relative_eq(&other.$x0, epsilon) $(&& self.$xi.relative_eq(&other.$xi, epsilon))*
+            }
+
+            fn relative_ne(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                if self.is_nan() || other.is_nan() {
+                    return false;
+                }
+                self.$x0.relative_ne(&other.$x0, epsilon) $(|| self.$xi.relative_ne(&other.$xi, epsilon))*
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                if self.is_nan() || other.is_nan() {
+                    return false;
+                }
+                self.$x0.abs_diff_eq(&other.$x0, epsilon) $(&& self.$xi.abs_diff_eq(&other.$xi, epsilon))*
+            }
+
+            fn abs_diff_ne(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                if self.is_nan() || other.is_nan() {
+                    return false;
+                }
+                self.$x0.abs_diff_ne(&other.$x0, epsilon) $(|| self.$xi.abs_diff_ne(&other.$xi, epsilon))*
+            }
+        }
     };
 }
 
@@ -636,7 +820,9 @@ macro_rules! impl_affine_space_ops_for_float {
 pub(super) use impl_affine_space;
 pub(super) use impl_affine_space_ops_for_float;
 pub(super) use impl_aggregate_conversion;
+pub(super) use impl_complex_matrix;
 pub(super) use impl_complex_vector;
+pub(super) use impl_matrix_ops_for_float;
 pub(super) use impl_multiplicative_group;
 pub(super) use impl_vector_norms;
 pub(super) use impl_vector_ops_for_float;
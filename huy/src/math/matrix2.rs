@@ -0,0 +1,433 @@
+use core::ops::Mul;
+
+use super::{macros::*, Complex, Field, RealField, Vector2};
+
+/// Create a new [`Matrix2`] from its columns.
+#[inline]
+pub const fn mat2<T: Field>(x_axis: Vector2<T>, y_axis: Vector2<T>) -> Matrix2<T> {
+    Matrix2::from_cols(x_axis, y_axis)
+}
+
+impl_vector_space! {
+    /// A 2x2 matrix, stored in column-major order.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[repr(C)]
+    pub struct Matrix2<T: Field> {
+        /// The first column of the matrix.
+        pub(super) x_axis: Vector2<T>,
+        /// The second column of the matrix.
+        pub(super) y_axis: Vector2<T>,
+    }
+
+    impl<T: Field> Matrix2<T> {
+        /// Construct a new matrix from its entries, given in row-major order.
+        #[inline]
+        pub const fn new(m00: T, m01: T, m10: T, m11: T) -> Self {
+            Self::from_cols(Vector2::new(m00, m10), Vector2::new(m01, m11))
+        }
+
+        /// Construct a new matrix from its columns.
+        #[inline]
+        pub const fn from_cols(x_axis: Vector2<T>, y_axis: Vector2<T>) -> Self {
+            Self { x_axis, y_axis }
+        }
+
+        /// Construct a new matrix from its rows.
+        #[inline]
+        pub const fn from_rows(r0: Vector2<T>, r1: Vector2<T>) -> Self {
+            Self::new(r0.x, r0.y, r1.x, r1.y)
+        }
+
+        /// Construct a new diagonal matrix.
+        #[inline]
+        pub const fn from_diagonal(diagonal: Vector2<T>) -> Self {
+            Self::new(diagonal.x, T::ZERO, T::ZERO, diagonal.y)
+        }
+
+        /// Returns the column with the given index.
+        ///
+        /// Panics if `index` is greater than 1.
+        #[inline]
+        pub fn col(self, index: usize) -> Vector2<T> {
+            match index {
+                0 => self.x_axis,
+                1 => self.y_axis,
+                _ => panic!("index out of bounds: {index}"),
+            }
+        }
+
+        /// Returns the row with the given index.
+        ///
+        /// Panics if `index` is greater than 1.
+        #[inline]
+        pub fn row(self, index: usize) -> Vector2<T> {
+            match index {
+                0 => Vector2::new(self.x_axis.x, self.y_axis.x),
+                1 => Vector2::new(self.x_axis.y, self.y_axis.y),
+                _ => panic!("index out of bounds: {index}"),
+            }
+        }
+
+        /// Returns the diagonal of the matrix.
+        #[inline]
+        pub fn diagonal(self) -> Vector2<T> {
+            Vector2::new(self.x_axis.x, self.y_axis.y)
+        }
+
+        /// Returns the transpose of the matrix.
+        #[inline]
+        pub fn transpose(self) -> Self {
+            Self::from_rows(self.x_axis, self.y_axis)
+        }
+
+        /// Returns the conjugate transpose of the matrix.
+        #[inline]
+        pub fn conj_transpose(self) -> Self {
+            Self::new(
+                self.x_axis.x.conj(),
+                self.x_axis.y.conj(),
+                self.y_axis.x.conj(),
+                self.y_axis.y.conj(),
+            )
+        }
+
+        /// Computes the determinant of the matrix.
+        #[inline]
+        pub fn determinant(self) -> T {
+            self.x_axis.x * self.y_axis.y - self.y_axis.x * self.x_axis.y
+        }
+
+        /// Computes the trace of the matrix.
+        #[inline]
+        pub fn trace(self) -> T {
+            self.x_axis.x + self.y_axis.y
+        }
+
+        /// Returns the inverse of the matrix.
+        ///
+        /// If the matrix is singular the entries of the result are not finite.
+        #[inline]
+        pub fn inverse(self) -> Self {
+            self.adjugate() / self.determinant()
+        }
+
+        /// Returns the inverse of the matrix if it is not singular, else `None`.
+        #[inline]
+        pub fn try_inverse(self) -> Option<Self> {
+            let det = self.determinant();
+            (det.abs() > <T::Real as Field>::ZERO).then(|| self.adjugate() / det)
+        }
+
+        /// Returns the transpose of the cofactor matrix.
+        #[inline]
+        fn adjugate(self) -> Self {
+            Self::new(self.y_axis.y, -self.y_axis.x, -self.x_axis.y, self.x_axis.x)
+        }
+    }
+}
+
+impl_multiplicative_group! {
+    impl Matrix2<T: Field> {
+        /// The multiplicative identity element.
+        pub const IDENTITY: Self = Self::from_cols(Vector2::X, Vector2::Y);
+
+        fn mul(self, rhs: Self) -> Self {
+            Matrix2 {
+                x_axis: self * rhs.x_axis,
+                y_axis: self * rhs.y_axis,
+            }
+        }
+    }
+}
+
+impl<T: Field> Mul<Vector2<T>> for Matrix2<T> {
+    type Output = Vector2<T>;
+
+    #[inline]
+    fn mul(self, rhs: Vector2<T>) -> Self::Output {
+        self.x_axis * rhs.x + self.y_axis * rhs.y
+    }
+}
+
+impl_complex_matrix! { Matrix2 { x_axis, y_axis } }
+impl_matrix_ops_for_float! { Matrix2 { x_axis, y_axis } }
+
+impl_aggregate_conversion!(From<[[T; 2]; 2]> for Matrix2<T: Field> { x_axis, y_axis });
+
+#[cfg(test)]
+mod tests {
+    use super::{super::vec2, *};
+
+    macro_rules! real_test_suite {
+        ($ty:ty) => {
+            use crate::*;
+
+            mod c {
+                #![allow(clippy::excessive_precision)]
+                #![allow(non_upper_case_globals)]
+
+                use super::*;
+
+                pub const _zero: Matrix2<$ty> = Matrix2::ZERO;
+
+                pub const _m1: Matrix2<$ty> = Matrix2::new(1.0, 2.0, 3.0, 4.0);
+                pub const _m2: Matrix2<$ty> = Matrix2::new(5.0, 6.0, 7.0, 8.0);
+                pub const _singular: Matrix2<$ty> = Matrix2::new(1.0, 2.0, 2.0, 4.0);
+            }
+
+            #[test]
+            fn consts() {
+                assert_eq!(Matrix2::<$ty>::ZERO, Matrix2::new(0.0, 0.0, 0.0, 0.0));
+                assert_eq!(Matrix2::<$ty>::IDENTITY, Matrix2::new(1.0, 0.0, 0.0, 1.0));
+            }
+
+            #[test]
+            fn constructors() {
+                let x_axis = vec2::<$ty>(1.0, 3.0);
+                let y_axis = vec2::<$ty>(2.0, 4.0);
+
+                assert_eq!(mat2(x_axis, y_axis), c::_m1);
+                assert_eq!(Matrix2::from_cols(x_axis, y_axis), c::_m1);
+                assert_eq!(Matrix2::from_rows(vec2(1.0, 2.0), vec2(3.0, 4.0)), c::_m1);
+                assert_eq!(
+                    Matrix2::from_diagonal(vec2::<$ty>(2.0, 3.0)),
+                    Matrix2::new(2.0, 0.0, 0.0, 3.0)
+                );
+            }
+
+            #[test]
+            fn cols_and_rows() {
+                assert_eq!(c::_m1.col(0), vec2(1.0, 3.0));
+                assert_eq!(c::_m1.col(1), vec2(2.0, 4.0));
+                assert_eq!(c::_m1.row(0), vec2(1.0, 2.0));
+                assert_eq!(c::_m1.row(1), vec2(3.0, 4.0));
+                assert_eq!(c::_m1.diagonal(), vec2(1.0, 4.0));
+            }
+
+            #[test]
+            #[should_panic]
+            fn col_out_of_bounds() {
+                c::_m1.col(2);
+            }
+
+            #[test]
+            fn add_and_sub() {
+                assert_eq!(c::_m1 + c::_m2, Matrix2::new(6.0, 8.0, 10.0, 12.0));
+                assert_eq!(c::_m2 - c::_m1, Matrix2::new(4.0, 4.0, 4.0, 4.0));
+                assert_eq!(-c::_m1, Matrix2::new(-1.0, -2.0, -3.0, -4.0));
+            }
+
+            #[test]
+            fn mul_and_div_by_scalar() {
+                assert_eq!(c::_m1 * 2.0, Matrix2::new(2.0, 4.0, 6.0, 8.0));
+                assert_eq!(c::_m1 / 2.0, Matrix2::new(0.5, 1.0, 1.5, 2.0));
+            }
+
+            #[test]
+            fn mul_vector() {
+                assert_eq!(c::_m1 * vec2(5.0, 6.0), vec2(17.0, 39.0));
+                assert_eq!(Matrix2::IDENTITY * vec2::<$ty>(5.0, 6.0), vec2(5.0, 6.0));
+            }
+
+            #[test]
+            fn mul_matrix() {
+                assert_eq!(c::_m1 * c::_m2, Matrix2::new(19.0, 22.0, 43.0, 50.0));
+                assert_eq!(c::_m1 * Matrix2::IDENTITY, c::_m1);
+                assert_eq!(Matrix2::IDENTITY * c::_m1, c::_m1);
+
+                let mut m = c::_m1;
+                m *= c::_m2;
+                assert_eq!(m, c::_m1 * c::_m2);
+
+                assert_eq!(
+                    [c::_m1, c::_m2].into_iter().product::<Matrix2<$ty>>(),
+                    c::_m1 * c::_m2
+                );
+            }
+
+            #[test]
+            fn transpose() {
+                assert_eq!(c::_m1.transpose(), Matrix2::new(1.0, 3.0, 2.0, 4.0));
+                assert_eq!(c::_m1.conj_transpose(), c::_m1.transpose());
+                assert_eq!(c::_m1.transpose().transpose(), c::_m1);
+            }
+
+            #[test]
+            fn determinant_and_trace() {
+                assert_eq!(c::_m1.determinant(), -2.0);
+                assert_eq!(c::_singular.determinant(), 0.0);
+                assert_eq!(c::_m1.trace(), 5.0);
+            }
+
+            #[test]
+            fn inverse() {
+                assert_almost_eq!(c::_m1.inverse(), Matrix2::new(-2.0, 1.0, 1.5, -0.5));
+                assert_abs_diff_eq!(c::_m1 * c::_m1.inverse(), Matrix2::IDENTITY);
+
+                assert!(c::_m1.try_inverse().is_some());
+                assert!(c::_singular.try_inverse().is_none());
+                assert!(c::_zero.try_inverse().is_none());
+            }
+
+            #[test]
+            fn array_conversion() {
+                let a: [[$ty; 2]; 2] = [[1.0, 3.0], [2.0, 4.0]];
+
+                assert_eq!(a, <[[$ty; 2]; 2]>::from(c::_m1));
+                assert_eq!(c::_m1, Matrix2::from(a));
+            }
+        };
+    }
+
+    mod f32 {
+        use super::*;
+        real_test_suite!(f32);
+
+        #[test]
+        fn to_f64() {
+            let m_f32 = Matrix2::<f32>::new(1.0, 2.0, 3.0, 4.0);
+            let m_f64 = Matrix2::<f64>::new(1.0, 2.0, 3.0, 4.0);
+
+            assert_eq!(m_f32.to_f64(), m_f64);
+            assert_eq!(Matrix2::<f64>::from(m_f32), m_f64);
+        }
+    }
+
+    mod f64 {
+        use super::*;
+        real_test_suite!(f64);
+
+        #[test]
+        fn to_f32() {
+            let m_f32 = Matrix2::<f32>::new(1.0, 2.0, 3.0, 4.0);
+            let m_f64 = Matrix2::<f64>::new(1.0, 2.0, 3.0, 4.0);
+
+            assert_eq!(m_f64.to_f32(), m_f32);
+        }
+    }
+
+    macro_rules! complex_test_suite {
+        ($ty:ty) => {
+            use crate::*;
+
+            mod c {
+                #![allow(clippy::excessive_precision)]
+                #![allow(non_upper_case_globals)]
+
+                use super::*;
+
+                pub const _m1: Matrix2<Complex<$ty>> = Matrix2::new(
+                    complex(1.0, 1.0),
+                    complex(2.0, 0.0),
+                    complex(0.0, 3.0),
+                    complex(4.0, -1.0),
+                );
+            }
+
+            #[test]
+            fn conj_transpose() {
+                let expected = Matrix2::new(
+                    complex(1.0, -1.0),
+                    complex(0.0, -3.0),
+                    complex(2.0, 0.0),
+                    complex(4.0, 1.0),
+                );
+                assert_eq!(c::_m1.conj_transpose(), expected);
+            }
+
+            #[test]
+            fn determinant_and_trace() {
+                assert_almost_eq!(c::_m1.determinant(), complex(5.0, -3.0));
+                assert_almost_eq!(c::_m1.trace(), complex(5.0, 0.0));
+            }
+
+            #[test]
+            fn inverse() {
+                assert_abs_diff_eq!(c::_m1 * c::_m1.inverse(), Matrix2::IDENTITY);
+                assert!(c::_m1.try_inverse().is_some());
+                assert!(Matrix2::<Complex<$ty>>::ZERO.try_inverse().is_none());
+            }
+
+            #[test]
+            fn mul_and_div_by_real() {
+                let expected = Matrix2::new(
+                    complex(2.0, 2.0),
+                    complex(4.0, 0.0),
+                    complex(0.0, 6.0),
+                    complex(8.0, -2.0),
+                );
+                assert_eq!(c::_m1 * 2.0, expected);
+                assert_eq!(expected / 2.0, c::_m1);
+            }
+
+            #[test]
+            fn real_and_imag() {
+                assert_eq!(c::_m1.real(), Matrix2::<$ty>::new(1.0, 2.0, 0.0, 4.0));
+                assert_eq!(c::_m1.imag(), Matrix2::<$ty>::new(1.0, 0.0, 3.0, -1.0));
+            }
+
+            #[test]
+            fn to_complex() {
+                let m = Matrix2::<$ty>::new(1.0, 2.0, 3.0, 4.0);
+                let c = Matrix2::new(
+                    complex(1.0, 0.0),
+                    complex(2.0, 0.0),
+                    complex(3.0, 0.0),
+                    complex(4.0, 0.0),
+                );
+                assert_eq!(m.to_complex(), c);
+            }
+        };
+    }
+
+    mod c32 {
+        use crate::math::complex;
+
+        use super::*;
+        complex_test_suite!(f32);
+
+        #[test]
+        fn to_f64() {
+            let m32 = Matrix2::new(
+                complex(1.0f32, 2.0),
+                Complex::ZERO,
+                Complex::ZERO,
+                Complex::ONE,
+            );
+            let m64 = Matrix2::new(
+                complex(1.0f64, 2.0),
+                Complex::ZERO,
+                Complex::ZERO,
+                Complex::ONE,
+            );
+
+            assert_eq!(m32.to_f64(), m64);
+            assert_eq!(Matrix2::<Complex<f64>>::from(m32), m64);
+        }
+    }
+
+    mod c64 {
+        use crate::math::complex;
+
+        use super::*;
+        complex_test_suite!(f64);
+
+        #[test]
+        fn to_f32() {
+            let m32 = Matrix2::new(
+                complex(1.0f32, 2.0),
+                Complex::ZERO,
+                Complex::ZERO,
+                Complex::ONE,
+            );
+            let m64 = Matrix2::new(
+                complex(1.0f64, 2.0),
+                Complex::ZERO,
+                Complex::ZERO,
+                Complex::ONE,
+            );
+
+            assert_eq!(m64.to_f32(), m32);
+        }
+    }
+}
@@ -0,0 +1,509 @@
+use core::ops::Mul;
+
+use super::{macros::*, Angle, Complex, Field, RealField, Vector3};
+
+/// Create a new [`Matrix3`] from its columns.
+#[inline]
+pub const fn mat3<T: Field>(
+    x_axis: Vector3<T>,
+    y_axis: Vector3<T>,
+    z_axis: Vector3<T>,
+) -> Matrix3<T> {
+    Matrix3::from_cols(x_axis, y_axis, z_axis)
+}
+
+impl_vector_space! {
+    /// A 3x3 matrix, stored in column-major order.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[repr(C)]
+    pub struct Matrix3<T: Field> {
+        /// The first column of the matrix.
+        pub(super) x_axis: Vector3<T>,
+        /// The second column of the matrix.
+        pub(super) y_axis: Vector3<T>,
+        /// The third column of the matrix.
+        pub(super) z_axis: Vector3<T>,
+    }
+
+    impl<T: Field> Matrix3<T> {
+        /// Construct a new matrix from its entries, given in row-major order.
+        #[inline]
+        #[allow(clippy::too_many_arguments)]
+        pub const fn new(
+            m00: T, m01: T, m02: T,
+            m10: T, m11: T, m12: T,
+            m20: T, m21: T, m22: T,
+        ) -> Self {
+            Self::from_cols(
+                Vector3::new(m00, m10, m20),
+                Vector3::new(m01, m11, m21),
+                Vector3::new(m02, m12, m22),
+            )
+        }
+
+        /// Construct a new matrix from its columns.
+        #[inline]
+        pub const fn from_cols(x_axis: Vector3<T>, y_axis: Vector3<T>, z_axis: Vector3<T>) -> Self {
+            Self { x_axis, y_axis, z_axis }
+        }
+
+        /// Construct a new matrix from its rows.
+        #[inline]
+        pub const fn from_rows(r0: Vector3<T>, r1: Vector3<T>, r2: Vector3<T>) -> Self {
+            Self::new(
+                r0.x, r0.y, r0.z,
+                r1.x, r1.y, r1.z,
+                r2.x, r2.y, r2.z,
+            )
+        }
+
+        /// Construct a new diagonal matrix.
+        #[inline]
+        pub const fn from_diagonal(diagonal: Vector3<T>) -> Self {
+            Self::new(
+                diagonal.x, T::ZERO, T::ZERO,
+                T::ZERO, diagonal.y, T::ZERO,
+                T::ZERO, T::ZERO, diagonal.z,
+            )
+        }
+
+        /// Returns the column with the given index.
+        ///
+        /// Panics if `index` is greater than 2.
+        #[inline]
+        pub fn col(self, index: usize) -> Vector3<T> {
+            match index {
+                0 => self.x_axis,
+                1 => self.y_axis,
+                2 => self.z_axis,
+                _ => panic!("index out of bounds: {index}"),
+            }
+        }
+
+        /// Returns the row with the given index.
+        ///
+        /// Panics if `index` is greater than 2.
+        #[inline]
+        pub fn row(self, index: usize) -> Vector3<T> {
+            match index {
+                0 => Vector3::new(self.x_axis.x, self.y_axis.x, self.z_axis.x),
+                1 => Vector3::new(self.x_axis.y, self.y_axis.y, self.z_axis.y),
+                2 => Vector3::new(self.x_axis.z, self.y_axis.z, self.z_axis.z),
+                _ => panic!("index out of bounds: {index}"),
+            }
+        }
+
+        /// Returns the diagonal of the matrix.
+        #[inline]
+        pub fn diagonal(self) -> Vector3<T> {
+            Vector3::new(self.x_axis.x, self.y_axis.y, self.z_axis.z)
+        }
+
+        /// Returns the transpose of the matrix.
+        #[inline]
+        pub fn transpose(self) -> Self {
+            Self::from_rows(self.x_axis, self.y_axis, self.z_axis)
+        }
+
+        /// Returns the conjugate transpose of the matrix.
+        #[inline]
+        pub fn conj_transpose(self) -> Self {
+            let Self { x_axis, y_axis, z_axis } = self;
+            Self::new(
+                x_axis.x.conj(), x_axis.y.conj(), x_axis.z.conj(),
+                y_axis.x.conj(), y_axis.y.conj(), y_axis.z.conj(),
+                z_axis.x.conj(), z_axis.y.conj(), z_axis.z.conj(),
+            )
+        }
+
+        /// Computes the determinant of the matrix.
+        #[inline]
+        pub fn determinant(self) -> T {
+            let cross = self.y_axis.cross(self.z_axis);
+            self.x_axis.x * cross.x + self.x_axis.y * cross.y + self.x_axis.z * cross.z
+        }
+
+        /// Computes the trace of the matrix.
+        #[inline]
+        pub fn trace(self) -> T {
+            self.x_axis.x + self.y_axis.y + self.z_axis.z
+        }
+
+        /// Returns the inverse of the matrix.
+        ///
+        /// If the matrix is singular the entries of the result are not finite.
+        #[inline]
+        pub fn inverse(self) -> Self {
+            self.adjugate() / self.determinant()
+        }
+
+        /// Returns the inverse of the matrix if it is not singular, else `None`.
+        #[inline]
+        pub fn try_inverse(self) -> Option<Self> {
+            let det = self.determinant();
+            (det.abs() > <T::Real as Field>::ZERO).then(|| self.adjugate() / det)
+        }
+
+        /// Returns the transpose of the cofactor matrix.
+        #[inline]
+        fn adjugate(self) -> Self {
+            Self::from_rows(
+                self.y_axis.cross(self.z_axis),
+                self.z_axis.cross(self.x_axis),
+                self.x_axis.cross(self.y_axis),
+            )
+        }
+    }
+}
+
+impl_multiplicative_group! {
+    impl Matrix3<T: Field> {
+        /// The multiplicative identity element.
+        pub const IDENTITY: Self = Self::from_cols(Vector3::X, Vector3::Y, Vector3::Z);
+
+        fn mul(self, rhs: Self) -> Self {
+            Matrix3 {
+                x_axis: self * rhs.x_axis,
+                y_axis: self * rhs.y_axis,
+                z_axis: self * rhs.z_axis,
+            }
+        }
+    }
+}
+
+impl<T: Field> Mul<Vector3<T>> for Matrix3<T> {
+    type Output = Vector3<T>;
+
+    #[inline]
+    fn mul(self, rhs: Vector3<T>) -> Self::Output {
+        self.x_axis * rhs.x + self.y_axis * rhs.y + self.z_axis * rhs.z
+    }
+}
+
+impl<T: RealField> Matrix3<T> {
+    /// Creates a rotation matrix around `axis` by the given `angle`.
+    ///
+    /// The axis is normalized before computing the rotation.
+    pub fn from_axis_angle(axis: Vector3<T>, angle: Angle<T>) -> Self {
+        let Vector3 { x, y, z } = axis.unit();
+        let sin = angle.sin();
+        let cos = angle.cos();
+        let t = T::ONE - cos;
+
+        Self::from_rows(
+            Vector3::new(t * x * x + cos, t * x * y - sin * z, t * x * z + sin * y),
+            Vector3::new(t * x * y + sin * z, t * y * y + cos, t * y * z - sin * x),
+            Vector3::new(t * x * z - sin * y, t * y * z + sin * x, t * z * z + cos),
+        )
+    }
+}
+
+impl_complex_matrix! { Matrix3 { x_axis, y_axis, z_axis } }
+impl_matrix_ops_for_float! { Matrix3 { x_axis, y_axis, z_axis } }
+
+impl_aggregate_conversion!(From<[[T; 3]; 3]> for Matrix3<T: Field> { x_axis, y_axis, z_axis });
+
+#[cfg(test)]
+mod tests {
+    use super::{super::vec3, *};
+
+    macro_rules! real_test_suite {
+        ($ty:ty) => {
+            use crate::*;
+
+            mod c {
+                #![allow(clippy::excessive_precision)]
+                #![allow(non_upper_case_globals)]
+
+                use super::*;
+
+                pub const _zero: Matrix3<$ty> = Matrix3::ZERO;
+
+                pub const _m1: Matrix3<$ty> = Matrix3::from_rows(
+                    vec3(2.0, 0.0, 1.0),
+                    vec3(1.0, 3.0, 2.0),
+                    vec3(1.0, 1.0, 2.0),
+                );
+                pub const _m2: Matrix3<$ty> = Matrix3::from_rows(
+                    vec3(1.0, 2.0, 3.0),
+                    vec3(4.0, 5.0, 6.0),
+                    vec3(7.0, 8.0, 10.0),
+                );
+                pub const _singular: Matrix3<$ty> = Matrix3::from_rows(
+                    vec3(1.0, 2.0, 3.0),
+                    vec3(4.0, 5.0, 6.0),
+                    vec3(7.0, 8.0, 9.0),
+                );
+            }
+
+            #[test]
+            fn consts() {
+                assert_eq!(
+                    Matrix3::<$ty>::ZERO,
+                    Matrix3::from_cols(Vector3::ZERO, Vector3::ZERO, Vector3::ZERO)
+                );
+                assert_eq!(
+                    Matrix3::<$ty>::IDENTITY,
+                    Matrix3::from_diagonal(vec3(1.0, 1.0, 1.0))
+                );
+            }
+
+            #[test]
+            fn constructors() {
+                let x_axis = vec3::<$ty>(2.0, 1.0, 1.0);
+                let y_axis = vec3::<$ty>(0.0, 3.0, 1.0);
+                let z_axis = vec3::<$ty>(1.0, 2.0, 2.0);
+
+                assert_eq!(mat3(x_axis, y_axis, z_axis), c::_m1);
+                assert_eq!(Matrix3::from_cols(x_axis, y_axis, z_axis), c::_m1);
+                assert_eq!(
+                    Matrix3::new(2.0, 0.0, 1.0, 1.0, 3.0, 2.0, 1.0, 1.0, 2.0),
+                    c::_m1
+                );
+            }
+
+            #[test]
+            fn cols_and_rows() {
+                assert_eq!(c::_m1.col(0), vec3(2.0, 1.0, 1.0));
+                assert_eq!(c::_m1.col(1), vec3(0.0, 3.0, 1.0));
+                assert_eq!(c::_m1.col(2), vec3(1.0, 2.0, 2.0));
+                assert_eq!(c::_m1.row(0), vec3(2.0, 0.0, 1.0));
+                assert_eq!(c::_m1.row(1), vec3(1.0, 3.0, 2.0));
+                assert_eq!(c::_m1.row(2), vec3(1.0, 1.0, 2.0));
+                assert_eq!(c::_m1.diagonal(), vec3(2.0, 3.0, 2.0));
+            }
+
+            #[test]
+            #[should_panic]
+            fn row_out_of_bounds() {
+                c::_m1.row(3);
+            }
+
+            #[test]
+            fn add_and_sub() {
+                let expected = Matrix3::from_rows(
+                    vec3(3.0, 2.0, 4.0),
+                    vec3(5.0, 8.0, 8.0),
+                    vec3(8.0, 9.0, 12.0),
+                );
+                assert_eq!(c::_m1 + c::_m2, expected);
+                assert_eq!(expected - c::_m2, c::_m1);
+            }
+
+            #[test]
+            fn mul_and_div_by_scalar() {
+                assert_eq!(c::_m1 * 2.0, c::_m1 + c::_m1);
+                assert_eq!((c::_m1 * 2.0) / 2.0, c::_m1);
+            }
+
+            #[test]
+            fn mul_vector() {
+                assert_eq!(c::_m1 * vec3(1.0, 2.0, 3.0), vec3(5.0, 13.0, 9.0));
+                assert_eq!(
+                    Matrix3::IDENTITY * vec3::<$ty>(1.0, 2.0, 3.0),
+                    vec3(1.0, 2.0, 3.0)
+                );
+            }
+
+            #[test]
+            fn mul_matrix() {
+                let expected = Matrix3::from_rows(
+                    vec3(9.0, 12.0, 16.0),
+                    vec3(27.0, 33.0, 41.0),
+                    vec3(19.0, 23.0, 29.0),
+                );
+                assert_eq!(c::_m1 * c::_m2, expected);
+                assert_eq!(c::_m1 * Matrix3::IDENTITY, c::_m1);
+                assert_eq!(Matrix3::IDENTITY * c::_m1, c::_m1);
+
+                let mut m = c::_m1;
+                m *= c::_m2;
+                assert_eq!(m, expected);
+            }
+
+            #[test]
+            fn transpose() {
+                assert_eq!(
+                    c::_m1.transpose(),
+                    Matrix3::from_rows(c::_m1.col(0), c::_m1.col(1), c::_m1.col(2))
+                );
+                assert_eq!(c::_m1.conj_transpose(), c::_m1.transpose());
+                assert_eq!(c::_m1.transpose().transpose(), c::_m1);
+            }
+
+            #[test]
+            fn determinant_and_trace() {
+                assert_eq!(c::_m1.determinant(), 6.0);
+                assert_eq!(c::_singular.determinant(), 0.0);
+                assert_eq!(c::_m1.transpose().determinant(), 6.0);
+                assert_eq!(c::_m1.trace(), 7.0);
+            }
+
+            #[test]
+            fn inverse() {
+                let adjugate = Matrix3::from_rows(
+                    vec3(4.0, 1.0, -3.0),
+                    vec3(0.0, 3.0, -3.0),
+                    vec3(-2.0, -2.0, 6.0),
+                );
+                assert_almost_eq!(c::_m1.inverse(), adjugate / 6.0);
+                assert_abs_diff_eq!(c::_m1 * c::_m1.inverse(), Matrix3::IDENTITY);
+
+                assert!(c::_m1.try_inverse().is_some());
+                assert!(c::_singular.try_inverse().is_none());
+                assert!(c::_zero.try_inverse().is_none());
+            }
+
+            #[test]
+            fn from_axis_angle() {
+                let rotation = Matrix3::<$ty>::from_axis_angle(Vector3::Z, Angle::RIGHT);
+                assert_abs_diff_eq!(rotation * Vector3::X, Vector3::Y);
+                assert_abs_diff_eq!(rotation * Vector3::Y, -Vector3::X);
+                assert_abs_diff_eq!(rotation * Vector3::Z, Vector3::Z);
+
+                let axis = vec3::<$ty>(1.0, 1.0, 1.0);
+                let rotation = Matrix3::from_axis_angle(axis, Angle::FULL / 3.0);
+                assert_abs_diff_eq!(rotation * Vector3::X, Vector3::Y);
+                assert_abs_diff_eq!(rotation * Vector3::Y, Vector3::Z);
+                assert_abs_diff_eq!(rotation * axis, axis);
+                assert_abs_diff_eq!(rotation.determinant(), 1.0);
+                assert_abs_diff_eq!(rotation * rotation.transpose(), Matrix3::IDENTITY);
+            }
+
+            #[test]
+            fn array_conversion() {
+                let a: [[$ty; 3]; 3] = [[2.0, 1.0, 1.0], [0.0, 3.0, 1.0], [1.0, 2.0, 2.0]];
+
+                assert_eq!(a, <[[$ty; 3]; 3]>::from(c::_m1));
+                assert_eq!(c::_m1, Matrix3::from(a));
+            }
+        };
+    }
+
+    mod f32 {
+        use super::*;
+        real_test_suite!(f32);
+
+        #[test]
+        fn to_f64() {
+            let m_f32 = Matrix3::<f32>::from_diagonal(vec3(1.0, 2.0, 3.0));
+            let m_f64 = Matrix3::<f64>::from_diagonal(vec3(1.0, 2.0, 3.0));
+
+            assert_eq!(m_f32.to_f64(), m_f64);
+            assert_eq!(Matrix3::<f64>::from(m_f32), m_f64);
+        }
+    }
+
+    mod f64 {
+        use super::*;
+        real_test_suite!(f64);
+
+        #[test]
+        fn to_f32() {
+            let m_f32 = Matrix3::<f32>::from_diagonal(vec3(1.0, 2.0, 3.0));
+            let m_f64 = Matrix3::<f64>::from_diagonal(vec3(1.0, 2.0, 3.0));
+
+            assert_eq!(m_f64.to_f32(), m_f32);
+        }
+    }
+
+    macro_rules! complex_test_suite {
+        ($ty:ty) => {
+            use crate::*;
+
+            mod c {
+                #![allow(clippy::excessive_precision)]
+                #![allow(non_upper_case_globals)]
+
+                use super::*;
+
+                pub const _m1: Matrix3<Complex<$ty>> = Matrix3::from_rows(
+                    vec3(complex(1.0, 1.0), complex(2.0, 0.0), complex(0.0, 0.0)),
+                    vec3(complex(0.0, 0.0), complex(0.0, 1.0), complex(1.0, 0.0)),
+                    vec3(complex(1.0, 0.0), complex(0.0, 0.0), complex(2.0, -1.0)),
+                );
+            }
+
+            #[test]
+            fn conj_transpose() {
+                let m = c::_m1.conj_transpose();
+                assert_eq!(
+                    m.row(0),
+                    vec3(complex(1.0, -1.0), complex(0.0, 0.0), complex(1.0, 0.0))
+                );
+                assert_eq!(
+                    m.row(1),
+                    vec3(complex(2.0, 0.0), complex(0.0, -1.0), complex(0.0, 0.0))
+                );
+                assert_eq!(
+                    m.row(2),
+                    vec3(complex(0.0, 0.0), complex(1.0, 0.0), complex(2.0, 1.0))
+                );
+            }
+
+            #[test]
+            fn determinant_and_trace() {
+                assert_almost_eq!(c::_m1.determinant(), complex(1.0, 3.0));
+                assert_almost_eq!(c::_m1.trace(), complex(3.0, 1.0));
+            }
+
+            #[test]
+            fn inverse() {
+                assert_abs_diff_eq!(c::_m1 * c::_m1.inverse(), Matrix3::IDENTITY);
+                assert!(c::_m1.try_inverse().is_some());
+                assert!(Matrix3::<Complex<$ty>>::ZERO.try_inverse().is_none());
+            }
+
+            #[test]
+            fn mul_and_div_by_real() {
+                assert_eq!(c::_m1 * 2.0, c::_m1 + c::_m1);
+                assert_eq!((c::_m1 * 2.0) / 2.0, c::_m1);
+            }
+
+            #[test]
+            fn real_and_imag() {
+                assert_eq!(c::_m1.real().row(0), vec3::<$ty>(1.0, 2.0, 0.0));
+                assert_eq!(c::_m1.imag().row(2), vec3::<$ty>(0.0, 0.0, -1.0));
+                assert_eq!(
+                    c::_m1.real().to_complex() + c::_m1.imag().to_complex() * Complex::I,
+                    c::_m1
+                );
+            }
+        };
+    }
+
+    mod c32 {
+        use crate::math::complex;
+
+        use super::*;
+        complex_test_suite!(f32);
+
+        #[test]
+        fn to_f64() {
+            let m32 =
+                Matrix3::from_diagonal(vec3(complex(1.0f32, 2.0), Complex::ZERO, Complex::ONE));
+            let m64 =
+                Matrix3::from_diagonal(vec3(complex(1.0f64, 2.0), Complex::ZERO, Complex::ONE));
+
+            assert_eq!(m32.to_f64(), m64);
+            assert_eq!(Matrix3::<Complex<f64>>::from(m32), m64);
+        }
+    }
+
+    mod c64 {
+        use crate::math::complex;
+
+        use super::*;
+        complex_test_suite!(f64);
+
+        #[test]
+        fn to_f32() {
+            let m32 =
+                Matrix3::from_diagonal(vec3(complex(1.0f32, 2.0), Complex::ZERO, Complex::ONE));
+            let m64 =
+                Matrix3::from_diagonal(vec3(complex(1.0f64, 2.0), Complex::ZERO, Complex::ONE));
+
+            assert_eq!(m64.to_f32(), m32);
+        }
+    }
+}
@@ -0,0 +1,584 @@
+use core::ops::Mul;
+
+use super::{macros::*, Angle, Matrix3, Point3, RealField, Vector3, Vector4};
+
+/// Create a new [`Matrix4`] from its columns.
+#[inline]
+pub const fn mat4<T: RealField>(
+    x_axis: Vector4<T>,
+    y_axis: Vector4<T>,
+    z_axis: Vector4<T>,
+    w_axis: Vector4<T>,
+) -> Matrix4<T> {
+    Matrix4::from_cols(x_axis, y_axis, z_axis, w_axis)
+}
+
+impl_vector_space! {
+    /// A 4x4 matrix, stored in column-major order.
+    ///
+    /// The matrix represents a transformation of the 3-dimensional space in homogeneous
+    /// coordinates: points are transformed with the implicit coordinate `w = 1`, while vectors
+    /// are transformed with `w = 0` and so they are not affected by translations.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[repr(C)]
+    pub struct Matrix4<T: RealField> {
+        /// The first column of the matrix.
+        pub(super) x_axis: Vector4<T>,
+        /// The second column of the matrix.
+        pub(super) y_axis: Vector4<T>,
+        /// The third column of the matrix.
+        pub(super) z_axis: Vector4<T>,
+        /// The fourth column of the matrix.
+        pub(super) w_axis: Vector4<T>,
+    }
+
+    impl<T: RealField> Matrix4<T> {
+        /// Construct a new matrix from its columns.
+        #[inline]
+        pub const fn from_cols(
+            x_axis: Vector4<T>,
+            y_axis: Vector4<T>,
+            z_axis: Vector4<T>,
+            w_axis: Vector4<T>,
+        ) -> Self {
+            Self { x_axis, y_axis, z_axis, w_axis }
+        }
+
+        /// Construct a new matrix from its rows.
+        #[inline]
+        pub const fn from_rows(
+            r0: Vector4<T>,
+            r1: Vector4<T>,
+            r2: Vector4<T>,
+            r3: Vector4<T>,
+        ) -> Self {
+            Self::from_cols(
+                Vector4::new(r0.x, r1.x, r2.x, r3.x),
+                Vector4::new(r0.y, r1.y, r2.y, r3.y),
+                Vector4::new(r0.z, r1.z, r2.z, r3.z),
+                Vector4::new(r0.w, r1.w, r2.w, r3.w),
+            )
+        }
+
+        /// Construct a new diagonal matrix.
+        #[inline]
+        pub const fn from_diagonal(diagonal: Vector4<T>) -> Self {
+            Self::from_cols(
+                Vector4::new(diagonal.x, T::ZERO, T::ZERO, T::ZERO),
+                Vector4::new(T::ZERO, diagonal.y, T::ZERO, T::ZERO),
+                Vector4::new(T::ZERO, T::ZERO, diagonal.z, T::ZERO),
+                Vector4::new(T::ZERO, T::ZERO, T::ZERO, diagonal.w),
+            )
+        }
+
+        /// Creates a transformation that translates by `translation`.
+        #[inline]
+        pub fn from_translation(translation: Vector3<T>) -> Self {
+            Self::from_cols(Vector4::X, Vector4::Y, Vector4::Z, translation.extend(T::ONE))
+        }
+
+        /// Creates a transformation that scales each axis by the given factor.
+        #[inline]
+        pub fn from_scale(scale: Vector3<T>) -> Self {
+            Self::from_diagonal(scale.extend(T::ONE))
+        }
+
+        /// Creates a transformation that rotates around `axis` by the given `angle`.
+        ///
+        /// The axis is normalized before computing the rotation.
+        #[inline]
+        pub fn from_axis_angle(axis: Vector3<T>, angle: Angle<T>) -> Self {
+            Matrix3::from_axis_angle(axis, angle).into()
+        }
+
+        /// Creates a right-handed view transformation, for a camera in `eye` looking at
+        /// `target`, with `up` pointing upward.
+        ///
+        /// The camera looks toward the negative Z axis of the view space.
+        pub fn look_at(eye: Point3<T>, target: Point3<T>, up: Vector3<T>) -> Self {
+            let eye = eye - Point3::ORIGIN;
+            let f = (target - Point3::ORIGIN - eye).unit();
+            let s = f.cross(up).unit();
+            let u = s.cross(f);
+
+            Self::from_rows(
+                s.extend(-s.dot(eye)),
+                u.extend(-u.dot(eye)),
+                (-f).extend(f.dot(eye)),
+                Vector4::W,
+            )
+        }
+
+        /// Creates a right-handed perspective projection, with the given vertical field of
+        /// view, aspect ratio (width over height) and distances of the clipping planes.
+        ///
+        /// The view frustum is mapped to the cube `[-1, 1]³` of the normalized device
+        /// coordinates.
+        pub fn perspective(fov_y: Angle<T>, aspect: T, near: T, far: T) -> Self {
+            let f = (fov_y * T::FRAC_1_2).cot();
+            let depth = (near - far).recip();
+            let two = T::ONE + T::ONE;
+
+            Self::from_rows(
+                Vector4::new(f / aspect, T::ZERO, T::ZERO, T::ZERO),
+                Vector4::new(T::ZERO, f, T::ZERO, T::ZERO),
+                Vector4::new(T::ZERO, T::ZERO, (far + near) * depth, two * far * near * depth),
+                Vector4::new(T::ZERO, T::ZERO, -T::ONE, T::ZERO),
+            )
+        }
+
+        /// Creates a right-handed orthographic projection, with the given clipping planes.
+        ///
+        /// The view volume is mapped to the cube `[-1, 1]³` of the normalized device
+        /// coordinates.
+        pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+            let width = (right - left).recip();
+            let height = (top - bottom).recip();
+            let depth = (far - near).recip();
+            let two = T::ONE + T::ONE;
+
+            Self::from_rows(
+                Vector4::new(two * width, T::ZERO, T::ZERO, -(right + left) * width),
+                Vector4::new(T::ZERO, two * height, T::ZERO, -(top + bottom) * height),
+                Vector4::new(T::ZERO, T::ZERO, -two * depth, -(far + near) * depth),
+                Vector4::W,
+            )
+        }
+
+        /// Returns the column with the given index.
+        ///
+        /// Panics if `index` is greater than 3.
+        #[inline]
+        pub fn col(self, index: usize) -> Vector4<T> {
+            match index {
+                0 => self.x_axis,
+                1 => self.y_axis,
+                2 => self.z_axis,
+                3 => self.w_axis,
+                _ => panic!("index out of bounds: {index}"),
+            }
+        }
+
+        /// Returns the row with the given index.
+        ///
+        /// Panics if `index` is greater than 3.
+        #[inline]
+        pub fn row(self, index: usize) -> Vector4<T> {
+            let Self { x_axis, y_axis, z_axis, w_axis } = self;
+            match index {
+                0 => Vector4::new(x_axis.x, y_axis.x, z_axis.x, w_axis.x),
+                1 => Vector4::new(x_axis.y, y_axis.y, z_axis.y, w_axis.y),
+                2 => Vector4::new(x_axis.z, y_axis.z, z_axis.z, w_axis.z),
+                3 => Vector4::new(x_axis.w, y_axis.w, z_axis.w, w_axis.w),
+                _ => panic!("index out of bounds: {index}"),
+            }
+        }
+
+        /// Returns the diagonal of the matrix.
+        #[inline]
+        pub fn diagonal(self) -> Vector4<T> {
+            Vector4::new(self.x_axis.x, self.y_axis.y, self.z_axis.z, self.w_axis.w)
+        }
+
+        /// Returns the transpose of the matrix.
+        #[inline]
+        pub fn transpose(self) -> Self {
+            Self::from_rows(self.x_axis, self.y_axis, self.z_axis, self.w_axis)
+        }
+
+        /// Computes the determinant of the matrix.
+        #[inline]
+        pub fn determinant(self) -> T {
+            self.adjugate_and_determinant().1
+        }
+
+        /// Computes the trace of the matrix.
+        #[inline]
+        pub fn trace(self) -> T {
+            self.x_axis.x + self.y_axis.y + self.z_axis.z + self.w_axis.w
+        }
+
+        /// Returns the inverse of the matrix.
+        ///
+        /// If the matrix is singular the entries of the result are not finite.
+        #[inline]
+        pub fn inverse(self) -> Self {
+            let (adjugate, det) = self.adjugate_and_determinant();
+            adjugate / det
+        }
+
+        /// Returns the inverse of the matrix if it is not singular, else `None`.
+        #[inline]
+        pub fn try_inverse(self) -> Option<Self> {
+            let (adjugate, det) = self.adjugate_and_determinant();
+            (det.abs() > T::ZERO).then(|| adjugate / det)
+        }
+
+        /// Transforms a point, the implicit fourth coordinate of the point is 1.
+        ///
+        /// If the resulting fourth coordinate is not 1, as it happens for projections, the
+        /// coordinates are divided by it.
+        #[inline]
+        pub fn transform_point(self, point: Point3<T>) -> Point3<T> {
+            let Vector4 { x, y, z, w } = self * Vector4::new(point.x, point.y, point.z, T::ONE);
+            if w == T::ONE {
+                Point3::new(x, y, z)
+            } else {
+                Point3::new(x / w, y / w, z / w)
+            }
+        }
+
+        /// Transforms a vector, the implicit fourth coordinate of the vector is 0.
+        #[inline]
+        pub fn transform_vector(self, vector: Vector3<T>) -> Vector3<T> {
+            let Vector3 { x, y, z } = vector;
+            (self.x_axis * x + self.y_axis * y + self.z_axis * z).truncate()
+        }
+
+        /// Computes the transpose of the cofactor matrix and the determinant, using the
+        /// Laplace expansion by complementary minors.
+        fn adjugate_and_determinant(self) -> (Self, T) {
+            let Vector4 { x: a00, y: a10, z: a20, w: a30 } = self.x_axis;
+            let Vector4 { x: a01, y: a11, z: a21, w: a31 } = self.y_axis;
+            let Vector4 { x: a02, y: a12, z: a22, w: a32 } = self.z_axis;
+            let Vector4 { x: a03, y: a13, z: a23, w: a33 } = self.w_axis;
+
+            let s0 = a00 * a11 - a10 * a01;
+            let s1 = a00 * a12 - a10 * a02;
+            let s2 = a00 * a13 - a10 * a03;
+            let s3 = a01 * a12 - a11 * a02;
+            let s4 = a01 * a13 - a11 * a03;
+            let s5 = a02 * a13 - a12 * a03;
+
+            let c5 = a22 * a33 - a32 * a23;
+            let c4 = a21 * a33 - a31 * a23;
+            let c3 = a21 * a32 - a31 * a22;
+            let c2 = a20 * a33 - a30 * a23;
+            let c1 = a20 * a32 - a30 * a22;
+            let c0 = a20 * a31 - a30 * a21;
+
+            let det = s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0;
+
+            let adjugate = Self::from_rows(
+                Vector4::new(
+                    a11 * c5 - a12 * c4 + a13 * c3,
+                    -a01 * c5 + a02 * c4 - a03 * c3,
+                    a31 * s5 - a32 * s4 + a33 * s3,
+                    -a21 * s5 + a22 * s4 - a23 * s3,
+                ),
+                Vector4::new(
+                    -a10 * c5 + a12 * c2 - a13 * c1,
+                    a00 * c5 - a02 * c2 + a03 * c1,
+                    -a30 * s5 + a32 * s2 - a33 * s1,
+                    a20 * s5 - a22 * s2 + a23 * s1,
+                ),
+                Vector4::new(
+                    a10 * c4 - a11 * c2 + a13 * c0,
+                    -a00 * c4 + a01 * c2 - a03 * c0,
+                    a30 * s4 - a31 * s2 + a33 * s0,
+                    -a20 * s4 + a21 * s2 - a23 * s0,
+                ),
+                Vector4::new(
+                    -a10 * c3 + a11 * c1 - a12 * c0,
+                    a00 * c3 - a01 * c1 + a02 * c0,
+                    -a30 * s3 + a31 * s1 - a32 * s0,
+                    a20 * s3 - a21 * s1 + a22 * s0,
+                ),
+            );
+
+            (adjugate, det)
+        }
+    }
+}
+
+impl_multiplicative_group! {
+    impl Matrix4<T: RealField> {
+        /// The multiplicative identity element.
+        pub const IDENTITY: Self = Self::from_cols(Vector4::X, Vector4::Y, Vector4::Z, Vector4::W);
+
+        fn mul(self, rhs: Self) -> Self {
+            Matrix4 {
+                x_axis: self * rhs.x_axis,
+                y_axis: self * rhs.y_axis,
+                z_axis: self * rhs.z_axis,
+                w_axis: self * rhs.w_axis,
+            }
+        }
+    }
+}
+
+impl<T: RealField> Mul<Vector4<T>> for Matrix4<T> {
+    type Output = Vector4<T>;
+
+    #[inline]
+    fn mul(self, rhs: Vector4<T>) -> Self::Output {
+        self.x_axis * rhs.x + self.y_axis * rhs.y + self.z_axis * rhs.z + self.w_axis * rhs.w
+    }
+}
+
+impl<T: RealField> Mul<Vector3<T>> for Matrix4<T> {
+    type Output = Vector3<T>;
+
+    #[inline]
+    fn mul(self, rhs: Vector3<T>) -> Self::Output {
+        self.transform_vector(rhs)
+    }
+}
+
+impl<T: RealField> Mul<Point3<T>> for Matrix4<T> {
+    type Output = Point3<T>;
+
+    #[inline]
+    fn mul(self, rhs: Point3<T>) -> Self::Output {
+        self.transform_point(rhs)
+    }
+}
+
+impl<T: RealField> From<Matrix3<T>> for Matrix4<T> {
+    #[inline]
+    fn from(value: Matrix3<T>) -> Self {
+        Self::from_cols(
+            value.x_axis.extend(T::ZERO),
+            value.y_axis.extend(T::ZERO),
+            value.z_axis.extend(T::ZERO),
+            Vector4::W,
+        )
+    }
+}
+
+impl_matrix_ops_for_float! { Matrix4<RealField> { x_axis, y_axis, z_axis, w_axis } }
+
+impl_aggregate_conversion!(From<[[T; 4]; 4]> for Matrix4<T: RealField> { x_axis, y_axis, z_axis, w_axis });
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{point3, vec3, vec4},
+        *,
+    };
+
+    macro_rules! test_suite {
+        ($ty:ty) => {
+            use crate::*;
+
+            mod c {
+                #![allow(clippy::excessive_precision)]
+                #![allow(non_upper_case_globals)]
+
+                use super::*;
+
+                pub const _zero: Matrix4<$ty> = Matrix4::ZERO;
+
+                pub const _m1: Matrix4<$ty> = Matrix4::from_rows(
+                    vec4(2.0, 0.0, 1.0, 3.0),
+                    vec4(1.0, 3.0, 2.0, 0.0),
+                    vec4(1.0, 1.0, 2.0, 1.0),
+                    vec4(0.0, 2.0, 1.0, 4.0),
+                );
+                pub const _singular: Matrix4<$ty> = Matrix4::from_rows(
+                    vec4(1.0, 2.0, 3.0, 4.0),
+                    vec4(5.0, 6.0, 7.0, 8.0),
+                    vec4(9.0, 10.0, 11.0, 12.0),
+                    vec4(13.0, 14.0, 15.0, 16.0),
+                );
+            }
+
+            #[test]
+            fn consts() {
+                assert_eq!(
+                    Matrix4::<$ty>::IDENTITY,
+                    Matrix4::from_diagonal(vec4(1.0, 1.0, 1.0, 1.0))
+                );
+                assert_eq!(
+                    Matrix4::<$ty>::ZERO,
+                    Matrix4::from_diagonal(vec4(0.0, 0.0, 0.0, 0.0))
+                );
+            }
+
+            #[test]
+            fn cols_and_rows() {
+                assert_eq!(c::_m1.col(0), vec4(2.0, 1.0, 1.0, 0.0));
+                assert_eq!(c::_m1.col(3), vec4(3.0, 0.0, 1.0, 4.0));
+                assert_eq!(c::_m1.row(0), vec4(2.0, 0.0, 1.0, 3.0));
+                assert_eq!(c::_m1.row(3), vec4(0.0, 2.0, 1.0, 4.0));
+                assert_eq!(c::_m1.diagonal(), vec4(2.0, 3.0, 2.0, 4.0));
+                assert_eq!(
+                    mat4(c::_m1.col(0), c::_m1.col(1), c::_m1.col(2), c::_m1.col(3)),
+                    c::_m1
+                );
+            }
+
+            #[test]
+            fn transpose() {
+                assert_eq!(c::_m1.transpose().row(0), c::_m1.col(0));
+                assert_eq!(c::_m1.transpose().transpose(), c::_m1);
+            }
+
+            #[test]
+            fn mul_matrix() {
+                assert_eq!(c::_m1 * Matrix4::IDENTITY, c::_m1);
+                assert_eq!(Matrix4::IDENTITY * c::_m1, c::_m1);
+                assert_eq!((c::_m1 * c::_m1).row(0), vec4(5.0, 7.0, 7.0, 19.0));
+            }
+
+            #[test]
+            fn determinant_and_trace() {
+                assert_eq!(c::_m1.determinant(), 30.0);
+                assert_eq!(c::_m1.transpose().determinant(), 30.0);
+                assert_eq!(c::_singular.determinant(), 0.0);
+                assert_eq!(c::_m1.trace(), 11.0);
+            }
+
+            #[test]
+            fn inverse() {
+                assert_abs_diff_eq!(
+                    c::_m1 * c::_m1.inverse(),
+                    Matrix4::IDENTITY,
+                    4.0 * <$ty>::EPSILON
+                );
+                assert_abs_diff_eq!(
+                    c::_m1.inverse() * c::_m1,
+                    Matrix4::IDENTITY,
+                    4.0 * <$ty>::EPSILON
+                );
+
+                assert!(c::_m1.try_inverse().is_some());
+                assert!(c::_singular.try_inverse().is_none());
+                assert!(c::_zero.try_inverse().is_none());
+            }
+
+            #[test]
+            fn translation() {
+                let m = Matrix4::from_translation(vec3::<$ty>(1.0, 2.0, 3.0));
+
+                assert_eq!(m * point3(1.0, 1.0, 1.0), point3(2.0, 3.0, 4.0));
+                assert_eq!(m * vec3(1.0, 1.0, 1.0), vec3(1.0, 1.0, 1.0));
+                assert_eq!(m.inverse() * point3(2.0, 3.0, 4.0), point3(1.0, 1.0, 1.0));
+            }
+
+            #[test]
+            fn scale() {
+                let m = Matrix4::from_scale(vec3::<$ty>(1.0, 2.0, 3.0));
+
+                assert_eq!(m * point3(1.0, 1.0, 1.0), point3(1.0, 2.0, 3.0));
+                assert_eq!(m * vec3(1.0, 1.0, 1.0), vec3(1.0, 2.0, 3.0));
+            }
+
+            #[test]
+            fn rotation() {
+                let m = Matrix4::<$ty>::from_axis_angle(Vector3::Z, Angle::RIGHT);
+
+                assert_abs_diff_eq!(m * Vector3::X, Vector3::Y);
+                assert_abs_diff_eq!(m * point3(1.0, 0.0, 0.0), point3(0.0, 1.0, 0.0));
+                assert_eq!(m.row(3), Vector4::W);
+            }
+
+            #[test]
+            fn compose() {
+                let translation = Matrix4::from_translation(vec3::<$ty>(1.0, 0.0, 0.0));
+                let rotation = Matrix4::from_axis_angle(Vector3::Z, Angle::RIGHT);
+
+                let m = translation * rotation;
+                assert_abs_diff_eq!(m * point3(1.0, 0.0, 0.0), point3(1.0, 1.0, 0.0));
+
+                let m = rotation * translation;
+                assert_abs_diff_eq!(m * point3(1.0, 0.0, 0.0), point3(0.0, 2.0, 0.0));
+            }
+
+            #[test]
+            fn look_at() {
+                let eye = point3::<$ty>(1.0, 2.0, 3.0);
+                let target = point3::<$ty>(1.0, 2.0, -1.0);
+                let view = Matrix4::look_at(eye, target, Vector3::Y);
+
+                assert_abs_diff_eq!(view * eye, Point3::ORIGIN);
+                assert_abs_diff_eq!(view * target, point3(0.0, 0.0, -4.0));
+                assert_abs_diff_eq!(view * Vector3::Y, Vector3::Y);
+
+                let eye = point3::<$ty>(0.0, 0.0, 0.0);
+                let target = point3::<$ty>(1.0, 0.0, 0.0);
+                let view = Matrix4::look_at(eye, target, Vector3::Y);
+
+                assert_abs_diff_eq!(view * target, point3(0.0, 0.0, -1.0));
+                assert_abs_diff_eq!(view * Vector3::Z, Vector3::X);
+            }
+
+            #[test]
+            fn perspective() {
+                let projection = Matrix4::<$ty>::perspective(Angle::RIGHT, 2.0, 1.0, 10.0);
+
+                assert_abs_diff_eq!(projection * point3(0.0, 0.0, -1.0), point3(0.0, 0.0, -1.0));
+                assert_abs_diff_eq!(projection * point3(0.0, 0.0, -10.0), point3(0.0, 0.0, 1.0));
+                assert_abs_diff_eq!(projection * point3(2.0, 1.0, -1.0), point3(1.0, 1.0, -1.0));
+                assert_abs_diff_eq!(
+                    projection * point3(-20.0, -10.0, -10.0),
+                    point3(-1.0, -1.0, 1.0),
+                    4.0 * <$ty>::EPSILON,
+                );
+            }
+
+            #[test]
+            fn orthographic() {
+                let projection = Matrix4::<$ty>::orthographic(-2.0, 2.0, -1.0, 1.0, 1.0, 10.0);
+
+                assert_abs_diff_eq!(
+                    projection * point3(-2.0, -1.0, -1.0),
+                    point3(-1.0, -1.0, -1.0)
+                );
+                assert_abs_diff_eq!(projection * point3(2.0, 1.0, -10.0), point3(1.0, 1.0, 1.0));
+                assert_abs_diff_eq!(projection * vec3(2.0, 1.0, 0.0), vec3(1.0, 1.0, 0.0));
+            }
+
+            #[test]
+            fn from_matrix3() {
+                let m = Matrix3::<$ty>::from_diagonal(vec3(1.0, 2.0, 3.0));
+                assert_eq!(
+                    Matrix4::from(m),
+                    Matrix4::from_diagonal(vec4(1.0, 2.0, 3.0, 1.0))
+                );
+            }
+
+            #[test]
+            fn array_conversion() {
+                let a: [[$ty; 4]; 4] = Matrix4::IDENTITY.into();
+                assert_eq!(
+                    a,
+                    [
+                        [1.0, 0.0, 0.0, 0.0],
+                        [0.0, 1.0, 0.0, 0.0],
+                        [0.0, 0.0, 1.0, 0.0],
+                        [0.0, 0.0, 0.0, 1.0]
+                    ]
+                );
+                assert_eq!(Matrix4::from(a), Matrix4::IDENTITY);
+            }
+        };
+    }
+
+    mod f32 {
+        use super::*;
+        test_suite!(f32);
+
+        #[test]
+        fn to_f64() {
+            let m_f32 = Matrix4::<f32>::from_translation(vec3(1.0, 2.0, 3.0));
+            let m_f64 = Matrix4::<f64>::from_translation(vec3(1.0, 2.0, 3.0));
+
+            assert_eq!(m_f32.to_f64(), m_f64);
+            assert_eq!(Matrix4::<f64>::from(m_f32), m_f64);
+        }
+    }
+
+    mod f64 {
+        use super::*;
+        test_suite!(f64);
+
+        #[test]
+        fn to_f32() {
+            let m_f32 = Matrix4::<f32>::from_translation(vec3(1.0, 2.0, 3.0));
+            let m_f64 = Matrix4::<f64>::from_translation(vec3(1.0, 2.0, 3.0));
+
+            assert_eq!(m_f64.to_f32(), m_f32);
+        }
+    }
+}
@@ -0,0 +1,643 @@
+use core::{fmt, ops::Mul};
+
+use super::{macros::*, Angle, Matrix3, Matrix4, Point3, RealField, Vector3};
+
+/// Create a new [`Quaternion`] from its components.
+#[inline]
+pub const fn quaternion<T: RealField>(real: T, i: T, j: T, k: T) -> Quaternion<T> {
+    Quaternion::new(real, i, j, k)
+}
+
+impl_vector_space! {
+    /// A quaternion.
+    ///
+    /// Unit quaternions represent rotations of the 3-dimensional space.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[repr(C)]
+    pub struct Quaternion<T: RealField> {
+        /// The real part of the quaternion.
+        pub real: T,
+        /// The imaginary part of the quaternion.
+        pub imag: Vector3<T>,
+    }
+
+    impl<T: RealField> Quaternion<T> {
+        /// The first imaginary unit.
+        pub const I: Self = Self::new(T::ZERO, T::ONE, T::ZERO, T::ZERO);
+
+        /// The second imaginary unit.
+        pub const J: Self = Self::new(T::ZERO, T::ZERO, T::ONE, T::ZERO);
+
+        /// The third imaginary unit.
+        pub const K: Self = Self::new(T::ZERO, T::ZERO, T::ZERO, T::ONE);
+
+        /// Construct a new quaternion from the real part and the components of the
+        /// imaginary part.
+        #[inline]
+        pub const fn new(real: T, i: T, j: T, k: T) -> Self {
+            Self::from_parts(real, Vector3::new(i, j, k))
+        }
+
+        /// Construct a new quaternion from the real and imaginary parts.
+        #[inline]
+        pub const fn from_parts(real: T, imag: Vector3<T>) -> Self {
+            Self { real, imag }
+        }
+
+        /// Creates the rotation around `axis` by the given `angle`.
+        ///
+        /// The axis is normalized before computing the rotation.
+        #[inline]
+        pub fn from_axis_angle(axis: Vector3<T>, angle: Angle<T>) -> Self {
+            let (sin, cos) = (angle * T::FRAC_1_2).sin_cos();
+            Self::from_parts(cos, axis.unit() * sin)
+        }
+
+        /// Returns the axis and the angle of the rotation represented by the unit quaternion.
+        ///
+        /// The angle belongs to the range [0, 2π], if the angle is zero the axis is zero.
+        #[inline]
+        pub fn to_axis_angle(self) -> (Vector3<T>, Angle<T>) {
+            let sin = self.imag.norm_square().sqrt();
+            let angle = Angle::atan2(sin, self.real) * (T::ONE + T::ONE);
+            (self.imag.unit_or_zero(), angle)
+        }
+
+        /// Creates the rotation from the given Euler angles.
+        ///
+        /// The rotation is obtained applying the `roll` around the X axis, then the `pitch`
+        /// around the Y axis and finally the `yaw` around the Z axis, all of them are
+        /// rotations about the fixed frame.
+        pub fn from_euler_angles(roll: Angle<T>, pitch: Angle<T>, yaw: Angle<T>) -> Self {
+            let roll = Self::from_axis_angle(Vector3::X, roll);
+            let pitch = Self::from_axis_angle(Vector3::Y, pitch);
+            let yaw = Self::from_axis_angle(Vector3::Z, yaw);
+            yaw * pitch * roll
+        }
+
+        /// Returns the Euler angles `(roll, pitch, yaw)` of the rotation represented by the
+        /// unit quaternion, see [`Quaternion::from_euler_angles`].
+        ///
+        /// The pitch belongs to the range [-π/2, π/2], roll and yaw to the range [-π, π].
+        pub fn to_euler_angles(self) -> (Angle<T>, Angle<T>, Angle<T>) {
+            let Self { real: w, imag: Vector3 { x, y, z } } = self;
+            let two = T::ONE + T::ONE;
+
+            let roll = Angle::atan2(two * (w * x + y * z), T::ONE - two * (x * x + y * y));
+            let sin_pitch = two * (w * y - z * x);
+            let cos_pitch = T::hypot(T::ONE - two * (y * y + z * z), two * (x * y + w * z));
+            let pitch = Angle::atan2(sin_pitch, cos_pitch);
+            let yaw = Angle::atan2(two * (w * z + x * y), T::ONE - two * (y * y + z * z));
+
+            (roll, pitch, yaw)
+        }
+
+        /// Creates the unit quaternion representing the same rotation of the given matrix.
+        ///
+        /// The matrix must be orthogonal with positive determinant.
+        pub fn from_rotation_matrix(matrix: Matrix3<T>) -> Self {
+            let m00 = matrix.x_axis.x;
+            let m10 = matrix.x_axis.y;
+            let m20 = matrix.x_axis.z;
+            let m01 = matrix.y_axis.x;
+            let m11 = matrix.y_axis.y;
+            let m21 = matrix.y_axis.z;
+            let m02 = matrix.z_axis.x;
+            let m12 = matrix.z_axis.y;
+            let m22 = matrix.z_axis.z;
+
+            // Shepperd's method: the largest component is computed first to avoid the loss
+            // of precision in the divisions.
+            let trace = m00 + m11 + m22;
+            if trace > T::ZERO {
+                let s = (T::ONE + trace).sqrt();
+                let f = T::FRAC_1_2 / s;
+                Self::new(s * T::FRAC_1_2, (m21 - m12) * f, (m02 - m20) * f, (m10 - m01) * f)
+            } else if m00 > m11 && m00 > m22 {
+                let s = (T::ONE + m00 - m11 - m22).sqrt();
+                let f = T::FRAC_1_2 / s;
+                Self::new((m21 - m12) * f, s * T::FRAC_1_2, (m01 + m10) * f, (m02 + m20) * f)
+            } else if m11 > m22 {
+                let s = (T::ONE + m11 - m00 - m22).sqrt();
+                let f = T::FRAC_1_2 / s;
+                Self::new((m02 - m20) * f, (m01 + m10) * f, s * T::FRAC_1_2, (m12 + m21) * f)
+            } else {
+                let s = (T::ONE + m22 - m00 - m11).sqrt();
+                let f = T::FRAC_1_2 / s;
+                Self::new((m10 - m01) * f, (m02 + m20) * f, (m12 + m21) * f, s * T::FRAC_1_2)
+            }
+        }
+
+        /// Returns the rotation matrix of the rotation represented by the unit quaternion.
+        pub fn to_rotation_matrix(self) -> Matrix3<T> {
+            let Self { real: w, imag: Vector3 { x, y, z } } = self;
+            let two = T::ONE + T::ONE;
+
+            Matrix3::from_rows(
+                Vector3::new(
+                    T::ONE - two * (y * y + z * z),
+                    two * (x * y - w * z),
+                    two * (x * z + w * y),
+                ),
+                Vector3::new(
+                    two * (x * y + w * z),
+                    T::ONE - two * (x * x + z * z),
+                    two * (y * z - w * x),
+                ),
+                Vector3::new(
+                    two * (x * z - w * y),
+                    two * (y * z + w * x),
+                    T::ONE - two * (x * x + y * y),
+                ),
+            )
+        }
+
+        /// Returns the quaternion conjugate.
+        #[inline]
+        pub fn conj(self) -> Self {
+            Self::from_parts(self.real, -self.imag)
+        }
+
+        /// Computes the dot product between `self` and `other`.
+        #[inline]
+        pub fn dot(self, other: Self) -> T {
+            self.real * other.real + self.imag.dot(other.imag)
+        }
+
+        /// Computes the absolute value of the quaternion.
+        #[inline]
+        pub fn abs(self) -> T {
+            self.abs_square().sqrt()
+        }
+
+        /// Computes the square of absolute value of the quaternion.
+        #[inline]
+        pub fn abs_square(self) -> T {
+            self.real * self.real + self.imag.norm_square()
+        }
+
+        /// Returns the multiplicative inverse of the quaternion.
+        #[inline]
+        pub fn inverse(self) -> Self {
+            self.conj() / self.abs_square()
+        }
+
+        /// Returns `self` with absolute value equal to 1.
+        #[inline]
+        pub fn unit(self) -> Self {
+            self / self.abs()
+        }
+
+        /// Returns `self` with absolute value equal to 1 if possible, else `None`.
+        #[inline]
+        pub fn try_unit(self) -> Option<Self> {
+            let abs = self.abs();
+            (abs > T::ZERO).then(|| self / abs)
+        }
+
+        /// Rotates a vector using the rotation represented by the unit quaternion.
+        #[inline]
+        pub fn rotate_vector(self, vector: Vector3<T>) -> Vector3<T> {
+            let t = self.imag.cross(vector) * (T::ONE + T::ONE);
+            vector + t * self.real + self.imag.cross(t)
+        }
+
+        /// Rotates a point around the origin using the rotation represented by the unit
+        /// quaternion.
+        #[inline]
+        pub fn rotate_point(self, point: Point3<T>) -> Point3<T> {
+            Point3::ORIGIN + self.rotate_vector(point - Point3::ORIGIN)
+        }
+
+        /// Performs a linear interpolation between `self` and `rhs`, the result is
+        /// normalized.
+        ///
+        /// The interpolation follows the shortest path between the two rotations.
+        #[inline]
+        pub fn nlerp(self, other: Self, s: T) -> Self {
+            let other = if self.dot(other) < T::ZERO { -other } else { other };
+            (self + (other - self) * s).unit()
+        }
+
+        /// Performs a spherical linear interpolation between `self` and `rhs`.
+        ///
+        /// The interpolation follows the shortest path between the two rotations.
+        pub fn slerp(self, other: Self, s: T) -> Self {
+            let other = if self.dot(other) < T::ZERO { -other } else { other };
+
+            // the angle between the quaternions is computed with the half-angle formula,
+            // which is accurate also for almost parallel quaternions
+            let angle = Angle::atan2((self - other).abs(), (self + other).abs()) * (T::ONE + T::ONE);
+            let sin = angle.sin();
+            if sin == T::ZERO {
+                return self.nlerp(other, s);
+            }
+
+            (self * (angle * (T::ONE - s)).sin() + other * (angle * s).sin()) / sin
+        }
+    }
+}
+
+impl_multiplicative_group! {
+    impl Quaternion<T: RealField> {
+        /// The multiplicative identity element.
+        pub const ONE: Self = Self::new(T::ONE, T::ZERO, T::ZERO, T::ZERO);
+
+        fn mul(self, rhs: Self) -> Self {
+            Quaternion {
+                real: self.real * rhs.real - self.imag.dot(rhs.imag),
+                imag: rhs.imag * self.real + self.imag * rhs.real + self.imag.cross(rhs.imag),
+            }
+        }
+
+        fn div(self, rhs: Self) -> Self {
+            let num = self * rhs.conj();
+            let den = rhs.abs_square();
+            num / den
+        }
+    }
+}
+
+impl<T: RealField> Mul<Vector3<T>> for Quaternion<T> {
+    type Output = Vector3<T>;
+
+    #[inline]
+    fn mul(self, rhs: Vector3<T>) -> Self::Output {
+        self.rotate_vector(rhs)
+    }
+}
+
+impl<T: RealField> Mul<Point3<T>> for Quaternion<T> {
+    type Output = Point3<T>;
+
+    #[inline]
+    fn mul(self, rhs: Point3<T>) -> Self::Output {
+        self.rotate_point(rhs)
+    }
+}
+
+impl<T: RealField> From<Quaternion<T>> for Matrix3<T> {
+    #[inline]
+    fn from(value: Quaternion<T>) -> Self {
+        value.to_rotation_matrix()
+    }
+}
+
+impl<T: RealField> From<Quaternion<T>> for Matrix4<T> {
+    #[inline]
+    fn from(value: Quaternion<T>) -> Self {
+        value.to_rotation_matrix().into()
+    }
+}
+
+impl Quaternion<f32> {
+    /// Cast to [`f64`].
+    #[inline]
+    pub fn to_f64(self) -> Quaternion<f64> {
+        Quaternion {
+            real: self.real as f64,
+            imag: self.imag.to_f64(),
+        }
+    }
+}
+
+impl Quaternion<f64> {
+    /// Cast to [`f32`].
+    #[inline]
+    pub fn to_f32(self) -> Quaternion<f32> {
+        Quaternion {
+            real: self.real as f32,
+            imag: self.imag.to_f32(),
+        }
+    }
+}
+
+impl From<Quaternion<f32>> for Quaternion<f64> {
+    #[inline]
+    fn from(value: Quaternion<f32>) -> Self {
+        value.to_f64()
+    }
+}
+
+macro_rules! display_quaternion {
+    ($f:ident, $t:expr, $field:ident, $real:expr, $i:expr, $j:expr, $k:expr) => {
+        let parts = [$real, $i, $j, $k].map(|x| (x < $field::ZERO, x.abs()));
+
+        if let Some(precision) = $f.precision() {
+            write_quaternion(
+                $f,
+                parts[0].0,
+                format_args!(concat!("{:.1$", $t, "}"), parts[0].1, precision),
+                parts[1].0,
+                format_args!(concat!("{:.1$", $t, "}"), parts[1].1, precision),
+                parts[2].0,
+                format_args!(concat!("{:.1$", $t, "}"), parts[2].1, precision),
+                parts[3].0,
+                format_args!(concat!("{:.1$", $t, "}"), parts[3].1, precision),
+            )
+        } else {
+            write_quaternion(
+                $f,
+                parts[0].0,
+                format_args!(concat!("{:", $t, "}"), parts[0].1),
+                parts[1].0,
+                format_args!(concat!("{:", $t, "}"), parts[1].1),
+                parts[2].0,
+                format_args!(concat!("{:", $t, "}"), parts[2].1),
+                parts[3].0,
+                format_args!(concat!("{:", $t, "}"), parts[3].1),
+            )
+        }
+    };
+}
+
+#[allow(clippy::too_many_arguments)]
+fn write_quaternion(
+    f: &mut fmt::Formatter<'_>,
+    real_neg: bool,
+    real: fmt::Arguments<'_>,
+    i_neg: bool,
+    i: fmt::Arguments<'_>,
+    j_neg: bool,
+    j: fmt::Arguments<'_>,
+    k_neg: bool,
+    k: fmt::Arguments<'_>,
+) -> fmt::Result {
+    let real_sign = if real_neg {
+        "-"
+    } else if f.sign_plus() {
+        "+"
+    } else {
+        ""
+    };
+    let i_sign = if i_neg { "-" } else { "+" };
+    let j_sign = if j_neg { "-" } else { "+" };
+    let k_sign = if k_neg { "-" } else { "+" };
+
+    write!(f, "{real_sign}{real}{i_sign}{i}i{j_sign}{j}j{k_sign}{k}k")
+}
+
+impl<T: RealField + fmt::Display> fmt::Display for Quaternion<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Vector3 { x, y, z } = self.imag;
+        display_quaternion! {f, "", T, self.real, x, y, z}
+    }
+}
+
+impl<T: RealField + fmt::LowerExp> fmt::LowerExp for Quaternion<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Vector3 { x, y, z } = self.imag;
+        display_quaternion! {f, "e", T, self.real, x, y, z}
+    }
+}
+
+impl<T: RealField + fmt::UpperExp> fmt::UpperExp for Quaternion<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Vector3 { x, y, z } = self.imag;
+        display_quaternion! {f, "E", T, self.real, x, y, z}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{deg, point3, vec3},
+        *,
+    };
+
+    macro_rules! test_suite {
+        ($ty:ident) => {
+            use crate::*;
+
+            mod c {
+                #![allow(clippy::excessive_precision)]
+                #![allow(non_upper_case_globals)]
+
+                use super::*;
+
+                pub const _q1: Quaternion<$ty> = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+                pub const _q2: Quaternion<$ty> = Quaternion::new(5.0, 6.0, 7.0, 8.0);
+            }
+
+            #[test]
+            fn constructor_facility() {
+                assert_eq!(
+                    quaternion(1.0 as $ty, 2.0, 3.0, 4.0),
+                    Quaternion::from_parts(1.0, vec3(2.0, 3.0, 4.0))
+                );
+            }
+
+            #[test]
+            fn consts() {
+                assert_eq!(Quaternion::<$ty>::ZERO, quaternion(0.0, 0.0, 0.0, 0.0));
+                assert_eq!(Quaternion::<$ty>::ONE, quaternion(1.0, 0.0, 0.0, 0.0));
+                assert_eq!(Quaternion::<$ty>::I, quaternion(0.0, 1.0, 0.0, 0.0));
+                assert_eq!(Quaternion::<$ty>::J, quaternion(0.0, 0.0, 1.0, 0.0));
+                assert_eq!(Quaternion::<$ty>::K, quaternion(0.0, 0.0, 0.0, 1.0));
+            }
+
+            #[test]
+            fn hamilton_product() {
+                type Q = Quaternion<$ty>;
+
+                assert_eq!(Q::I * Q::I, -Q::ONE);
+                assert_eq!(Q::J * Q::J, -Q::ONE);
+                assert_eq!(Q::K * Q::K, -Q::ONE);
+                assert_eq!(Q::I * Q::J * Q::K, -Q::ONE);
+
+                assert_eq!(Q::I * Q::J, Q::K);
+                assert_eq!(Q::J * Q::K, Q::I);
+                assert_eq!(Q::K * Q::I, Q::J);
+                assert_eq!(Q::J * Q::I, -Q::K);
+
+                assert_eq!(c::_q1 * c::_q2, quaternion(-60.0, 12.0, 30.0, 24.0));
+                assert_eq!(c::_q2 * c::_q1, quaternion(-60.0, 20.0, 14.0, 32.0));
+                assert_eq!([c::_q1, c::_q2].into_iter().product::<Q>(), c::_q1 * c::_q2);
+            }
+
+            #[test]
+            fn div() {
+                assert_almost_eq!((c::_q1 * c::_q2) / c::_q2, c::_q1);
+
+                let mut q = c::_q1;
+                q /= c::_q1;
+                assert_almost_eq!(q, Quaternion::ONE);
+            }
+
+            #[test]
+            fn conj() {
+                assert_eq!(c::_q1.conj(), quaternion(1.0, -2.0, -3.0, -4.0));
+                assert_eq!(c::_q1 * c::_q1.conj(), quaternion(30.0, 0.0, 0.0, 0.0));
+            }
+
+            #[test]
+            fn abs() {
+                assert_eq!(quaternion::<$ty>(1.0, 1.0, 1.0, 1.0).abs(), 2.0);
+                assert_eq!(c::_q1.abs_square(), 30.0);
+                assert_almost_eq!(c::_q1.unit().abs(), 1.0);
+
+                assert!(c::_q1.try_unit().is_some());
+                assert!(Quaternion::<$ty>::ZERO.try_unit().is_none());
+            }
+
+            #[test]
+            fn inverse() {
+                assert_abs_diff_eq!(c::_q1 * c::_q1.inverse(), Quaternion::ONE);
+                assert_abs_diff_eq!(c::_q1.inverse() * c::_q1, Quaternion::ONE);
+            }
+
+            #[test]
+            fn axis_angle() {
+                let q = Quaternion::<$ty>::from_axis_angle(vec3(0.0, 0.0, 2.0), Angle::RIGHT);
+                assert_abs_diff_eq!(q * Vector3::X, Vector3::Y);
+                assert_abs_diff_eq!(q * Vector3::Y, -Vector3::X);
+                assert_abs_diff_eq!(q * Vector3::Z, Vector3::Z);
+
+                let (axis, angle) = q.to_axis_angle();
+                assert_abs_diff_eq!(axis, Vector3::Z);
+                assert_abs_diff_eq!(angle, Angle::RIGHT);
+
+                let (axis, angle) = Quaternion::<$ty>::ONE.to_axis_angle();
+                assert_eq!(axis, Vector3::ZERO);
+                assert_eq!(angle, Angle::ZERO);
+            }
+
+            #[test]
+            fn rotate() {
+                let axis = vec3::<$ty>(1.0, 1.0, 1.0);
+                let q = Quaternion::from_axis_angle(axis, Angle::FULL / 3.0);
+
+                assert_abs_diff_eq!(q * Vector3::X, Vector3::Y);
+                assert_abs_diff_eq!(q * Vector3::Y, Vector3::Z);
+                assert_abs_diff_eq!(q * axis, axis, 4.0 * $ty::EPSILON);
+                assert_abs_diff_eq!(q * point3(1.0, 0.0, 0.0), point3(0.0, 1.0, 0.0));
+
+                let composed = q * q * q;
+                assert_abs_diff_eq!(composed * Vector3::X, Vector3::X);
+            }
+
+            #[test]
+            fn rotation_matrix() {
+                let axis = vec3::<$ty>(1.0, -2.0, 3.0);
+                let angles = [deg(10.0), deg(90.0), deg(170.0), deg(-120.0)];
+
+                for angle in angles {
+                    let q = Quaternion::from_axis_angle(axis, angle);
+                    let m = q.to_rotation_matrix();
+                    assert_abs_diff_eq!(
+                        m,
+                        Matrix3::from_axis_angle(axis, angle),
+                        4.0 * $ty::EPSILON
+                    );
+
+                    let v = vec3(0.5, -1.0, 2.0);
+                    assert_abs_diff_eq!(m * v, q * v, 8.0 * $ty::EPSILON);
+
+                    let r = Quaternion::from_rotation_matrix(m);
+                    let r = if r.dot(q) < 0.0 { -r } else { r };
+                    assert_abs_diff_eq!(r, q, 4.0 * $ty::EPSILON);
+                }
+
+                let m = Matrix4::from(Quaternion::<$ty>::ONE);
+                assert_eq!(m, Matrix4::IDENTITY);
+            }
+
+            #[test]
+            fn euler_angles() {
+                let q = Quaternion::<$ty>::from_euler_angles(Angle::ZERO, Angle::ZERO, deg(30.0));
+                assert_abs_diff_eq!(q, Quaternion::from_axis_angle(Vector3::Z, deg(30.0)));
+
+                let (roll, pitch, yaw) = (deg(10.0), deg(-20.0), deg(30.0));
+                let q = Quaternion::<$ty>::from_euler_angles(roll, pitch, yaw);
+                let expected = Matrix3::from_axis_angle(Vector3::Z, yaw)
+                    * Matrix3::from_axis_angle(Vector3::Y, pitch)
+                    * Matrix3::from_axis_angle(Vector3::X, roll);
+                assert_abs_diff_eq!(q.to_rotation_matrix(), expected, 4.0 * $ty::EPSILON);
+
+                let angles = q.to_euler_angles();
+                assert_abs_diff_eq!(angles.0, roll, 4.0 * $ty::EPSILON);
+                assert_abs_diff_eq!(angles.1, pitch, 4.0 * $ty::EPSILON);
+                assert_abs_diff_eq!(angles.2, yaw, 4.0 * $ty::EPSILON);
+
+                let q =
+                    Quaternion::<$ty>::from_euler_angles(Angle::ZERO, Angle::RIGHT, Angle::ZERO);
+                assert_abs_diff_eq!(q.to_euler_angles().1, Angle::RIGHT);
+            }
+
+            #[test]
+            fn nlerp_and_slerp() {
+                let a = Quaternion::<$ty>::from_axis_angle(Vector3::Z, deg(10.0));
+                let b = Quaternion::<$ty>::from_axis_angle(Vector3::Z, deg(70.0));
+
+                assert_abs_diff_eq!(a.slerp(b, 0.0), a);
+                assert_abs_diff_eq!(a.slerp(b, 1.0), b);
+                assert_abs_diff_eq!(
+                    a.slerp(b, 0.25),
+                    Quaternion::from_axis_angle(Vector3::Z, deg(25.0))
+                );
+                assert_abs_diff_eq!(a.slerp(-b, 0.5), a.slerp(b, 0.5));
+                assert_abs_diff_eq!(a.slerp(a, 0.5), a);
+
+                assert_abs_diff_eq!(a.nlerp(b, 0.0), a);
+                assert_abs_diff_eq!(a.nlerp(b, 1.0), b);
+                assert_abs_diff_eq!(
+                    a.nlerp(b, 0.5),
+                    Quaternion::from_axis_angle(Vector3::Z, deg(40.0))
+                );
+                assert_almost_eq!(a.nlerp(b, 0.3).abs(), 1.0);
+            }
+
+            #[test]
+            fn display() {
+                assert_eq!(format!("{}", c::_q1), "1+2i+3j+4k");
+                assert_eq!(format!("{}", -c::_q1), "-1-2i-3j-4k");
+                assert_eq!(format!("{:+}", c::_q1), "+1+2i+3j+4k");
+                assert_eq!(format!("{:e}", c::_q1), "1e0+2e0i+3e0j+4e0k");
+                assert_eq!(format!("{:E}", c::_q1.conj()), "1E0-2E0i-3E0j-4E0k");
+                assert_eq!(
+                    format!("{:.1}", quaternion::<$ty>(1.23, -2.67, 0.0, 1.0)),
+                    "1.2-2.7i+0.0j+1.0k"
+                );
+            }
+
+            #[test]
+            fn approx_eq() {
+                let lhs = c::_q1;
+                let rhs = c::_q1 + Quaternion::ONE * $ty::EPSILON;
+
+                assert_ne!(lhs, rhs);
+                assert_almost_eq!(lhs, rhs);
+                assert_relative_eq!(lhs, rhs);
+                assert_abs_diff_ne!(lhs, c::_q2);
+            }
+        };
+    }
+
+    mod f32 {
+        use super::*;
+        test_suite!(f32);
+
+        #[test]
+        fn cast_to_f64() {
+            let q = quaternion(1.0f32, 2.0, 3.0, 4.0);
+
+            assert_eq!(q.to_f64(), quaternion(1.0f64, 2.0, 3.0, 4.0));
+            assert_eq!(
+                Quaternion::<f64>::from(q),
+                quaternion(1.0f64, 2.0, 3.0, 4.0)
+            );
+        }
+    }
+
+    mod f64 {
+        use super::*;
+        test_suite!(f64);
+
+        #[test]
+        fn cast_to_f32() {
+            let q = quaternion(1.0f64, 2.0, 3.0, 4.0);
+
+            assert_eq!(q.to_f32(), quaternion(1.0f32, 2.0, 3.0, 4.0));
+        }
+    }
+}
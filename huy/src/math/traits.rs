@@ -3,6 +3,12 @@ use core::ops::{Add, Div, Mul, Neg, Sub};
 use super::Complex;
 
 /// A trait for a type that can represent a real number.
+///
+/// The trait is implemented for [`f32`], [`f64`] and the dual numbers
+/// [`Dual`](super::Dual) and [`DualN`](super::DualN). External types can implement it
+/// only when the `unsealed` feature is enabled, the implementation is expected to
+/// follow the semantic of the floating point types. The functions in
+/// [`conformance`](super::conformance) can be used to check the implementation.
 pub trait RealField:
     Copy
     + Add<Output = Self>
@@ -32,15 +38,60 @@ pub trait RealField:
     /// The value 2π.
     const TAU: Self;
 
+    /// Euler's number (e).
+    const E: Self;
+
+    /// The value ln(2).
+    const LN_2: Self;
+
+    /// The value √2.
+    const SQRT_2: Self;
+
+    /// The difference between 1 and the next larger representable number.
+    const EPSILON: Self;
+
+    /// The smallest positive normal number.
+    const MIN_POSITIVE: Self;
+
+    /// The largest finite number.
+    const MAX: Self;
+
+    /// Positive infinity.
+    const INFINITY: Self;
+
+    /// Not a Number (NaN).
+    const NAN: Self;
+
     /// Computes the distance between the origin and the point with coordinates `x` and `y`.
     fn hypot(x: Self, y: Self) -> Self;
 
     /// Compute the absolute value of `self`.
     fn abs(self) -> Self;
 
+    /// Returns a number with the magnitude of `self` and the sign of `sign`.
+    fn copysign(self, sign: Self) -> Self;
+
+    /// Returns a number that represents the sign of `self`.
+    fn signum(self) -> Self;
+
     /// Returns `true` if the number is Nan.
     fn is_nan(self) -> bool;
 
+    /// Returns `true` if the number is neither infinite nor NaN.
+    fn is_finite(self) -> bool;
+
+    /// Returns `true` if the number is positive or negative infinity.
+    fn is_infinite(self) -> bool;
+
+    /// Returns `true` if the number is exactly zero, including any additional data it
+    /// carries such as the derivatives of a dual number.
+    ///
+    /// Algorithms use it to take shortcuts that are valid only for a true zero.
+    #[inline]
+    fn is_exact_zero(self) -> bool {
+        self == Self::ZERO
+    }
+
     /// Converts from radians to degrees.
     fn to_degrees(self) -> Self;
 
@@ -104,6 +155,45 @@ pub trait RealField:
     /// Computes the four quadrant arc-tangent.
     fn atan2(y: Self, x: Self) -> Self;
 
+    /// Simultaneously computes the sine and cosine of the angle.
+    fn sin_cos(self) -> (Self, Self);
+
+    /// Computes the hyperbolic sine of a number.
+    fn sinh(self) -> Self;
+
+    /// Computes the hyperbolic cosine of a number.
+    fn cosh(self) -> Self;
+
+    /// Computes the hyperbolic tangent of a number.
+    fn tanh(self) -> Self;
+
+    /// Computes the inverse hyperbolic sine of a number.
+    fn asinh(self) -> Self;
+
+    /// Returns `e^(self)`.
+    fn exp(self) -> Self;
+
+    /// Returns `e^(self) - 1`, accurate even if the number is close to zero.
+    fn exp_m1(self) -> Self;
+
+    /// Returns the natural logarithm of the number.
+    fn ln(self) -> Self;
+
+    /// Returns `ln(1 + self)`, more accurate than the separate operations.
+    fn ln_1p(self) -> Self;
+
+    /// Returns the base 2 logarithm of the number.
+    fn log2(self) -> Self;
+
+    /// Raises a number to a floating point power.
+    fn powf(self, n: Self) -> Self;
+
+    /// Raises a number to an integer power.
+    fn powi(self, n: i32) -> Self;
+
+    /// Computes the fused multiply-add `(self * a) + b` with only one rounding error.
+    fn mul_add(self, a: Self, b: Self) -> Self;
+
     /// Computes the least non-negative remainder, the return value `r` satisfies
     /// `0.0 <= r <= rhs.abs()`.
     fn rem_euclid(self, rhs: Self) -> Self;
@@ -114,8 +204,31 @@ pub trait RealField:
     /// Returns the square root of the number.
     fn sqrt(self) -> Self;
 
+    /// Returns the cube root of the number.
+    fn cbrt(self) -> Self;
+
+    /// Returns the largest integer less than or equal to `self`.
+    fn floor(self) -> Self;
+
+    /// Returns the smallest integer greater than or equal to `self`.
+    fn ceil(self) -> Self;
+
+    /// Returns the nearest integer to `self`, half-way cases are rounded away from zero.
+    fn round(self) -> Self;
+
+    /// Returns the integer part of `self`.
+    fn trunc(self) -> Self;
+
+    /// Returns the minimum of the two numbers.
+    fn min(self, other: Self) -> Self;
+
     /// Returns the maximum of the two numbers.
     fn max(self, other: Self) -> Self;
+
+    /// Restricts the number to the interval [`min`, `max`].
+    ///
+    /// Panics if `min > max` or if any of them is NaN.
+    fn clamp(self, min: Self, max: Self) -> Self;
 }
 
 macro_rules! forward {
@@ -145,11 +258,23 @@ macro_rules! impl_real_field_for_float {
             const FRAC_PI_2: Self = core::$ty::consts::FRAC_PI_2;
             const PI: Self = core::$ty::consts::PI;
             const TAU: Self = core::$ty::consts::TAU;
+            const E: Self = core::$ty::consts::E;
+            const LN_2: Self = core::$ty::consts::LN_2;
+            const SQRT_2: Self = core::$ty::consts::SQRT_2;
+            const EPSILON: Self = $ty::EPSILON;
+            const MIN_POSITIVE: Self = $ty::MIN_POSITIVE;
+            const MAX: Self = $ty::MAX;
+            const INFINITY: Self = $ty::INFINITY;
+            const NAN: Self = $ty::NAN;
 
             forward! {
                 fn hypot(x: Self, y: Self) -> Self;
                 fn abs(self) -> Self;
+                fn copysign(self, sign: Self) -> Self;
+                fn signum(self) -> Self;
                 fn is_nan(self) -> bool;
+                fn is_finite(self) -> bool;
+                fn is_infinite(self) -> bool;
                 fn to_degrees(self) -> Self;
                 fn to_radians(self) -> Self;
                 fn sin(self) -> Self;
@@ -159,10 +284,34 @@ macro_rules! impl_real_field_for_float {
                 fn tan(self) -> Self;
                 fn atan(self) -> Self;
                 fn atan2(y: Self, x: Self) -> Self;
+                fn sinh(self) -> Self;
+                fn cosh(self) -> Self;
+                fn tanh(self) -> Self;
+                fn asinh(self) -> Self;
+                fn exp(self) -> Self;
+                fn exp_m1(self) -> Self;
+                fn ln(self) -> Self;
+                fn ln_1p(self) -> Self;
+                fn log2(self) -> Self;
+                fn powf(self, n: Self) -> Self;
+                fn powi(self, n: i32) -> Self;
+                fn mul_add(self, a: Self, b: Self) -> Self;
                 fn rem_euclid(self, rhs: Self) -> Self;
                 fn recip(self) -> Self;
                 fn sqrt(self) -> Self;
+                fn cbrt(self) -> Self;
+                fn floor(self) -> Self;
+                fn ceil(self) -> Self;
+                fn round(self) -> Self;
+                fn trunc(self) -> Self;
+                fn min(self, other: Self) -> Self;
                 fn max(self, other: Self) -> Self;
+                fn clamp(self, min: Self, max: Self) -> Self;
+            }
+
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                self.sin_cos()
             }
         }
     };
@@ -280,8 +429,16 @@ mod sealed {
 
     pub trait RealField {}
 
+    #[cfg(not(feature = "unsealed"))]
     impl RealField for f32 {}
+    #[cfg(not(feature = "unsealed"))]
     impl RealField for f64 {}
+    #[cfg(not(feature = "unsealed"))]
+    impl<T: super::RealField> RealField for crate::math::Dual<T> {}
+    #[cfg(not(feature = "unsealed"))]
+    impl<T: super::RealField, const N: usize> RealField for crate::math::DualN<T, N> {}
+    #[cfg(feature = "unsealed")]
+    impl<T> RealField for T {}
 
     pub trait Field {}
 
@@ -1,4 +1,4 @@
-use super::{macros::*, Complex, Field, RealField};
+use super::{macros::*, Angle, Complex, Field, RealField, Vector4};
 
 /// Create a new [`Vector3`] from its components.
 #[inline]
@@ -40,6 +40,91 @@ impl_vector_space! {
         pub fn lerp(self, other: Self, s: T::Real) -> Self {
             self + (other - self) * T::from(s)
         }
+
+        /// Returns a 4-dimensional vector with `w` as last component.
+        #[inline]
+        pub fn extend(self, w: T) -> Vector4<T> {
+            Vector4::new(self.x, self.y, self.z, w)
+        }
+
+        /// Computes the cross product between `self` and `other`.
+        ///
+        /// The components are not conjugated.
+        #[inline]
+        pub fn cross(self, other: Self) -> Self {
+            Self::new(
+                self.y * other.z - self.z * other.y,
+                self.z * other.x - self.x * other.z,
+                self.x * other.y - self.y * other.x,
+            )
+        }
+
+        /// Computes the scalar triple product `self · (b × c)`, the components are not
+        /// conjugated.
+        ///
+        /// It is equal to the determinant of the matrix with columns `self`, `b` and `c`.
+        #[inline]
+        pub fn scalar_triple_product(self, b: Self, c: Self) -> T {
+            let cross = b.cross(c);
+            self.x * cross.x + self.y * cross.y + self.z * cross.z
+        }
+
+        /// Computes the vector triple product `self × (b × c)`.
+        #[inline]
+        pub fn vector_triple_product(self, b: Self, c: Self) -> Self {
+            self.cross(b.cross(c))
+        }
+
+        /// Returns the projection of `self` onto `other`.
+        #[inline]
+        pub fn project_onto(self, other: Self) -> Self {
+            other * (other.dot(self) / T::from(other.norm_square()))
+        }
+
+        /// Returns the rejection of `self` from `other`, the component of `self`
+        /// orthogonal to `other`.
+        #[inline]
+        pub fn reject_from(self, other: Self) -> Self {
+            self - self.project_onto(other)
+        }
+
+        /// Returns the reflection of `self` across the plane orthogonal to `normal`.
+        #[inline]
+        pub fn reflect(self, normal: Self) -> Self {
+            self - self.project_onto(normal) * (T::ONE + T::ONE)
+        }
+    }
+}
+
+impl<T: RealField> Vector3<T> {
+    /// Computes the angle between `self` and `other`.
+    ///
+    /// The angle belongs to the range [0, π].
+    #[inline]
+    pub fn angle_between(self, other: Self) -> Angle<T> {
+        let cross = self.cross(other);
+        let sin = (cross.x * cross.x + cross.y * cross.y + cross.z * cross.z).sqrt();
+        Angle::atan2(sin, self.dot(other))
+    }
+
+    /// Returns two unit vectors such that together with `self` they form an orthonormal
+    /// right-handed basis.
+    ///
+    /// `self` must be a unit vector.
+    /// See [Building an Orthonormal Basis, Revisited](https://jcgt.org/published/0006/01/01/).
+    #[inline]
+    pub fn any_orthonormal_basis(self) -> (Self, Self) {
+        let sign = if self.z < T::ZERO { -T::ONE } else { T::ONE };
+        let a = -(sign + self.z).recip();
+        let b = self.x * self.y * a;
+        (
+            Self::new(
+                T::ONE + sign * self.x * self.x * a,
+                sign * b,
+                -sign * self.x,
+            ),
+            Self::new(b, sign + self.y * self.y * a, -self.y),
+        )
     }
 }
 
@@ -119,6 +204,38 @@ mod tests {
                 assert_eq!(c::_zero.unit_or(c::_v1), c::_v1);
             }
 
+            #[test]
+            fn norm_extreme_magnitudes() {
+                let frac_1_sqrt_2 = 0.5 * <$ty>::SQRT_2;
+
+                let v = vec3::<$ty>(<$ty>::MAX, <$ty>::MAX, 0.0);
+                assert_eq!(v.norm(), <$ty>::INFINITY);
+                assert_almost_eq!(v.unit(), vec3(frac_1_sqrt_2, frac_1_sqrt_2, 0.0));
+                assert!(v.try_unit().is_some());
+
+                let big = <$ty>::MAX * 0.5;
+                let v = vec3::<$ty>(big, -big, 0.0);
+                assert_relative_eq!(v.norm(), big * <$ty>::SQRT_2);
+                assert_almost_eq!(v.unit(), vec3(frac_1_sqrt_2, -frac_1_sqrt_2, 0.0));
+
+                let tiny = <$ty>::MIN_POSITIVE * <$ty>::EPSILON;
+                let v = vec3::<$ty>(3.0 * tiny, 0.0, -4.0 * tiny);
+                assert!(v.norm_square() == 0.0);
+                assert_eq!(v.norm(), 5.0 * tiny);
+                assert_almost_eq!(v.unit(), vec3(0.6, 0.0, -0.8));
+                assert!(v.try_unit().is_some());
+
+                assert_eq!(c::_zero.norm(), 0.0);
+                let v = vec3::<$ty>(1.0, -<$ty>::INFINITY, 0.0);
+                assert_eq!(v.norm(), <$ty>::INFINITY);
+                assert!(v.try_unit().is_none());
+                assert!(vec3::<$ty>(1.0, <$ty>::NAN, 0.0).norm().is_nan());
+                assert!(vec3::<$ty>(<$ty>::NAN, 0.0, 0.0).norm().is_nan());
+                assert!(vec3::<$ty>(0.0, <$ty>::NAN, 1.0).norm().is_nan());
+                assert!(vec3::<$ty>(<$ty>::NAN, 0.0, 0.0).try_unit().is_none());
+                assert!(vec3::<$ty>(0.0, <$ty>::NAN, 1.0).try_unit().is_none());
+            }
+
             #[test]
             fn lerp() {
                 assert_eq!(c::_v1.lerp(c::_v2, 0.0), c::_v1);
@@ -126,6 +243,91 @@ mod tests {
                 assert_almost_eq!(c::_v1.lerp(c::_v2, 0.5), vec3::<$ty>(2.5, 3.5, 4.5));
             }
 
+            #[test]
+            fn extend() {
+                assert_eq!(c::_v1.extend(4.0), Vector4::new(1.0, 2.0, 3.0, 4.0));
+            }
+
+            #[test]
+            fn cross() {
+                assert_eq!(Vector3::<$ty>::X.cross(Vector3::Y), Vector3::Z);
+                assert_eq!(Vector3::<$ty>::Y.cross(Vector3::Z), Vector3::X);
+                assert_eq!(Vector3::<$ty>::Z.cross(Vector3::X), Vector3::Y);
+                assert_eq!(c::_v1.cross(c::_v2), vec3(-3.0, 6.0, -3.0));
+                assert_eq!(c::_v1.cross(c::_v1), c::_zero);
+            }
+
+            #[test]
+            fn triple_products() {
+                assert_eq!(c::_v1.scalar_triple_product(c::_v2, c::_v3), -60.0);
+                assert_eq!(
+                    c::_v1.scalar_triple_product(c::_v2, c::_v3),
+                    c::_v3.scalar_triple_product(c::_v1, c::_v2)
+                );
+                assert_eq!(c::_v1.scalar_triple_product(c::_v2, c::_v1), 0.0);
+
+                // BAC-CAB rule
+                assert_eq!(
+                    c::_v1.vector_triple_product(c::_v2, c::_v3),
+                    c::_v2 * c::_v1.dot(c::_v3) - c::_v3 * c::_v1.dot(c::_v2)
+                );
+            }
+
+            #[test]
+            fn angle_between() {
+                assert_almost_eq!(Vector3::<$ty>::X.angle_between(Vector3::Y), Angle::RIGHT);
+                assert_almost_eq!(
+                    Vector3::<$ty>::X.angle_between(-Vector3::X),
+                    Angle::STRAIGHT
+                );
+                assert_eq!(c::_v1.angle_between(c::_v1 * 2.0), Angle::ZERO);
+                assert_almost_eq!(
+                    vec3::<$ty>(1.0, 1.0, 0.0).angle_between(Vector3::X),
+                    Angle::RIGHT / 2.0
+                );
+            }
+
+            #[test]
+            fn project_reject_reflect() {
+                let v = vec3::<$ty>(1.0, 2.0, 3.0);
+                let n = vec3::<$ty>(0.0, 0.0, 2.0);
+
+                assert_eq!(v.project_onto(n), vec3(0.0, 0.0, 3.0));
+                assert_eq!(v.reject_from(n), vec3(1.0, 2.0, 0.0));
+                assert_eq!(v.reflect(n), vec3(1.0, 2.0, -3.0));
+
+                let p = c::_v1.project_onto(c::_v2);
+                let r = c::_v1.reject_from(c::_v2);
+                assert_almost_eq!(p + r, c::_v1);
+                assert_abs_diff_eq!(r.dot(c::_v2), 0.0, 16.0 * <$ty>::EPSILON);
+                assert_almost_eq!(c::_v1.reflect(c::_v2).norm(), c::_v1_norm);
+            }
+
+            #[test]
+            fn any_orthonormal_basis() {
+                let normals = [
+                    Vector3::<$ty>::X,
+                    Vector3::Y,
+                    Vector3::Z,
+                    -Vector3::Z,
+                    c::_v1.unit(),
+                    c::_v3.unit(),
+                    -c::_v3.unit(),
+                    vec3(1e-7, 0.0, -1.0).unit(),
+                ];
+
+                for n in normals {
+                    let (b1, b2) = n.any_orthonormal_basis();
+
+                    assert_abs_diff_eq!(b1.norm(), 1.0, 4.0 * <$ty>::EPSILON);
+                    assert_abs_diff_eq!(b2.norm(), 1.0, 4.0 * <$ty>::EPSILON);
+                    assert_abs_diff_eq!(b1.dot(b2), 0.0, 4.0 * <$ty>::EPSILON);
+                    assert_abs_diff_eq!(b1.dot(n), 0.0, 4.0 * <$ty>::EPSILON);
+                    assert_abs_diff_eq!(b2.dot(n), 0.0, 4.0 * <$ty>::EPSILON);
+                    assert_abs_diff_eq!(b1.cross(b2), n, 4.0 * <$ty>::EPSILON);
+                }
+            }
+
             #[test]
             fn array_conversion() {
                 let v: Vector3<$ty> = vec3(1.0, 2.0, 3.0);
@@ -202,6 +404,41 @@ mod tests {
                 assert_almost_eq!(c::_v2.norm(), c::_v2_norm);
             }
 
+            #[test]
+            fn norm_extreme_magnitudes() {
+                let big = <$ty>::MAX * 0.25;
+                let v: Vector3<Complex<$ty>> =
+                    vec3(complex(big, big), complex(-big, 0.0), complex(0.0, big));
+                assert_relative_eq!(v.norm(), 2.0 * big);
+                assert_almost_eq!(v.unit().norm(), 1.0);
+
+                let tiny = <$ty>::MIN_POSITIVE * <$ty>::EPSILON;
+                let v: Vector3<Complex<$ty>> =
+                    vec3(complex(tiny, tiny), complex(tiny, 0.0), complex(0.0, -tiny));
+                assert_eq!(v.norm(), 2.0 * tiny);
+                let expected: Vector3<Complex<$ty>> =
+                    vec3(complex(0.5, 0.5), complex(0.5, 0.0), complex(0.0, -0.5));
+                assert_almost_eq!(v.unit(), expected);
+            }
+
+            #[test]
+            fn cross() {
+                let expected: Vector3<Complex<$ty>> =
+                    vec3(complex(0.0, -24.0), complex(0.0, 48.0), complex(0.0, -24.0));
+                assert_eq!(c::_v1.cross(c::_v2), expected);
+                assert_eq!(c::_v1.scalar_triple_product(c::_v2, c::_v1), Complex::ZERO);
+            }
+
+            #[test]
+            fn project_and_reject() {
+                let p = c::_v1.project_onto(c::_v2);
+                let r = c::_v1.reject_from(c::_v2);
+
+                assert_almost_eq!(p + r, c::_v1);
+                assert_abs_diff_eq!(c::_v2.dot(r), Complex::ZERO, 64.0 * <$ty>::EPSILON);
+                assert_almost_eq!(c::_v1.reflect(c::_v2).norm(), c::_v1_norm);
+            }
+
             #[test]
             fn mul_and_div_by_real() {
                 let expected: Vector3<Complex<$ty>> =
@@ -0,0 +1,181 @@
+use super::{macros::*, Complex, Field, RealField, Vector3};
+
+/// Create a new [`Vector4`] from its components.
+#[inline]
+pub const fn vec4<T: Field>(x: T, y: T, z: T, w: T) -> Vector4<T> {
+    Vector4::new(x, y, z, w)
+}
+
+impl_vector_space! {
+    /// A 4-dimensional vector.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[repr(C)]
+    pub struct Vector4<T: Field> {
+        /// The x component of the vector.
+        pub(super) x: T,
+        /// The y component of the vector.
+        pub(super) y: T,
+        /// The z component of the vector.
+        pub(super) z: T,
+        /// The w component of the vector.
+        pub(super) w: T,
+    }
+
+    impl<T: Field> Vector4<T> {
+        /// A unit vector parallel to the X axis.
+        pub const X: Self = Self::new(T::ONE, T::ZERO, T::ZERO, T::ZERO);
+
+        /// A unit vector parallel to the Y axis.
+        pub const Y: Self = Self::new(T::ZERO, T::ONE, T::ZERO, T::ZERO);
+
+        /// A unit vector parallel to the Z axis.
+        pub const Z: Self = Self::new(T::ZERO, T::ZERO, T::ONE, T::ZERO);
+
+        /// A unit vector parallel to the W axis.
+        pub const W: Self = Self::new(T::ZERO, T::ZERO, T::ZERO, T::ONE);
+
+        /// Construct a new vector from its components.
+        #[inline]
+        pub const fn new(x: T, y: T, z: T, w: T) -> Self {
+            Self { x, y, z, w }
+        }
+
+        /// Performs a linear interpolation between `self`` and `rhs`.
+        #[inline]
+        pub fn lerp(self, other: Self, s: T::Real) -> Self {
+            self + (other - self) * T::from(s)
+        }
+
+        /// Returns the vector with the first three components.
+        #[inline]
+        pub fn truncate(self) -> Vector3<T> {
+            Vector3::new(self.x, self.y, self.z)
+        }
+    }
+}
+
+impl_vector_norms!(Vector4 { x, y, z, w });
+impl_complex_vector!(Vector4 { x, y, z, w });
+impl_vector_ops_for_float!(Vector4 { x, y, z, w });
+
+impl_aggregate_conversion!(From<[T; 4]> for Vector4<T: Field> { x, y, z, w });
+impl_aggregate_conversion!(From<(T, T, T, T)> for Vector4<T: Field> { x, y, z, w });
+
+#[cfg(test)]
+mod tests {
+    use super::{super::vec3, *};
+
+    macro_rules! real_test_suite {
+        ($ty:ty) => {
+            use crate::*;
+
+            mod c {
+                #![allow(clippy::excessive_precision)]
+                #![allow(non_upper_case_globals)]
+
+                use super::*;
+
+                pub const _zero: Vector4<$ty> = Vector4::ZERO;
+
+                pub const _v1: Vector4<$ty> = Vector4::new(1.0, 2.0, 3.0, 4.0);
+                pub const _v2: Vector4<$ty> = Vector4::new(5.0, 6.0, 7.0, 8.0);
+                pub const _v3: Vector4<$ty> = Vector4::new(5.0, -6.0, 7.0, -8.0);
+
+                pub const _v1_norm: $ty = 5.477225575051661;
+                pub const _v2_norm: $ty = 13.190905958272919;
+                pub const _v3_norm_l1: $ty = 26.0;
+                pub const _v3_norm_linf: $ty = 8.0;
+            }
+
+            #[test]
+            fn consts() {
+                assert_eq!(Vector4::<$ty>::ZERO, vec4::<$ty>(0.0, 0.0, 0.0, 0.0));
+                assert_eq!(Vector4::<$ty>::X, vec4::<$ty>(1.0, 0.0, 0.0, 0.0));
+                assert_eq!(Vector4::<$ty>::Y, vec4::<$ty>(0.0, 1.0, 0.0, 0.0));
+                assert_eq!(Vector4::<$ty>::Z, vec4::<$ty>(0.0, 0.0, 1.0, 0.0));
+                assert_eq!(Vector4::<$ty>::W, vec4::<$ty>(0.0, 0.0, 0.0, 1.0));
+            }
+
+            #[test]
+            fn constructor() {
+                assert_eq!(c::_zero, vec4::<$ty>(0.0, 0.0, 0.0, 0.0));
+                assert_eq!(c::_v1, vec4::<$ty>(1.0, 2.0, 3.0, 4.0));
+            }
+
+            #[test]
+            fn dot() {
+                assert_almost_eq!(c::_v1.dot(c::_v2), 70.0);
+            }
+
+            #[test]
+            fn norm() {
+                assert_almost_eq!(c::_v1.norm(), c::_v1_norm);
+                assert_almost_eq!(c::_v2.norm(), c::_v2_norm);
+
+                assert_almost_eq!(c::_v1.norm_square(), 30.0);
+                assert_almost_eq!(c::_v2.norm_square(), 174.0);
+
+                assert_almost_eq!(c::_v3.norm_l1(), c::_v3_norm_l1);
+                assert_almost_eq!(c::_v3.norm_linf(), c::_v3_norm_linf);
+            }
+
+            #[test]
+            fn unit() {
+                assert_almost_eq!(c::_v1.unit().norm(), 1.0);
+                assert_almost_eq!(c::_v2.unit().norm(), 1.0);
+
+                assert!(c::_v1.try_unit().is_some());
+                assert!(c::_zero.try_unit().is_none());
+            }
+
+            #[test]
+            fn truncate() {
+                assert_eq!(c::_v1.truncate(), vec3(1.0, 2.0, 3.0));
+            }
+
+            #[test]
+            fn array_conversion() {
+                let v: Vector4<$ty> = vec4(1.0, 2.0, 3.0, 4.0);
+                let a: [$ty; 4] = [1.0, 2.0, 3.0, 4.0];
+
+                assert_eq!(a, <[$ty; 4]>::from(v));
+                assert_eq!(v, Vector4::from(a));
+            }
+
+            #[test]
+            fn tuple_conversion() {
+                let v: Vector4<$ty> = vec4(1.0, 2.0, 3.0, 4.0);
+                let t: ($ty, $ty, $ty, $ty) = (1.0, 2.0, 3.0, 4.0);
+
+                assert_eq!(t, <($ty, $ty, $ty, $ty)>::from(v));
+                assert_eq!(v, Vector4::from(t));
+            }
+        };
+    }
+
+    mod f32 {
+        use super::*;
+        real_test_suite!(f32);
+
+        #[test]
+        fn to_f64() {
+            let v_f32: Vector4<f32> = vec4(1.0, 2.0, 3.0, 4.0);
+            let v_f64: Vector4<f64> = vec4(1.0, 2.0, 3.0, 4.0);
+
+            assert_eq!(v_f32.to_f64(), v_f64);
+            assert_eq!(Vector4::<f64>::from(v_f32), v_f64);
+        }
+    }
+
+    mod f64 {
+        use super::*;
+        real_test_suite!(f64);
+
+        #[test]
+        fn to_f32() {
+            let v_f32: Vector4<f32> = vec4(1.0, 2.0, 3.0, 4.0);
+            let v_f64: Vector4<f64> = vec4(1.0, 2.0, 3.0, 4.0);
+            assert_eq!(v_f64.to_f32(), v_f32);
+        }
+    }
+}
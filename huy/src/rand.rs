@@ -1,8 +1,34 @@
 //! Random number generator.
 
-pub use self::{float::*, integer::*, random::*, rng::*};
+pub use self::{
+    chacha::*, continuous::*, discrete::*, distribution::*, float::*, geometry::*, integer::*,
+    normal::*, pcg::*, qmc::*, random::*, rng::*, seed::*, seq::*, thread::*, weighted::*,
+    wyrand::*, xoshiro128::*,
+};
 
+#[cfg(feature = "rand_core")]
+pub use self::compat::*;
+
+mod chacha;
+#[cfg(feature = "rand_core")]
+mod compat;
+mod continuous;
+mod discrete;
+mod distribution;
 mod float;
+mod geometry;
 mod integer;
+mod normal;
+mod pcg;
+mod qmc;
 mod random;
 mod rng;
+mod seed;
+mod seq;
+mod thread;
+mod weighted;
+mod wyrand;
+mod xoshiro128;
+
+#[cfg(test)]
+mod testing;
@@ -0,0 +1,289 @@
+use core::fmt;
+
+use super::{RandomSource, SplitMix64};
+
+/// The ChaCha random number generator with 20 rounds, it is cryptographically secure.
+pub type ChaCha20Rng = ChaChaRng<20>;
+
+/// The ChaCha random number generator with 12 rounds.
+pub type ChaCha12Rng = ChaChaRng<12>;
+
+/// The ChaCha random number generator with 8 rounds.
+pub type ChaCha8Rng = ChaChaRng<8>;
+
+/// Random number generator based on the ChaCha stream cipher with `ROUNDS` rounds.
+///
+/// The output is the keystream of the cipher, the seed is the key. The 128 bits input
+/// is split in a 64 bits block counter and a 64 bits stream, then each of the `2^64`
+/// streams contains `2^68` words of 32 bits. The block function is the one described
+/// in [RFC 8439].
+///
+/// [RFC 8439]: https://www.rfc-editor.org/rfc/rfc8439
+#[derive(Clone)]
+pub struct ChaChaRng<const ROUNDS: usize> {
+    key: [u32; 8],
+    stream: u64,
+    counter: u64,
+    buffer: [u32; 16],
+    index: usize,
+}
+
+impl<const ROUNDS: usize> ChaChaRng<ROUNDS> {
+    /// Create a new PRNG using the given seed as key, the stream is zero.
+    pub fn from_seed(seed: [u8; 32]) -> Self {
+        let mut key = [0; 8];
+        for (x, chunk) in key.iter_mut().zip(seed.chunks_exact(4)) {
+            *x = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Self {
+            key,
+            stream: 0,
+            counter: 0,
+            buffer: [0; 16],
+            index: 16,
+        }
+    }
+
+    /// Create a new PRNG using a `u64` seed.
+    ///
+    /// The key is expanded from the seed using [`SplitMix64`], then the generator is
+    /// not suitable for cryptographic purposes.
+    pub fn seed_from_u64(state: u64) -> Self {
+        let mut seed = [0; 32];
+        SplitMix64::seed_from_u64(state).fill_bytes(&mut seed);
+        Self::from_seed(seed)
+    }
+
+    /// Returns the stream.
+    #[inline]
+    pub fn stream(&self) -> u64 {
+        self.stream
+    }
+
+    /// Set the stream, the word position is unchanged.
+    pub fn set_stream(&mut self, stream: u64) {
+        self.stream = stream;
+        self.set_word_pos(self.word_pos());
+    }
+
+    /// Returns the position of the next word in the current stream, it is a 68 bits
+    /// value.
+    #[inline]
+    pub fn word_pos(&self) -> u128 {
+        let pos = (u128::from(self.counter) << 4) + self.index as u128;
+        pos.wrapping_sub(16) & ((1 << 68) - 1)
+    }
+
+    /// Set the position of the next word in the current stream, only the lowest 68 bits
+    /// are used.
+    pub fn set_word_pos(&mut self, pos: u128) {
+        self.counter = (pos >> 4) as u64;
+        self.index = 16;
+        let offset = (pos & 15) as usize;
+        if offset > 0 {
+            self.refill();
+            self.index = offset;
+        }
+    }
+
+    /// Generate the next block of the keystream.
+    fn refill(&mut self) {
+        const CONSTANTS: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+
+        let mut input = [0; 16];
+        input[..4].copy_from_slice(&CONSTANTS);
+        input[4..12].copy_from_slice(&self.key);
+        input[12] = self.counter as u32;
+        input[13] = (self.counter >> 32) as u32;
+        input[14] = self.stream as u32;
+        input[15] = (self.stream >> 32) as u32;
+
+        let mut x = input;
+        for _ in 0..ROUNDS / 2 {
+            quarter_round(&mut x, 0, 4, 8, 12);
+            quarter_round(&mut x, 1, 5, 9, 13);
+            quarter_round(&mut x, 2, 6, 10, 14);
+            quarter_round(&mut x, 3, 7, 11, 15);
+            quarter_round(&mut x, 0, 5, 10, 15);
+            quarter_round(&mut x, 1, 6, 11, 12);
+            quarter_round(&mut x, 2, 7, 8, 13);
+            quarter_round(&mut x, 3, 4, 9, 14);
+        }
+        for (x, input) in self.buffer.iter_mut().zip(x.iter().zip(input)) {
+            *x = input.0.wrapping_add(input.1);
+        }
+
+        self.counter = self.counter.wrapping_add(1);
+        self.index = 0;
+    }
+}
+
+#[inline(always)]
+fn quarter_round(x: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(16);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(12);
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(8);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(7);
+}
+
+impl<const ROUNDS: usize> RandomSource for ChaChaRng<ROUNDS> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        if self.index >= 16 {
+            self.refill();
+        }
+        let x = self.buffer[self.index];
+        self.index += 1;
+        x
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let lo = u64::from(self.next_u32());
+        let hi = u64::from(self.next_u32());
+        (hi << 32) | lo
+    }
+
+    /// Fill a slice of bytes with the keystream, a partially used word is discarded.
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(4) {
+            let bytes = self.next_u32().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
+
+impl<const ROUNDS: usize> PartialEq for ChaChaRng<ROUNDS> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.stream == other.stream && self.word_pos() == other.word_pos()
+    }
+}
+
+impl<const ROUNDS: usize> Eq for ChaChaRng<ROUNDS> {}
+
+impl<const ROUNDS: usize> fmt::Debug for ChaChaRng<ROUNDS> {
+    /// The state is not printed, it contains the key.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChaChaRng").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keystream<const ROUNDS: usize>(rng: &mut ChaChaRng<ROUNDS>) -> [u8; 64] {
+        let mut block = [0; 64];
+        rng.fill_bytes(&mut block);
+        block
+    }
+
+    #[test]
+    fn chacha20_block_function() {
+        // RFC 8439, section 2.3.2, the 96 bits nonce is mapped to the upper half of the
+        // block counter and to the stream
+        let seed = core::array::from_fn(|i| i as u8);
+        let mut rng = ChaCha20Rng::from_seed(seed);
+        rng.set_stream(0x4a000000);
+        rng.set_word_pos((1 | (0x09000000 << 32)) << 4);
+
+        #[rustfmt::skip]
+        let expected = [
+            0xe4e7f110, 0x15593bd1, 0x1fdd0f50, 0xc47120a3,
+            0xc7f4d1c7, 0x0368c033, 0x9aaa2204, 0x4e6cd4c3,
+            0x466482d2, 0x09aa9f07, 0x05d7c214, 0xa2028bd9,
+            0xd19c12b5, 0xb94e16de, 0xe883d0cb, 0x4e3c50a2,
+        ];
+        for x in expected {
+            assert_eq!(rng.next_u32(), x);
+        }
+    }
+
+    #[test]
+    fn chacha20_keystream() {
+        // RFC 8439, appendix A.1, test vectors #1 and #2
+        let mut rng = ChaCha20Rng::from_seed([0; 32]);
+
+        #[rustfmt::skip]
+        let expected = [
+            0x76, 0xb8, 0xe0, 0xad, 0xa0, 0xf1, 0x3d, 0x90, 0x40, 0x5d, 0x6a, 0xe5, 0x53, 0x86, 0xbd, 0x28,
+            0xbd, 0xd2, 0x19, 0xb8, 0xa0, 0x8d, 0xed, 0x1a, 0xa8, 0x36, 0xef, 0xcc, 0x8b, 0x77, 0x0d, 0xc7,
+            0xda, 0x41, 0x59, 0x7c, 0x51, 0x57, 0x48, 0x8d, 0x77, 0x24, 0xe0, 0x3f, 0xb8, 0xd8, 0x4a, 0x37,
+            0x6a, 0x43, 0xb8, 0xf4, 0x15, 0x18, 0xa1, 0x1c, 0xc3, 0x87, 0xb6, 0x69, 0xb2, 0xee, 0x65, 0x86,
+        ];
+        assert_eq!(keystream(&mut rng), expected);
+
+        #[rustfmt::skip]
+        let expected = [
+            0x9f, 0x07, 0xe7, 0xbe, 0x55, 0x51, 0x38, 0x7a, 0x98, 0xba, 0x97, 0x7c, 0x73, 0x2d, 0x08, 0x0d,
+            0xcb, 0x0f, 0x29, 0xa0, 0x48, 0xe3, 0x65, 0x69, 0x12, 0xc6, 0x53, 0x3e, 0x32, 0xee, 0x7a, 0xed,
+            0x29, 0xb7, 0x21, 0x76, 0x9c, 0xe6, 0x4e, 0x43, 0xd5, 0x71, 0x33, 0xb0, 0x74, 0xd8, 0x39, 0xd5,
+            0x31, 0xed, 0x1f, 0x28, 0x51, 0x0a, 0xfb, 0x45, 0xac, 0xe1, 0x0a, 0x1f, 0x4b, 0x79, 0x4d, 0x6f,
+        ];
+        assert_eq!(keystream(&mut rng), expected);
+    }
+
+    #[test]
+    fn chacha12_rand() {
+        use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+        // the standard generator of rand is ChaCha12 with the same layout
+        let seed = core::array::from_fn(|i| (i * 7) as u8);
+        let mut rng = ChaCha12Rng::from_seed(seed);
+        let mut expected = StdRng::from_seed(seed);
+        for _ in 0..100 {
+            assert_eq!(rng.next_u32(), expected.next_u32());
+            assert_eq!(rng.next_u64(), expected.next_u64());
+        }
+    }
+
+    #[test]
+    fn word_pos() {
+        let mut rng = ChaCha8Rng::seed_from_u64(0x5eed);
+        assert_eq!(rng.word_pos(), 0);
+
+        let values = (0..100).map(|_| rng.next_u32()).collect::<Vec<_>>();
+        assert_eq!(rng.word_pos(), 100);
+
+        for pos in [0, 1, 15, 16, 17, 42, 99] {
+            rng.set_word_pos(pos);
+            assert_eq!(rng.word_pos(), pos);
+            assert_eq!(rng.next_u32(), values[pos as usize]);
+        }
+
+        rng.set_word_pos((1 << 68) - 1);
+        rng.next_u32();
+        assert_eq!(rng.word_pos(), 0);
+        assert_eq!(rng.next_u32(), values[0]);
+    }
+
+    #[test]
+    fn stream() {
+        let mut rng = ChaCha20Rng::seed_from_u64(0x5eed);
+        let first = keystream(&mut rng);
+
+        rng.set_stream(1);
+        assert_eq!((rng.stream(), rng.word_pos()), (1, 16));
+        let second = keystream(&mut rng);
+
+        let mut expected = ChaCha20Rng::seed_from_u64(0x5eed);
+        assert_eq!(keystream(&mut expected), first);
+        assert_ne!(keystream(&mut expected), second);
+
+        expected.set_stream(1);
+        assert_eq!(expected, rng);
+
+        rng.set_word_pos(16);
+        assert_eq!(keystream(&mut rng), second);
+    }
+
+    #[test]
+    fn debug() {
+        let rng = ChaCha20Rng::from_seed([0x42; 32]);
+        assert_eq!(format!("{rng:?}"), "ChaChaRng { .. }");
+    }
+}
@@ -0,0 +1,154 @@
+//! Interoperability with the traits of [`rand_core`].
+
+use rand_core::{RngCore, SeedableRng};
+
+use super::{RandomSource, Rng};
+
+impl RngCore for Rng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        RandomSource::next_u32(self)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        RandomSource::next_u64(self)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        RandomSource::fill_bytes(self, dest);
+    }
+}
+
+impl SeedableRng for Rng {
+    type Seed = [u8; 32];
+
+    #[inline]
+    fn from_seed(seed: [u8; 32]) -> Self {
+        Rng::from_seed(seed)
+    }
+
+    /// Create a new PRNG using a `u64` seed, it is the same as [`Rng::seed_from_u64`].
+    #[inline]
+    fn seed_from_u64(state: u64) -> Self {
+        Rng::seed_from_u64(state)
+    }
+}
+
+/// An adapter using a generator implementing [`RngCore`] as source of randomness.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RandCoreAdapter<R> {
+    rng: R,
+}
+
+impl<R: RngCore> RandCoreAdapter<R> {
+    /// Create a new adapter wrapping the given generator.
+    #[inline]
+    pub fn new(rng: R) -> Self {
+        Self { rng }
+    }
+
+    /// Returns the wrapped generator.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+}
+
+impl<R: RngCore> RandomSource for RandCoreAdapter<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rand::{ChaCha12Rng, Random, UniformFloat, UniformInt};
+
+    #[test]
+    fn rng_core() {
+        let mut rng = <Rng as SeedableRng>::seed_from_u64(0x5eed);
+        let mut expected = Rng::seed_from_u64(0x5eed);
+        for _ in 0..100 {
+            assert_eq!(
+                RngCore::next_u64(&mut rng),
+                RandomSource::next_u64(&mut expected)
+            );
+            assert_eq!(
+                RngCore::next_u32(&mut rng),
+                RandomSource::next_u32(&mut expected)
+            );
+        }
+
+        let mut bytes = [0; 37];
+        let mut expected_bytes = [0; 37];
+        RngCore::fill_bytes(&mut rng, &mut bytes);
+        RandomSource::fill_bytes(&mut expected, &mut expected_bytes);
+        assert_eq!(bytes, expected_bytes);
+
+        let seed = core::array::from_fn(|i| i as u8);
+        assert_eq!(<Rng as SeedableRng>::from_seed(seed), Rng::from_seed(seed));
+    }
+
+    #[test]
+    fn rand_distributions() {
+        use rand::{distr::Uniform, Rng as _};
+
+        // rand can use the generator
+        let mut rng = <Rng as SeedableRng>::seed_from_u64(0x5eed);
+        let uniform = Uniform::new(10u8, 20).unwrap();
+        assert!((0..100).all(|_| (10..20).contains(&rng.sample(uniform))));
+        let _: f64 = rng.random();
+    }
+
+    #[test]
+    fn adapter() {
+        let int = UniformInt::<u32>::new(10..1000);
+        let float = UniformFloat::<f64>::new(-1.0, 2.0);
+
+        let mut rng = RandCoreAdapter::new(Rng::seed_from_u64(0x5eed));
+        let mut expected = Rng::seed_from_u64(0x5eed);
+        for _ in 0..100 {
+            assert_eq!(int.sample(&mut rng), int.sample(&mut expected));
+            assert_eq!(float.sample(&mut rng), float.sample(&mut expected));
+            assert_eq!(u16::random(&mut rng), u16::random(&mut expected));
+            assert_eq!(
+                <[f32; 3]>::random(&mut rng),
+                <[f32; 3]>::random(&mut expected)
+            );
+        }
+        assert_eq!(rng.into_inner(), expected);
+    }
+
+    #[test]
+    fn adapter_std_rng() {
+        use rand::rngs::StdRng;
+
+        // the standard generator of rand is ChaCha12 with the same stream
+        let seed = core::array::from_fn(|i| (i * 3) as u8);
+        let mut rng = RandCoreAdapter::new(StdRng::from_seed(seed));
+        let mut expected = ChaCha12Rng::from_seed(seed);
+
+        let int = UniformInt::<i64>::new(-50..=50);
+        let float = UniformFloat::<f32>::new(0.0, 10.0);
+        for _ in 0..100 {
+            assert_eq!(int.sample(&mut rng), int.sample(&mut expected));
+            assert_eq!(float.sample(&mut rng), float.sample(&mut expected));
+            assert_eq!(bool::random(&mut rng), bool::random(&mut expected));
+            assert_eq!(u64::random(&mut rng), u64::random(&mut expected));
+        }
+    }
+}
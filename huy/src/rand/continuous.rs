@@ -0,0 +1,580 @@
+//! Continuous probability distributions.
+
+use super::{normal::standard_normal, Distribution, Float, Normal, RandomSource};
+
+/// Sample floating point numbers from an exponential distribution.
+///
+/// The probability density function is `λ exp(-λx)` for `x ≥ 0`, where `λ` is the rate.
+#[derive(Clone, Copy, Debug)]
+pub struct Exponential<T: Float> {
+    rate: T,
+}
+
+impl<T: Float> Exponential<T> {
+    /// Creates a new [`Exponential`] distribution with the given rate.
+    ///
+    /// Panic if the rate is not finite and positive.
+    pub fn new(rate: T) -> Self {
+        Self::try_new(rate).unwrap_or_else(|| panic!("invalid parameters: rate {rate:?}"))
+    }
+
+    /// Creates a new [`Exponential`] distribution with the given rate.
+    ///
+    /// Returns `None` if the rate is not finite and positive.
+    pub fn try_new(rate: T) -> Option<Self> {
+        is_positive(rate).then_some(Exponential { rate })
+    }
+}
+
+impl<T: Float> Distribution<T> for Exponential<T> {
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
+        -open_closed01::<T>(rng).ln() / self.rate
+    }
+}
+
+/// Sample floating point numbers from a gamma distribution.
+///
+/// The probability density function is `x^(k-1) exp(-x/θ) / (Γ(k) θ^k)` for `x > 0`,
+/// where `k` is the shape and `θ` is the scale. The values are generated with the
+/// [method of Marsaglia and Tsang], when the shape is less than one the sample is
+/// boosted from the distribution with shape `k + 1`.
+///
+/// [method of Marsaglia and Tsang]: https://doi.org/10.1145/358407.358414
+#[derive(Clone, Copy, Debug)]
+pub struct Gamma<T: Float> {
+    scale: T,
+    d: T,
+    c: T,
+    inv_shape: Option<T>,
+}
+
+impl<T: Float> Gamma<T> {
+    /// Creates a new [`Gamma`] distribution with the given shape and scale.
+    ///
+    /// Panic if the parameters are not finite and positive.
+    pub fn new(shape: T, scale: T) -> Self {
+        Self::try_new(shape, scale)
+            .unwrap_or_else(|| panic!("invalid parameters: shape {shape:?}, scale {scale:?}"))
+    }
+
+    /// Creates a new [`Gamma`] distribution with the given shape and scale.
+    ///
+    /// Returns `None` if the parameters are not finite and positive.
+    pub fn try_new(shape: T, scale: T) -> Option<Self> {
+        if !is_positive(shape) || !is_positive(scale) {
+            return None;
+        }
+
+        let (shape, inv_shape) = if shape < T::ONE {
+            (shape + T::ONE, Some(shape.recip()))
+        } else {
+            (shape, None)
+        };
+        let d = shape - T::from_f64(1.0 / 3.0);
+        let c = (T::from_f64(9.0) * d).sqrt().recip();
+
+        Some(Gamma {
+            scale,
+            d,
+            c,
+            inv_shape,
+        })
+    }
+
+    /// Generate a random float from the distribution with the same shape and unit scale.
+    fn sample_unit_scale<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
+        let x = loop {
+            let z: T = standard_normal(rng);
+            let v = T::ONE + self.c * z;
+            if v <= T::ZERO {
+                continue;
+            }
+
+            let v = v * v * v;
+            let u: T = open_closed01(rng);
+            let z2 = z * z;
+            if u < T::ONE - T::from_f64(0.0331) * z2 * z2
+                || u.ln() < T::FRAC_1_2 * z2 + self.d * (T::ONE - v + v.ln())
+            {
+                break self.d * v;
+            }
+        };
+
+        match self.inv_shape {
+            Some(inv_shape) => x * open_closed01::<T>(rng).powf(inv_shape),
+            None => x,
+        }
+    }
+}
+
+impl<T: Float> Distribution<T> for Gamma<T> {
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
+        self.scale * self.sample_unit_scale(rng)
+    }
+}
+
+/// Sample floating point numbers from a beta distribution.
+///
+/// The probability density function is `x^(α-1) (1-x)^(β-1) / B(α, β)` for
+/// `0 ≤ x ≤ 1`. When both the parameters are at most one the values are generated
+/// with the algorithm of Jöhnk, otherwise as the ratio `X / (X + Y)` where `X` and `Y`
+/// are sampled from gamma distributions.
+#[derive(Clone, Copy, Debug)]
+pub struct Beta<T: Float> {
+    sampler: BetaSampler<T>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum BetaSampler<T: Float> {
+    Johnk { inv_alpha: T, inv_beta: T },
+    Gamma { alpha: Gamma<T>, beta: Gamma<T> },
+}
+
+impl<T: Float> Beta<T> {
+    /// Creates a new [`Beta`] distribution with the given shape parameters.
+    ///
+    /// Panic if the parameters are not finite and positive.
+    pub fn new(alpha: T, beta: T) -> Self {
+        Self::try_new(alpha, beta)
+            .unwrap_or_else(|| panic!("invalid parameters: alpha {alpha:?}, beta {beta:?}"))
+    }
+
+    /// Creates a new [`Beta`] distribution with the given shape parameters.
+    ///
+    /// Returns `None` if the parameters are not finite and positive.
+    pub fn try_new(alpha: T, beta: T) -> Option<Self> {
+        if !is_positive(alpha) || !is_positive(beta) {
+            return None;
+        }
+
+        let sampler = if alpha <= T::ONE && beta <= T::ONE {
+            BetaSampler::Johnk {
+                inv_alpha: alpha.recip(),
+                inv_beta: beta.recip(),
+            }
+        } else {
+            BetaSampler::Gamma {
+                alpha: Gamma::try_new(alpha, T::ONE)?,
+                beta: Gamma::try_new(beta, T::ONE)?,
+            }
+        };
+
+        Some(Beta { sampler })
+    }
+}
+
+impl<T: Float> Distribution<T> for Beta<T> {
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
+        match self.sampler {
+            BetaSampler::Johnk {
+                inv_alpha,
+                inv_beta,
+            } => loop {
+                let u: T = open_closed01(rng);
+                let v: T = open_closed01(rng);
+                let x = u.powf(inv_alpha);
+                let y = v.powf(inv_beta);
+                let sum = x + y;
+                if sum > T::ONE {
+                    continue;
+                }
+                if sum > T::ZERO {
+                    return x / sum;
+                }
+
+                // both the powers underflow, compute the ratio in logarithmic scale
+                let log_x = u.ln() * inv_alpha;
+                let log_y = v.ln() * inv_beta;
+                let log_max = log_x.max(log_y);
+                let (log_x, log_y) = (log_x - log_max, log_y - log_max);
+                return (log_x - (log_x.exp() + log_y.exp()).ln()).exp();
+            },
+            BetaSampler::Gamma { alpha, beta } => {
+                let x = alpha.sample(rng);
+                let y = beta.sample(rng);
+                x / (x + y)
+            }
+        }
+    }
+}
+
+/// Sample floating point numbers from a chi-squared distribution.
+///
+/// The chi-squared distribution with `k` degrees of freedom is the gamma distribution
+/// with shape `k/2` and scale `2`.
+#[derive(Clone, Copy, Debug)]
+pub struct ChiSquared<T: Float> {
+    gamma: Gamma<T>,
+}
+
+impl<T: Float> ChiSquared<T> {
+    /// Creates a new [`ChiSquared`] distribution with the given degrees of freedom.
+    ///
+    /// Panic if the degrees of freedom are not finite and positive.
+    pub fn new(dof: T) -> Self {
+        Self::try_new(dof)
+            .unwrap_or_else(|| panic!("invalid parameters: degrees of freedom {dof:?}"))
+    }
+
+    /// Creates a new [`ChiSquared`] distribution with the given degrees of freedom.
+    ///
+    /// Returns `None` if the degrees of freedom are not finite and positive.
+    pub fn try_new(dof: T) -> Option<Self> {
+        let two = T::ONE + T::ONE;
+        let gamma = Gamma::try_new(dof * T::FRAC_1_2, two)?;
+        Some(ChiSquared { gamma })
+    }
+}
+
+impl<T: Float> Distribution<T> for ChiSquared<T> {
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
+        self.gamma.sample(rng)
+    }
+}
+
+/// Sample floating point numbers from a Student's t-distribution.
+///
+/// The values are generated as `Z / √(V/ν)`, where `Z` is sampled from the standard
+/// normal distribution and `V` from the chi-squared distribution with `ν` degrees of
+/// freedom.
+#[derive(Clone, Copy, Debug)]
+pub struct StudentT<T: Float> {
+    chi_squared: ChiSquared<T>,
+    dof: T,
+}
+
+impl<T: Float> StudentT<T> {
+    /// Creates a new [`StudentT`] distribution with the given degrees of freedom.
+    ///
+    /// Panic if the degrees of freedom are not finite and positive.
+    pub fn new(dof: T) -> Self {
+        Self::try_new(dof)
+            .unwrap_or_else(|| panic!("invalid parameters: degrees of freedom {dof:?}"))
+    }
+
+    /// Creates a new [`StudentT`] distribution with the given degrees of freedom.
+    ///
+    /// Returns `None` if the degrees of freedom are not finite and positive.
+    pub fn try_new(dof: T) -> Option<Self> {
+        let chi_squared = ChiSquared::try_new(dof)?;
+        Some(StudentT { chi_squared, dof })
+    }
+}
+
+impl<T: Float> Distribution<T> for StudentT<T> {
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
+        let z: T = standard_normal(rng);
+        z * (self.dof / self.chi_squared.sample(rng)).sqrt()
+    }
+}
+
+/// Sample floating point numbers from a log-normal distribution.
+///
+/// The values are generated as `exp(X)`, where `X` is sampled from the normal
+/// distribution with mean `μ` and standard deviation `σ`.
+#[derive(Clone, Copy, Debug)]
+pub struct LogNormal<T: Float> {
+    normal: Normal<T>,
+}
+
+impl<T: Float> LogNormal<T> {
+    /// Creates a new [`LogNormal`] distribution with the given parameters of the
+    /// underlying normal distribution.
+    ///
+    /// Panic if the parameters are not finite or `sigma` is negative.
+    pub fn new(mu: T, sigma: T) -> Self {
+        Self::try_new(mu, sigma)
+            .unwrap_or_else(|| panic!("invalid parameters: mu {mu:?}, sigma {sigma:?}"))
+    }
+
+    /// Creates a new [`LogNormal`] distribution with the given parameters of the
+    /// underlying normal distribution.
+    ///
+    /// Returns `None` if the parameters are not finite or `sigma` is negative.
+    pub fn try_new(mu: T, sigma: T) -> Option<Self> {
+        let normal = Normal::try_new(mu, sigma)?;
+        Some(LogNormal { normal })
+    }
+}
+
+impl<T: Float> Distribution<T> for LogNormal<T> {
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
+        self.normal.sample(rng).exp()
+    }
+}
+
+/// Sample floating point numbers from a Cauchy distribution.
+///
+/// The probability density function is `γ / (π (γ² + (x - x₀)²))`, where `x₀` is the
+/// median and `γ` is the scale.
+#[derive(Clone, Copy, Debug)]
+pub struct Cauchy<T: Float> {
+    median: T,
+    scale: T,
+}
+
+impl<T: Float> Cauchy<T> {
+    /// Creates a new [`Cauchy`] distribution with the given median and scale.
+    ///
+    /// Panic if the parameters are not finite or the scale is not positive.
+    pub fn new(median: T, scale: T) -> Self {
+        Self::try_new(median, scale)
+            .unwrap_or_else(|| panic!("invalid parameters: median {median:?}, scale {scale:?}"))
+    }
+
+    /// Creates a new [`Cauchy`] distribution with the given median and scale.
+    ///
+    /// Returns `None` if the parameters are not finite or the scale is not positive.
+    pub fn try_new(median: T, scale: T) -> Option<Self> {
+        (median.is_finite() && is_positive(scale)).then_some(Cauchy { median, scale })
+    }
+}
+
+impl<T: Float> Distribution<T> for Cauchy<T> {
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
+        let u: T = open_closed01(rng);
+        self.median + self.scale * (T::PI * (u - T::FRAC_1_2)).tan()
+    }
+}
+
+/// Sample floating point numbers from a Weibull distribution.
+///
+/// The cumulative distribution function is `1 - exp(-(x/λ)^k)` for `x ≥ 0`, where `λ` is
+/// the scale and `k` is the shape.
+#[derive(Clone, Copy, Debug)]
+pub struct Weibull<T: Float> {
+    scale: T,
+    inv_shape: T,
+}
+
+impl<T: Float> Weibull<T> {
+    /// Creates a new [`Weibull`] distribution with the given scale and shape.
+    ///
+    /// Panic if the parameters are not finite and positive.
+    pub fn new(scale: T, shape: T) -> Self {
+        Self::try_new(scale, shape)
+            .unwrap_or_else(|| panic!("invalid parameters: scale {scale:?}, shape {shape:?}"))
+    }
+
+    /// Creates a new [`Weibull`] distribution with the given scale and shape.
+    ///
+    /// Returns `None` if the parameters are not finite and positive.
+    pub fn try_new(scale: T, shape: T) -> Option<Self> {
+        (is_positive(scale) && is_positive(shape)).then(|| Weibull {
+            scale,
+            inv_shape: shape.recip(),
+        })
+    }
+}
+
+impl<T: Float> Distribution<T> for Weibull<T> {
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
+        self.scale * (-open_closed01::<T>(rng).ln()).powf(self.inv_shape)
+    }
+}
+
+/// Sample floating point numbers from a Pareto distribution.
+///
+/// The cumulative distribution function is `1 - (xₘ/x)^α` for `x ≥ xₘ`, where `xₘ` is
+/// the scale and `α` is the shape.
+#[derive(Clone, Copy, Debug)]
+pub struct Pareto<T: Float> {
+    scale: T,
+    inv_shape: T,
+}
+
+impl<T: Float> Pareto<T> {
+    /// Creates a new [`Pareto`] distribution with the given scale and shape.
+    ///
+    /// Panic if the parameters are not finite and positive.
+    pub fn new(scale: T, shape: T) -> Self {
+        Self::try_new(scale, shape)
+            .unwrap_or_else(|| panic!("invalid parameters: scale {scale:?}, shape {shape:?}"))
+    }
+
+    /// Creates a new [`Pareto`] distribution with the given scale and shape.
+    ///
+    /// Returns `None` if the parameters are not finite and positive.
+    pub fn try_new(scale: T, shape: T) -> Option<Self> {
+        (is_positive(scale) && is_positive(shape)).then(|| Pareto {
+            scale,
+            inv_shape: shape.recip(),
+        })
+    }
+}
+
+impl<T: Float> Distribution<T> for Pareto<T> {
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
+        self.scale * open_closed01::<T>(rng).powf(-self.inv_shape)
+    }
+}
+
+/// Check if the value is finite and positive.
+#[inline]
+fn is_positive<T: Float>(x: T) -> bool {
+    x > T::ZERO && x.is_finite()
+}
+
+/// Generate a random float in the half-open interval `(0, 1]`.
+#[inline]
+fn open_closed01<T: Float>(rng: &mut (impl RandomSource + ?Sized)) -> T {
+    T::ONE - T::random(rng)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::PI;
+
+    use super::*;
+    use crate::rand::{testing::*, Rng};
+
+    #[test]
+    fn exponential() {
+        let exponential = Exponential::<f64>::new(2.0);
+        kolmogorov_smirnov_test(|rng| exponential.sample(rng), |x| 1.0 - (-2.0 * x).exp());
+    }
+
+    #[test]
+    fn gamma() {
+        // with integer shape the cumulative distribution function has a closed form
+        let gamma = Gamma::<f64>::new(3.0, 0.5);
+        kolmogorov_smirnov_test(
+            |rng| gamma.sample(rng),
+            |x| 1.0 - (-2.0 * x).exp() * (1.0 + 2.0 * x + 2.0 * x * x),
+        );
+
+        let gamma = Gamma::<f64>::new(0.3, 2.0);
+        let (mean, variance) = sample_mean_and_variance(|rng| gamma.sample(rng));
+        assert!((mean - 0.6).abs() < 0.02);
+        assert!((variance - 1.2).abs() < 0.05);
+    }
+
+    #[test]
+    fn beta() {
+        let beta = Beta::<f64>::new(2.0, 1.0);
+        kolmogorov_smirnov_test(|rng| beta.sample(rng), |x| x * x);
+
+        let beta = Beta::<f64>::new(0.5, 0.5);
+        kolmogorov_smirnov_test(|rng| beta.sample(rng), |x| 2.0 / PI * x.sqrt().asin());
+
+        let beta = Beta::<f64>::new(2.0, 3.0);
+        let (mean, variance) = sample_mean_and_variance(|rng| beta.sample(rng));
+        assert!((mean - 0.4).abs() < 0.005);
+        assert!((variance - 0.04).abs() < 0.002);
+    }
+
+    #[test]
+    fn beta_small_parameters() {
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        let beta = Beta::<f64>::new(1e-3, 1e-3);
+        for _ in 0..10_000 {
+            let x = beta.sample(&mut rng);
+            assert!((0.0..=1.0).contains(&x));
+        }
+    }
+
+    #[test]
+    fn chi_squared() {
+        let chi_squared = ChiSquared::<f64>::new(4.0);
+        kolmogorov_smirnov_test(
+            |rng| chi_squared.sample(rng),
+            |x| 1.0 - (-0.5 * x).exp() * (1.0 + 0.5 * x),
+        );
+    }
+
+    #[test]
+    fn student_t() {
+        let student_t = StudentT::<f64>::new(2.0);
+        kolmogorov_smirnov_test(
+            |rng| student_t.sample(rng),
+            |x| 0.5 + x / (2.0 * (2.0 + x * x).sqrt()),
+        );
+    }
+
+    #[test]
+    fn log_normal() {
+        let log_normal = LogNormal::<f64>::new(0.5, 0.75);
+        kolmogorov_smirnov_test(
+            |rng| log_normal.sample(rng),
+            |x| normal_cdf((x.ln() - 0.5) / 0.75),
+        );
+    }
+
+    #[test]
+    fn cauchy() {
+        let cauchy = Cauchy::<f64>::new(1.0, 2.0);
+        kolmogorov_smirnov_test(
+            |rng| cauchy.sample(rng),
+            |x| 0.5 + ((x - 1.0) / 2.0).atan() / PI,
+        );
+    }
+
+    #[test]
+    fn weibull() {
+        let weibull = Weibull::<f64>::new(2.0, 1.5);
+        kolmogorov_smirnov_test(
+            |rng| weibull.sample(rng),
+            |x| 1.0 - (-(x / 2.0).powf(1.5)).exp(),
+        );
+    }
+
+    #[test]
+    fn pareto() {
+        let pareto = Pareto::<f64>::new(1.5, 3.0);
+        kolmogorov_smirnov_test(|rng| pareto.sample(rng), |x| 1.0 - (1.5 / x).powi(3));
+    }
+
+    #[test]
+    fn f32() {
+        let gamma = Gamma::<f32>::new(0.5, 1.0);
+        kolmogorov_smirnov_test(
+            |rng| {
+                let x = gamma.sample(rng);
+                f64::from(2.0 * x)
+            },
+            // chi-squared with one degree of freedom
+            |x| 2.0 * normal_cdf(x.sqrt()) - 1.0,
+        );
+
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        let beta = Beta::<f32>::new(3.0, 2.0);
+        let student_t = StudentT::<f32>::new(5.0);
+        for _ in 0..10_000 {
+            assert!((0.0..=1.0).contains(&beta.sample(&mut rng)));
+            assert!(student_t.sample(&mut rng).is_finite());
+        }
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        assert!(Exponential::<f64>::try_new(0.0).is_none());
+        assert!(Exponential::<f64>::try_new(f64::INFINITY).is_none());
+        assert!(Gamma::<f64>::try_new(-1.0, 1.0).is_none());
+        assert!(Gamma::<f64>::try_new(1.0, f64::NAN).is_none());
+        assert!(Beta::<f32>::try_new(0.0, 1.0).is_none());
+        assert!(ChiSquared::<f64>::try_new(-2.0).is_none());
+        assert!(StudentT::<f64>::try_new(0.0).is_none());
+        assert!(LogNormal::<f64>::try_new(0.0, -1.0).is_none());
+        assert!(Cauchy::<f64>::try_new(f64::NAN, 1.0).is_none());
+        assert!(Cauchy::<f64>::try_new(0.0, 0.0).is_none());
+        assert!(Weibull::<f64>::try_new(1.0, 0.0).is_none());
+        assert!(Pareto::<f32>::try_new(-1.0, 1.0).is_none());
+
+        assert!(Gamma::<f64>::try_new(0.5, 1.0).is_some());
+        assert!(LogNormal::<f64>::try_new(0.0, 0.0).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid parameters: shape -1.0, scale 1.0")]
+    fn invalid_gamma() {
+        Gamma::<f64>::new(-1.0, 1.0);
+    }
+}
@@ -0,0 +1,889 @@
+//! Discrete probability distributions.
+
+use core::{cmp::Ordering, marker::PhantomData};
+
+use super::{Distribution, Gamma, Integer, Random, RandomSource};
+
+/// Sample booleans from a Bernoulli distribution.
+///
+/// The probability is converted to a 64 bits integer threshold, a sample is `true` when
+/// the output of [`RandomSource::next_u64`] is below the threshold. Then the probability of
+/// `true` is exactly the given one rounded to a multiple of `2⁻⁶⁴`.
+#[derive(Clone, Copy, Debug)]
+pub struct Bernoulli {
+    threshold: u64,
+}
+
+impl Bernoulli {
+    /// Special threshold value for the probability `1`.
+    const ALWAYS_TRUE: u64 = u64::MAX;
+
+    /// Creates a new [`Bernoulli`] distribution with the given probability of `true`.
+    ///
+    /// Panic if the probability is not in the interval `[0, 1]`.
+    pub fn new(p: f64) -> Self {
+        Self::try_new(p).unwrap_or_else(|| panic!("invalid parameters: probability {p:?}"))
+    }
+
+    /// Creates a new [`Bernoulli`] distribution with the given probability of `true`.
+    ///
+    /// Returns `None` if the probability is not in the interval `[0, 1]`.
+    pub fn try_new(p: f64) -> Option<Self> {
+        const SCALE: f64 = 2.0 * (1u64 << 63) as f64;
+
+        if !(0.0..=1.0).contains(&p) {
+            return None;
+        }
+
+        let threshold = if p == 1.0 {
+            Self::ALWAYS_TRUE
+        } else {
+            (p * SCALE) as u64
+        };
+        Some(Bernoulli { threshold })
+    }
+}
+
+impl Distribution<bool> for Bernoulli {
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> bool {
+        // the comparison is always done to consume the same amount of randomness
+        let x = rng.next_u64();
+        x < self.threshold || self.threshold == Self::ALWAYS_TRUE
+    }
+}
+
+/// Sample integers from a binomial distribution.
+///
+/// The values are the number of successes in `n` independent trials, each with
+/// probability `p`. When the mean is small the values are generated by inversion,
+/// otherwise with the [BTPE algorithm] by Kachitvichyanukul and Schmeiser.
+///
+/// [BTPE algorithm]: https://doi.org/10.1145/42372.42381
+#[derive(Clone, Copy, Debug)]
+pub struct Binomial<T: Integer> {
+    n: u64,
+    flipped: bool,
+    method: BinomialMethod,
+    _marker: PhantomData<T>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum BinomialMethod {
+    Constant(u64),
+    Inversion(BinomialInversion),
+    Btpe(Btpe),
+}
+
+impl<T: Integer> Binomial<T> {
+    /// Creates a new [`Binomial`] distribution with the given number of trials and
+    /// probability of success.
+    ///
+    /// Panic if the number of trials is negative or the probability is not in the
+    /// interval `[0, 1]`.
+    pub fn new(n: T, p: f64) -> Self {
+        Self::try_new(n, p).unwrap_or_else(|| panic!("invalid parameters: n {n:?}, p {p:?}"))
+    }
+
+    /// Creates a new [`Binomial`] distribution with the given number of trials and
+    /// probability of success.
+    ///
+    /// Returns `None` if the number of trials is negative or the probability is not in
+    /// the interval `[0, 1]`.
+    pub fn try_new(n: T, p: f64) -> Option<Self> {
+        let n = n.to_u64()?;
+        if !(0.0..=1.0).contains(&p) {
+            return None;
+        }
+
+        // sample the number of failures when the success is more probable
+        let flipped = p > 0.5;
+        let p = if flipped { 1.0 - p } else { p };
+
+        let method = if n == 0 || p == 0.0 {
+            BinomialMethod::Constant(0)
+        } else if (n as f64) * p < BinomialInversion::MAX_MEAN {
+            BinomialMethod::Inversion(BinomialInversion::new(n, p))
+        } else {
+            BinomialMethod::Btpe(Btpe::new(n, p))
+        };
+
+        Some(Binomial {
+            n,
+            flipped,
+            method,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<T: Integer> Distribution<T> for Binomial<T> {
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
+        let x = match &self.method {
+            BinomialMethod::Constant(x) => *x,
+            BinomialMethod::Inversion(inversion) => inversion.sample(rng),
+            BinomialMethod::Btpe(btpe) => btpe.sample(rng),
+        };
+        let x = if self.flipped { self.n - x } else { x };
+        T::saturating_from_u64(x)
+    }
+}
+
+/// Binomial sampler by inversion, it is used when the mean is small.
+#[derive(Clone, Copy, Debug)]
+struct BinomialInversion {
+    /// The probability of zero successes.
+    r0: f64,
+    s: f64,
+    a: f64,
+}
+
+impl BinomialInversion {
+    /// The largest mean for which the inversion is used.
+    const MAX_MEAN: f64 = 10.0;
+
+    /// The largest value before restarting the search, it avoids the accumulation of
+    /// rounding errors.
+    const MAX_X: u64 = 110;
+
+    fn new(n: u64, p: f64) -> Self {
+        let q = 1.0 - p;
+        let s = p / q;
+        Self {
+            r0: ((n as f64) * (-p).ln_1p()).exp(),
+            s,
+            a: (n as f64 + 1.0) * s,
+        }
+    }
+
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> u64 {
+        'restart: loop {
+            let mut u = f64::random(rng);
+            let mut r = self.r0;
+            let mut x = 0;
+            while u > r {
+                u -= r;
+                x += 1;
+                if x > Self::MAX_X {
+                    continue 'restart;
+                }
+                r *= self.a / (x as f64) - self.s;
+            }
+            return x;
+        }
+    }
+}
+
+/// Binomial sampler based on the triangle, parallelogram and exponential regions.
+#[derive(Clone, Copy, Debug)]
+struct Btpe {
+    n: f64,
+    p: f64,
+    q: f64,
+    npq: f64,
+    m: i64,
+    x_m: f64,
+    x_l: f64,
+    x_r: f64,
+    c: f64,
+    p1: f64,
+    p2: f64,
+    p3: f64,
+    p4: f64,
+    lambda_l: f64,
+    lambda_r: f64,
+}
+
+impl Btpe {
+    /// The distance from the mode below which the density is evaluated explicitly.
+    const SQUEEZE_THRESHOLD: i64 = 20;
+
+    fn new(n: u64, p: f64) -> Self {
+        let n = n as f64;
+        let q = 1.0 - p;
+        let np = n * p;
+        let npq = np * q;
+        let f_m = np + p;
+        let m = f_m.floor() as i64;
+
+        let p1 = (2.195 * npq.sqrt() - 4.6 * q).floor() + 0.5;
+        let x_m = (m as f64) + 0.5;
+        let x_l = x_m - p1;
+        let x_r = x_m + p1;
+        let c = 0.134 + 20.5 / (15.3 + (m as f64));
+        let p2 = p1 * (1.0 + 2.0 * c);
+
+        let lambda = |a: f64| a * (1.0 + 0.5 * a);
+        let lambda_l = lambda((f_m - x_l) / (f_m - x_l * p));
+        let lambda_r = lambda((x_r - f_m) / (x_r * q));
+        let p3 = p2 + c / lambda_l;
+        let p4 = p3 + c / lambda_r;
+
+        Self {
+            n,
+            p,
+            q,
+            npq,
+            m,
+            x_m,
+            x_l,
+            x_r,
+            c,
+            p1,
+            p2,
+            p3,
+            p4,
+            lambda_l,
+            lambda_r,
+        }
+    }
+
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> u64 {
+        loop {
+            // select the region, in the triangle the sample is accepted immediately
+            let u = self.p4 * f64::random(rng);
+            let mut v = f64::random(rng);
+            if u <= self.p1 {
+                return (self.x_m - self.p1 * v + u).floor() as u64;
+            }
+
+            let y = if u <= self.p2 {
+                // parallelograms
+                let x = self.x_l + (u - self.p1) / self.c;
+                v = v * self.c + 1.0 - (x - self.x_m).abs() / self.p1;
+                if v > 1.0 {
+                    continue;
+                }
+                x.floor() as i64
+            } else if u <= self.p3 {
+                // left exponential tail
+                let y = (self.x_l + v.ln() / self.lambda_l).floor() as i64;
+                if y < 0 {
+                    continue;
+                }
+                v *= (u - self.p2) * self.lambda_l;
+                y
+            } else {
+                // right exponential tail
+                let y = (self.x_r - v.ln() / self.lambda_r).floor() as i64;
+                if y as f64 > self.n {
+                    continue;
+                }
+                v *= (u - self.p3) * self.lambda_r;
+                y
+            };
+
+            if self.accept(y, v) {
+                return y as u64;
+            }
+        }
+    }
+
+    fn accept(&self, y: i64, v: f64) -> bool {
+        let (n, p, q, npq, m) = (self.n, self.p, self.q, self.npq, self.m);
+
+        // close to the mode the density is evaluated with the recurrence relation
+        let k = (y - m).abs();
+        if k <= Self::SQUEEZE_THRESHOLD || (k as f64) >= 0.5 * npq - 1.0 {
+            let s = p / q;
+            let a = s * (n + 1.0);
+            let mut f = 1.0;
+            match y.cmp(&m) {
+                Ordering::Less => (y + 1..=m).for_each(|i| f /= a / (i as f64) - s),
+                Ordering::Greater => (m + 1..=y).for_each(|i| f *= a / (i as f64) - s),
+                Ordering::Equal => {}
+            }
+            return v <= f;
+        }
+
+        // squeeze with the bounds of the logarithm of the density
+        let k = k as f64;
+        let rho = (k / npq) * ((k * (k / 3.0 + 0.625) + 1.0 / 6.0) / npq + 0.5);
+        let t = -0.5 * k * k / npq;
+        let alpha = v.ln();
+        if alpha < t - rho {
+            return true;
+        }
+        if alpha > t + rho {
+            return false;
+        }
+
+        // final comparison with the Stirling approximation of the density
+        let stirling = |a: f64| {
+            let a2 = a * a;
+            (13860.0 - (462.0 - (132.0 - (99.0 - 140.0 / a2) / a2) / a2) / a2) / a / 166320.0
+        };
+        let x1 = (y + 1) as f64;
+        let f1 = (m + 1) as f64;
+        let z = n + 1.0 - m as f64;
+        let w = n + 1.0 - y as f64;
+
+        alpha
+            <= self.x_m * (f1 / x1).ln()
+                + (n - m as f64 + 0.5) * (z / w).ln()
+                + ((y - m) as f64) * (w * p / (x1 * q)).ln()
+                + stirling(f1)
+                + stirling(z)
+                - stirling(x1)
+                - stirling(w)
+    }
+}
+
+/// Sample integers from a Poisson distribution.
+///
+/// The probability mass function is `λᵏ exp(-λ) / k!`. When the mean is small the
+/// values are generated by inversion, otherwise with the [PTRS algorithm] by Hörmann.
+/// The values larger than the maximum of the integer type are saturated.
+///
+/// [PTRS algorithm]: https://doi.org/10.1016/0167-6687(93)90997-4
+#[derive(Clone, Copy, Debug)]
+pub struct Poisson<T: Integer> {
+    sampler: PoissonSampler,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Integer> Poisson<T> {
+    /// Creates a new [`Poisson`] distribution with the given mean.
+    ///
+    /// Panic if the mean is not finite and positive.
+    pub fn new(lambda: f64) -> Self {
+        Self::try_new(lambda).unwrap_or_else(|| panic!("invalid parameters: lambda {lambda:?}"))
+    }
+
+    /// Creates a new [`Poisson`] distribution with the given mean.
+    ///
+    /// Returns `None` if the mean is not finite and positive.
+    pub fn try_new(lambda: f64) -> Option<Self> {
+        (lambda > 0.0 && lambda.is_finite()).then(|| Poisson {
+            sampler: PoissonSampler::new(lambda),
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<T: Integer> Distribution<T> for Poisson<T> {
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
+        T::saturating_from_u64(self.sampler.sample(rng))
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum PoissonSampler {
+    Inversion { lambda: f64, exp_neg_lambda: f64 },
+    Ptrs(Ptrs),
+}
+
+impl PoissonSampler {
+    /// The largest mean for which the inversion is used.
+    const MAX_INVERSION_MEAN: f64 = 10.0;
+
+    fn new(lambda: f64) -> Self {
+        if lambda < Self::MAX_INVERSION_MEAN {
+            Self::Inversion {
+                lambda,
+                exp_neg_lambda: (-lambda).exp(),
+            }
+        } else {
+            Self::Ptrs(Ptrs::new(lambda))
+        }
+    }
+
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> u64 {
+        match self {
+            Self::Inversion {
+                lambda,
+                exp_neg_lambda,
+            } => {
+                let u = f64::random(rng);
+                let mut p = *exp_neg_lambda;
+                let mut s = p;
+                let mut x = 0;
+                // the probability vanishes if the rounding errors make `s` less than `u`
+                while u >= s && p > 0.0 {
+                    x += 1;
+                    p *= *lambda / (x as f64);
+                    s += p;
+                }
+                x
+            }
+            Self::Ptrs(ptrs) => ptrs.sample(rng),
+        }
+    }
+}
+
+/// Poisson sampler based on the transformed rejection with squeeze.
+#[derive(Clone, Copy, Debug)]
+struct Ptrs {
+    lambda: f64,
+    ln_lambda: f64,
+    a: f64,
+    b: f64,
+    ln_inv_alpha: f64,
+    v_r: f64,
+}
+
+impl Ptrs {
+    fn new(lambda: f64) -> Self {
+        let b = 0.931 + 2.53 * lambda.sqrt();
+        Self {
+            lambda,
+            ln_lambda: lambda.ln(),
+            a: -0.059 + 0.02483 * b,
+            b,
+            ln_inv_alpha: (1.1239 + 1.1328 / (b - 3.4)).ln(),
+            v_r: 0.9277 - 3.6224 / (b - 2.0),
+        }
+    }
+
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> u64 {
+        loop {
+            let u = f64::random(rng) - 0.5;
+            let v = f64::random(rng);
+            let us = 0.5 - u.abs();
+            let k = ((2.0 * self.a / us + self.b) * u + self.lambda + 0.43).floor();
+
+            if us >= 0.07 && v <= self.v_r {
+                return k as u64;
+            }
+            if k < 0.0 || (us < 0.013 && v > us) {
+                continue;
+            }
+
+            let lhs = v.ln() + self.ln_inv_alpha - (self.a / (us * us) + self.b).ln();
+            let rhs = -self.lambda + k * self.ln_lambda - ln_factorial(k as u64);
+            if lhs <= rhs {
+                return k as u64;
+            }
+        }
+    }
+}
+
+/// Sample integers from a geometric distribution.
+///
+/// The values are the number of failures before the first success in independent
+/// trials, each with probability `p`. The values larger than the maximum of the integer
+/// type are saturated.
+#[derive(Clone, Copy, Debug)]
+pub struct Geometric<T: Integer> {
+    /// The inverse of `ln(1 - p)`.
+    inv_ln_q: f64,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Integer> Geometric<T> {
+    /// Creates a new [`Geometric`] distribution with the given probability of success.
+    ///
+    /// Panic if the probability is not in the interval `(0, 1]`.
+    pub fn new(p: f64) -> Self {
+        Self::try_new(p).unwrap_or_else(|| panic!("invalid parameters: probability {p:?}"))
+    }
+
+    /// Creates a new [`Geometric`] distribution with the given probability of success.
+    ///
+    /// Returns `None` if the probability is not in the interval `(0, 1]`.
+    pub fn try_new(p: f64) -> Option<Self> {
+        (p > 0.0 && p <= 1.0).then(|| Geometric {
+            inv_ln_q: (-p).ln_1p().recip(),
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<T: Integer> Distribution<T> for Geometric<T> {
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
+        // when `p = 1` the product is `-0 * 0 = -0`, which is converted to zero
+        let u = 1.0 - f64::random(rng);
+        T::saturating_from_u64((u.ln() * self.inv_ln_q).floor() as u64)
+    }
+}
+
+/// Sample integers from a negative binomial distribution.
+///
+/// The values are the number of failures before `r` successes in independent trials,
+/// each with probability `p`. They are generated as a Poisson distribution whose mean
+/// is sampled from a gamma distribution, then `r` can be any positive real number. The
+/// values larger than the maximum of the integer type are saturated.
+#[derive(Clone, Copy, Debug)]
+pub struct NegativeBinomial<T: Integer> {
+    gamma: Option<Gamma<f64>>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Integer> NegativeBinomial<T> {
+    /// Creates a new [`NegativeBinomial`] distribution with the given number of
+    /// successes and probability of success.
+    ///
+    /// Panic if `r` is not finite and positive, or the probability is not in the
+    /// interval `(0, 1]`.
+    pub fn new(r: f64, p: f64) -> Self {
+        Self::try_new(r, p).unwrap_or_else(|| panic!("invalid parameters: r {r:?}, p {p:?}"))
+    }
+
+    /// Creates a new [`NegativeBinomial`] distribution with the given number of
+    /// successes and probability of success.
+    ///
+    /// Returns `None` if `r` is not finite and positive, or the probability is not in
+    /// the interval `(0, 1]`.
+    pub fn try_new(r: f64, p: f64) -> Option<Self> {
+        if !(r > 0.0 && r.is_finite() && p > 0.0 && p <= 1.0) {
+            return None;
+        }
+
+        // with `p = 1` there are no failures
+        let gamma = if p < 1.0 {
+            Some(Gamma::try_new(r, (1.0 - p) / p)?)
+        } else {
+            None
+        };
+
+        Some(NegativeBinomial {
+            gamma,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<T: Integer> Distribution<T> for NegativeBinomial<T> {
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
+        let Some(gamma) = &self.gamma else {
+            return T::saturating_from_u64(0);
+        };
+
+        let lambda = gamma.sample(rng);
+        let x = if lambda > 0.0 {
+            PoissonSampler::new(lambda).sample(rng)
+        } else {
+            0
+        };
+        T::saturating_from_u64(x)
+    }
+}
+
+/// Sample integers from a hypergeometric distribution.
+///
+/// The values are the number of successes in `n` draws without replacement from a
+/// population of size `N` containing `K` successes. The values are generated by
+/// inversion, the search starts from the mode and proceeds alternating on both sides,
+/// so the expected number of steps grows with the standard deviation.
+#[derive(Clone, Copy, Debug)]
+pub struct Hypergeometric<T: Integer> {
+    population: u64,
+    successes: u64,
+    draws: u64,
+    min: u64,
+    max: u64,
+    mode: u64,
+    /// The probability of the mode.
+    p_mode: f64,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Integer> Hypergeometric<T> {
+    /// Creates a new [`Hypergeometric`] distribution with the given population size,
+    /// number of successes in the population and number of draws.
+    ///
+    /// Panic if the parameters are negative, or the successes or the draws exceed the
+    /// population.
+    pub fn new(population: T, successes: T, draws: T) -> Self {
+        Self::try_new(population, successes, draws).unwrap_or_else(|| {
+            panic!(
+                "invalid parameters: population {population:?}, successes {successes:?}, draws {draws:?}"
+            )
+        })
+    }
+
+    /// Creates a new [`Hypergeometric`] distribution with the given population size,
+    /// number of successes in the population and number of draws.
+    ///
+    /// Returns `None` if the parameters are negative, or the successes or the draws
+    /// exceed the population.
+    pub fn try_new(population: T, successes: T, draws: T) -> Option<Self> {
+        let (population, successes, draws) =
+            (population.to_u64()?, successes.to_u64()?, draws.to_u64()?);
+        if successes > population || draws > population {
+            return None;
+        }
+
+        let failures = population - successes;
+        let min = draws.saturating_sub(failures);
+        let max = draws.min(successes);
+
+        let mode = ((draws as f64 + 1.0) * (successes as f64 + 1.0) / (population as f64 + 2.0))
+            .floor() as u64;
+        let mode = mode.clamp(min, max);
+        let p_mode = (ln_binomial(successes, mode) + ln_binomial(failures, draws - mode)
+            - ln_binomial(population, draws))
+        .exp();
+
+        Some(Hypergeometric {
+            population,
+            successes,
+            draws,
+            min,
+            max,
+            mode,
+            p_mode,
+            _marker: PhantomData,
+        })
+    }
+
+    fn sample_u64<R: RandomSource + ?Sized>(&self, rng: &mut R) -> u64 {
+        let (n, k, d) = (
+            self.population as f64,
+            self.successes as f64,
+            self.draws as f64,
+        );
+
+        let mut u = f64::random(rng);
+        if u < self.p_mode {
+            return self.mode;
+        }
+        u -= self.p_mode;
+
+        let (mut lo, mut p_lo) = (self.mode, self.p_mode);
+        let (mut hi, mut p_hi) = (self.mode, self.p_mode);
+        while lo > self.min || hi < self.max {
+            if lo > self.min {
+                let x = lo as f64;
+                p_lo *= x * (n - k - d + x) / ((k - x + 1.0) * (d - x + 1.0));
+                lo -= 1;
+                if u < p_lo {
+                    return lo;
+                }
+                u -= p_lo;
+            }
+            if hi < self.max {
+                let x = hi as f64;
+                p_hi *= (k - x) * (d - x) / ((x + 1.0) * (n - k - d + x + 1.0));
+                hi += 1;
+                if u < p_hi {
+                    return hi;
+                }
+                u -= p_hi;
+            }
+        }
+
+        // the remaining probability is due to rounding errors
+        self.mode
+    }
+}
+
+impl<T: Integer> Distribution<T> for Hypergeometric<T> {
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
+        T::saturating_from_u64(self.sample_u64(rng))
+    }
+}
+
+/// Computes the logarithm of `k!`.
+fn ln_factorial(k: u64) -> f64 {
+    if k < 20 {
+        return ((1..=k).product::<u64>() as f64).ln();
+    }
+
+    // Stirling's series
+    let x = k as f64;
+    let inv_x = x.recip();
+    let inv_x2 = inv_x * inv_x;
+    (x + 0.5) * x.ln() - x
+        + 0.5 * core::f64::consts::TAU.ln()
+        + inv_x * (1.0 / 12.0 - inv_x2 * (1.0 / 360.0 - inv_x2 / 1260.0))
+}
+
+/// Computes the logarithm of the binomial coefficient.
+fn ln_binomial(n: u64, k: u64) -> f64 {
+    ln_factorial(n) - ln_factorial(k) - ln_factorial(n - k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rand::{testing::*, Rng};
+
+    fn binomial_pmf(n: u64, p: f64) -> impl Fn(u64) -> f64 {
+        move |k| {
+            if k > n {
+                return 0.0;
+            }
+            let q = 1.0 - p;
+            (ln_binomial(n, k) + (k as f64) * p.ln() + ((n - k) as f64) * q.ln()).exp()
+        }
+    }
+
+    #[test]
+    fn ln_factorial_accuracy() {
+        let mut expected = 0.0;
+        for k in 2..200 {
+            expected += (k as f64).ln();
+            let relative_error = (ln_factorial(k) - expected).abs() / expected;
+            assert!(relative_error < 1e-13, "{k}: {relative_error}");
+        }
+    }
+
+    #[test]
+    fn bernoulli() {
+        assert_eq!(Bernoulli::new(0.5).threshold, 1 << 63);
+        assert_eq!(Bernoulli::new(0.25).threshold, 1 << 62);
+
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        let never = Bernoulli::new(0.0);
+        let always = Bernoulli::new(1.0);
+        assert!((0..1000).all(|_| !never.sample(&mut rng)));
+        assert!((0..1000).all(|_| always.sample(&mut rng)));
+
+        let bernoulli = Bernoulli::new(0.3);
+        chi_squared_test(
+            |rng| bernoulli.sample(rng) as u64,
+            |k| [0.7, 0.3][k as usize],
+        );
+    }
+
+    #[test]
+    fn binomial() {
+        // inversion
+        let binomial = Binomial::<u32>::new(20, 0.3);
+        chi_squared_test(|rng| binomial.sample(rng) as u64, binomial_pmf(20, 0.3));
+
+        // BTPE
+        let binomial = Binomial::<u32>::new(200, 0.4);
+        chi_squared_test(|rng| binomial.sample(rng) as u64, binomial_pmf(200, 0.4));
+
+        // BTPE, sampling the failures
+        let binomial = Binomial::<i64>::new(1000, 0.9);
+        chi_squared_test(|rng| binomial.sample(rng) as u64, binomial_pmf(1000, 0.9));
+
+        // BTPE, most of the values are accepted with the squeeze
+        let binomial = Binomial::<u16>::new(10_000, 0.3);
+        chi_squared_test(|rng| binomial.sample(rng) as u64, binomial_pmf(10_000, 0.3));
+
+        let binomial = Binomial::<u64>::new(1 << 40, 0.25);
+        let (mean, variance) = sample_mean_and_variance(|rng| binomial.sample(rng) as f64);
+        let (expected_mean, expected_variance) = (2f64.powi(38), 3.0 * 2f64.powi(36));
+        assert!((mean - expected_mean).abs() < 1e-5 * expected_mean);
+        assert!((variance - expected_variance).abs() < 0.02 * expected_variance);
+    }
+
+    #[test]
+    fn binomial_degenerate() {
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        assert_eq!(Binomial::<u8>::new(0, 0.5).sample(&mut rng), 0);
+        assert_eq!(Binomial::<u8>::new(10, 0.0).sample(&mut rng), 0);
+        assert_eq!(Binomial::<u8>::new(10, 1.0).sample(&mut rng), 10);
+        assert_eq!(Binomial::<i8>::new(i8::MAX, 1.0).sample(&mut rng), i8::MAX);
+    }
+
+    #[test]
+    fn poisson() {
+        let poisson_pmf = |lambda: f64| {
+            move |k: u64| (-lambda + (k as f64) * lambda.ln() - ln_factorial(k)).exp()
+        };
+
+        // inversion
+        let poisson = Poisson::<u32>::new(3.0);
+        chi_squared_test(|rng| poisson.sample(rng) as u64, poisson_pmf(3.0));
+
+        // PTRS
+        let poisson = Poisson::<u32>::new(50.0);
+        chi_squared_test(|rng| poisson.sample(rng) as u64, poisson_pmf(50.0));
+
+        let poisson = Poisson::<u64>::new(1e6);
+        let (mean, variance) = sample_mean_and_variance(|rng| poisson.sample(rng) as f64);
+        assert!((mean - 1e6).abs() < 20.0);
+        assert!((variance - 1e6).abs() < 2e4);
+    }
+
+    #[test]
+    fn poisson_saturation() {
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        let poisson = Poisson::<u8>::new(1e4);
+        assert!((0..1000).all(|_| poisson.sample(&mut rng) == u8::MAX));
+    }
+
+    #[test]
+    fn geometric() {
+        let geometric = Geometric::<u16>::new(0.2);
+        chi_squared_test(
+            |rng| geometric.sample(rng) as u64,
+            |k| 0.2 * 0.8f64.powi(k as i32),
+        );
+
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        let geometric = Geometric::<i32>::new(1.0);
+        assert!((0..1000).all(|_| geometric.sample(&mut rng) == 0));
+    }
+
+    #[test]
+    fn negative_binomial() {
+        let negative_binomial = NegativeBinomial::<u32>::new(3.0, 0.4);
+        chi_squared_test(
+            |rng| negative_binomial.sample(rng) as u64,
+            |k| (k + 2) as f64 * (k + 1) as f64 / 2.0 * 0.4f64.powi(3) * 0.6f64.powi(k as i32),
+        );
+
+        let negative_binomial = NegativeBinomial::<u64>::new(2.5, 0.1);
+        let (mean, variance) = sample_mean_and_variance(|rng| negative_binomial.sample(rng) as f64);
+        assert!((mean - 22.5).abs() < 0.3);
+        assert!((variance - 225.0).abs() < 6.0);
+    }
+
+    #[test]
+    fn hypergeometric() {
+        let hypergeometric_pmf = |n: u64, k: u64, d: u64| {
+            move |x: u64| {
+                if x > k || x > d || d - x > n - k {
+                    return 0.0;
+                }
+                (ln_binomial(k, x) + ln_binomial(n - k, d - x) - ln_binomial(n, d)).exp()
+            }
+        };
+
+        let hypergeometric = Hypergeometric::<u8>::new(50, 20, 10);
+        chi_squared_test(
+            |rng| hypergeometric.sample(rng) as u64,
+            hypergeometric_pmf(50, 20, 10),
+        );
+
+        let hypergeometric = Hypergeometric::<i16>::new(100, 90, 30);
+        chi_squared_test(
+            |rng| hypergeometric.sample(rng) as u64,
+            hypergeometric_pmf(100, 90, 30),
+        );
+
+        let hypergeometric = Hypergeometric::<u64>::new(1_000_000, 300_000, 50_000);
+        let (mean, variance) = sample_mean_and_variance(|rng| hypergeometric.sample(rng) as f64);
+        assert!((mean - 15_000.0).abs() < 1.0);
+        assert!((variance - 9975.0).abs() < 300.0);
+    }
+
+    #[test]
+    fn hypergeometric_degenerate() {
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        assert_eq!(Hypergeometric::<u8>::new(10, 10, 4).sample(&mut rng), 4);
+        assert_eq!(Hypergeometric::<u8>::new(10, 0, 4).sample(&mut rng), 0);
+        assert_eq!(Hypergeometric::<u8>::new(10, 6, 10).sample(&mut rng), 6);
+        assert_eq!(Hypergeometric::<u8>::new(0, 0, 0).sample(&mut rng), 0);
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        assert!(Bernoulli::try_new(-0.1).is_none());
+        assert!(Bernoulli::try_new(f64::NAN).is_none());
+        assert!(Binomial::<i32>::try_new(-1, 0.5).is_none());
+        assert!(Binomial::<u32>::try_new(10, 1.5).is_none());
+        assert!(Poisson::<u32>::try_new(0.0).is_none());
+        assert!(Poisson::<u32>::try_new(f64::INFINITY).is_none());
+        assert!(Geometric::<u32>::try_new(0.0).is_none());
+        assert!(NegativeBinomial::<u32>::try_new(0.0, 0.5).is_none());
+        assert!(NegativeBinomial::<u32>::try_new(1.0, 0.0).is_none());
+        assert!(Hypergeometric::<u32>::try_new(10, 11, 5).is_none());
+        assert!(Hypergeometric::<u32>::try_new(10, 5, 11).is_none());
+        assert!(Hypergeometric::<i32>::try_new(10, -1, 5).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid parameters: probability 2.0")]
+    fn invalid_bernoulli() {
+        Bernoulli::new(2.0);
+    }
+}
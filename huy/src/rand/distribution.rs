@@ -0,0 +1,246 @@
+//! Define the common interface of the probability distributions.
+
+use core::{iter::FusedIterator, marker::PhantomData};
+
+use super::{Random, RandomSource};
+
+/// A probability distribution of values of type `T`.
+pub trait Distribution<T> {
+    /// Generate a random value using the given source of randomness.
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T;
+
+    /// Returns an infinite iterator of random values generated using the given source
+    /// of randomness.
+    #[inline]
+    fn sample_iter<R: RandomSource + ?Sized>(self, rng: &mut R) -> SampleIter<'_, Self, R, T>
+    where
+        Self: Sized,
+    {
+        SampleIter {
+            distribution: self,
+            rng,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Fill the slice with random values using the given source of randomness.
+    #[inline]
+    fn fill<R: RandomSource + ?Sized>(&self, rng: &mut R, data: &mut [T]) {
+        for x in data.iter_mut() {
+            *x = self.sample(rng);
+        }
+    }
+
+    /// Returns a distribution whose values are obtained applying `f` to the values of
+    /// this distribution.
+    #[inline]
+    fn map<F, U>(self, f: F) -> Map<Self, F, T>
+    where
+        Self: Sized,
+        F: Fn(T) -> U,
+    {
+        Map {
+            distribution: self,
+            f,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, D: Distribution<T> + ?Sized> Distribution<T> for &D {
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
+        (**self).sample(rng)
+    }
+
+    #[inline]
+    fn fill<R: RandomSource + ?Sized>(&self, rng: &mut R, data: &mut [T]) {
+        (**self).fill(rng, data);
+    }
+}
+
+/// An infinite iterator of random values, see [`Distribution::sample_iter`].
+#[derive(Debug)]
+pub struct SampleIter<'a, D, R: ?Sized, T> {
+    distribution: D,
+    rng: &'a mut R,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<D: Distribution<T>, R: RandomSource + ?Sized, T> Iterator for SampleIter<'_, D, R, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        Some(self.distribution.sample(self.rng))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<D: Distribution<T>, R: RandomSource + ?Sized, T> FusedIterator for SampleIter<'_, D, R, T> {}
+
+/// A distribution whose values are transformed by a function, see
+/// [`Distribution::map`].
+#[derive(Clone, Copy, Debug)]
+pub struct Map<D, F, T> {
+    distribution: D,
+    f: F,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<D, F, T, U> Distribution<U> for Map<D, F, T>
+where
+    D: Distribution<T>,
+    F: Fn(T) -> U,
+{
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> U {
+        (self.f)(self.distribution.sample(rng))
+    }
+}
+
+/// The default distribution of the types implementing [`Random`].
+///
+/// See [`Random`] trait for more information about the distribution of the generated
+/// values.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Standard;
+
+impl<T: Random> Distribution<T> for Standard {
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
+        T::random(rng)
+    }
+
+    #[inline]
+    fn fill<R: RandomSource + ?Sized>(&self, rng: &mut R, data: &mut [T]) {
+        T::fill_random(rng, data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rand::{
+        Normal, Pcg64Dxsm, Rng, SplitMix64, UniformFloat, UniformInt, WeightedIndex, WyRand,
+        Xoshiro128PlusPlus,
+    };
+
+    /// A generic function accepting any distribution.
+    fn sum<D: Distribution<f64>, R: RandomSource + ?Sized>(
+        distribution: D,
+        rng: &mut R,
+        count: usize,
+    ) -> f64 {
+        distribution.sample_iter(rng).take(count).sum()
+    }
+
+    #[test]
+    fn sample_iter() {
+        let uniform = UniformInt::<u8>::new(10..20);
+
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        let values = (&uniform)
+            .sample_iter(&mut rng)
+            .take(100)
+            .collect::<Vec<_>>();
+
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        let expected = (0..100)
+            .map(|_| uniform.sample(&mut rng))
+            .collect::<Vec<_>>();
+
+        assert_eq!(values, expected);
+        assert!(values.iter().all(|x| (10..20).contains(x)));
+    }
+
+    #[test]
+    fn fill() {
+        let normal = Normal::<f64>::new(2.0, 0.5);
+
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        let mut values = [0.0; 100];
+        normal.fill(&mut rng, &mut values);
+
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        let mut expected = [0.0; 100];
+        expected.fill_with(|| normal.sample(&mut rng));
+
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn map() {
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        let square = UniformFloat::<f64>::new(1.0, 2.0).map(|x| x * x);
+        assert!(square
+            .sample_iter(&mut rng)
+            .take(100)
+            .all(|x| (1.0..4.0).contains(&x)));
+
+        let parity = UniformInt::<u32>::new(..).map(|x| x % 2 == 0);
+        let _: bool = parity.sample(&mut rng);
+    }
+
+    #[test]
+    fn generic() {
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        let uniform = UniformFloat::new(1.0, 2.0);
+        let total = sum(uniform, &mut rng, 100);
+        assert!((100.0..200.0).contains(&total));
+
+        let weighted = WeightedIndex::new([1u8, 1]);
+        let total = sum((&weighted).map(|i| i as f64), &mut rng, 100);
+        assert!((0.0..=100.0).contains(&total));
+
+        let total = sum(Normal::new(0.0, 0.0), &mut rng, 100);
+        assert_eq!(total, 0.0);
+    }
+
+    #[test]
+    fn standard() {
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        let values: [u64; 4] = Standard.sample(&mut rng);
+        let mut filled = [0u16; 8];
+        Standard.fill(&mut rng, &mut filled);
+
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        let expected = <[u64; 4]>::random(&mut rng);
+        let mut expected_filled = [0u16; 8];
+        u16::fill_random(&mut rng, &mut expected_filled);
+
+        assert_eq!(values, expected);
+        assert_eq!(filled, expected_filled);
+
+        let x: f32 = Standard.sample(&mut rng);
+        assert!((0.0..1.0).contains(&x));
+    }
+
+    #[test]
+    fn random_source() {
+        fn check<R: RandomSource>(mut rng: R) {
+            let uniform = UniformInt::<u8>::new(10..20);
+            assert!((&uniform)
+                .sample_iter(&mut rng)
+                .take(100)
+                .all(|x| (10..20).contains(&x)));
+
+            // the source of randomness can be a trait object
+            let rng: &mut dyn RandomSource = &mut rng;
+            let x: f64 = Normal::new(0.0, 1.0).sample(rng);
+            assert!(x.is_finite());
+            let values: [u32; 3] = Standard.sample(rng);
+            assert!(values.iter().any(|&x| x != 0));
+        }
+
+        check(Rng::seed_from_u64(0x5eed));
+        check(Xoshiro128PlusPlus::seed_from_u64(0x5eed));
+        check(Pcg64Dxsm::seed_from_u64(0x5eed));
+        check(SplitMix64::seed_from_u64(0x5eed));
+        check(WyRand::seed_from_u64(0x5eed));
+    }
+}
@@ -1,6 +1,6 @@
 //! Random float generator
 
-use super::{Random, Rng};
+use super::{Distribution, Random, RandomSource};
 
 /// A trait for a type that can represent a float.
 pub trait Float: sealed::Float {}
@@ -14,6 +14,7 @@ macro_rules! impl_float {
 impl_float![f32, f64];
 
 /// Sample floating point numbers from a uniform distribution.
+#[derive(Clone, Copy, Debug)]
 pub struct UniformFloat<T: Float> {
     low: T,
     scale: T,
@@ -37,39 +38,38 @@ impl<T: Float> UniformFloat<T> {
     }
 
     /// Generate a random float using the given source of randomness.
+    ///
+    /// It is the same as [`Distribution::sample`], available without importing the trait.
+    #[inline]
+    pub fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
+        Distribution::sample(self, rng)
+    }
+}
+
+impl<T: Float> Distribution<T> for UniformFloat<T> {
     #[inline]
-    pub fn sample(&self, rng: &mut Rng) -> T {
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
         self.low + self.scale * Random::random(rng)
     }
 }
 
 mod sealed {
-    use core::{
-        fmt::Debug,
-        ops::{Add, Mul, Sub},
-    };
+    use core::fmt::Debug;
 
     use super::Random;
+    use crate::math::RealField;
 
-    pub trait Float:
-        Copy
-        + Debug
-        + Mul<Output = Self>
-        + Add<Output = Self>
-        + Sub<Output = Self>
-        + PartialOrd
-        + Random
-    {
-        /// Check if the value is finite.
-        fn is_finite(self) -> bool;
+    pub trait Float: RealField + Debug + Random {
+        /// Convert a `f64` to the nearest representable value.
+        fn from_f64(value: f64) -> Self;
     }
 
     macro_rules! impl_float {
         ($ty:ty) => {
             impl Float for $ty {
                 #[inline]
-                fn is_finite(self) -> bool {
-                    self.is_finite()
+                fn from_f64(value: f64) -> Self {
+                    value as $ty
                 }
             }
         };
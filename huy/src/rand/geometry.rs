@@ -0,0 +1,341 @@
+//! Random generation of geometric objects.
+
+use super::{Distribution, Float, Random, RandomSource};
+use crate::math::{Angle, Complex, Point2, Point3, Vector2, Vector3};
+
+impl<T: Float> Random for Vector2<T> {
+    #[inline]
+    fn random<R: RandomSource + ?Sized>(rng: &mut R) -> Self {
+        Vector2::new(T::random(rng), T::random(rng))
+    }
+}
+
+impl<T: Float> Random for Vector3<T> {
+    #[inline]
+    fn random<R: RandomSource + ?Sized>(rng: &mut R) -> Self {
+        Vector3::new(T::random(rng), T::random(rng), T::random(rng))
+    }
+}
+
+impl<T: Float> Random for Point2<T> {
+    #[inline]
+    fn random<R: RandomSource + ?Sized>(rng: &mut R) -> Self {
+        Point2::new(T::random(rng), T::random(rng))
+    }
+}
+
+impl<T: Float> Random for Point3<T> {
+    #[inline]
+    fn random<R: RandomSource + ?Sized>(rng: &mut R) -> Self {
+        Point3::new(T::random(rng), T::random(rng), T::random(rng))
+    }
+}
+
+impl<T: Float> Random for Complex<T> {
+    #[inline]
+    fn random<R: RandomSource + ?Sized>(rng: &mut R) -> Self {
+        Complex::new(T::random(rng), T::random(rng))
+    }
+}
+
+impl<T: Float> Random for Angle<T> {
+    #[inline]
+    fn random<R: RandomSource + ?Sized>(rng: &mut R) -> Self {
+        Angle::radians(T::random(rng))
+    }
+}
+
+/// Sample angles uniformly distributed over the half-open interval `[0, 2π)`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UniformAngle;
+
+impl<T: Float> Distribution<Angle<T>> for UniformAngle {
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Angle<T> {
+        Angle::radians(uniform_radians(rng))
+    }
+}
+
+/// Sample unit vectors uniformly distributed on the unit circle.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UnitCircle;
+
+impl<T: Float> Distribution<Vector2<T>> for UnitCircle {
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Vector2<T> {
+        let (sin, cos) = uniform_radians::<T>(rng).sin_cos();
+        Vector2::new(cos, sin)
+    }
+}
+
+/// Sample unit vectors uniformly distributed on the unit sphere.
+///
+/// By Archimedes' hat-box theorem the `z` component is uniformly distributed over
+/// `[-1, 1]`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UnitSphere;
+
+impl<T: Float> Distribution<Vector3<T>> for UnitSphere {
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Vector3<T> {
+        let u = T::random(rng);
+        let z = T::ONE - (u + u);
+        let r = (T::ONE - z * z).max(T::ZERO).sqrt();
+        let (sin, cos) = uniform_radians::<T>(rng).sin_cos();
+        Vector3::new(r * cos, r * sin, z)
+    }
+}
+
+/// Sample points uniformly distributed in the unit disk centered at the origin.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UnitDisk;
+
+impl<T: Float> Distribution<Point2<T>> for UnitDisk {
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Point2<T> {
+        let r = T::random(rng).sqrt();
+        let (sin, cos) = uniform_radians::<T>(rng).sin_cos();
+        Point2::new(r * cos, r * sin)
+    }
+}
+
+/// Sample points uniformly distributed in the unit ball centered at the origin.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UnitBall;
+
+impl<T: Float> Distribution<Point3<T>> for UnitBall {
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Point3<T> {
+        let direction: Vector3<T> = UnitSphere.sample(rng);
+        Point3::ORIGIN + direction * T::random(rng).cbrt()
+    }
+}
+
+/// Sample points uniformly distributed in an axis-aligned box.
+#[derive(Clone, Copy, Debug)]
+pub struct UniformBox<T: Float> {
+    min: [T; 3],
+    extent: [T; 3],
+}
+
+impl<T: Float> UniformBox<T> {
+    /// Creates a new [`UniformBox`] distribution, the box is given by two opposite
+    /// corners.
+    ///
+    /// Panic if the corners are not finite.
+    pub fn new(a: Point3<T>, b: Point3<T>) -> Self {
+        Self::try_new(a, b).unwrap_or_else(|| panic!("invalid parameters: a {a:?}, b {b:?}"))
+    }
+
+    /// Creates a new [`UniformBox`] distribution, the box is given by two opposite
+    /// corners.
+    ///
+    /// Returns `None` if the corners are not finite.
+    pub fn try_new(a: Point3<T>, b: Point3<T>) -> Option<Self> {
+        let a: [T; 3] = (a - Point3::ORIGIN).into();
+        let b: [T; 3] = (b - Point3::ORIGIN).into();
+        if !a.iter().chain(&b).all(|x| x.is_finite()) {
+            return None;
+        }
+
+        let min = [a[0].min(b[0]), a[1].min(b[1]), a[2].min(b[2])];
+        let extent = [
+            (a[0] - b[0]).abs(),
+            (a[1] - b[1]).abs(),
+            (a[2] - b[2]).abs(),
+        ];
+        extent
+            .iter()
+            .all(|x| x.is_finite())
+            .then_some(UniformBox { min, extent })
+    }
+}
+
+impl<T: Float> Distribution<Point3<T>> for UniformBox<T> {
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Point3<T> {
+        let [x, y, z]: [T; 3] = Random::random(rng);
+        Point3::new(
+            x.mul_add(self.extent[0], self.min[0]),
+            y.mul_add(self.extent[1], self.min[1]),
+            z.mul_add(self.extent[2], self.min[2]),
+        )
+    }
+}
+
+/// Generate a random angle in radians uniformly distributed over `[0, 2π)`.
+#[inline]
+fn uniform_radians<T: Float>(rng: &mut (impl RandomSource + ?Sized)) -> T {
+    loop {
+        // the product can be rounded up to 2π
+        let radians = T::TAU * T::random(rng);
+        if radians < T::TAU {
+            return radians;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        math::point3,
+        rand::{testing::*, Rng},
+    };
+
+    const TAU: f64 = core::f64::consts::TAU;
+
+    fn uniform_cdf(low: f64, high: f64) -> impl Fn(f64) -> f64 {
+        move |x| ((x - low) / (high - low)).clamp(0.0, 1.0)
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        for _ in 0..1000 {
+            let v: [f64; 3] = Vector3::<f64>::random(&mut rng).into();
+            assert!(v.iter().all(|x| (0.0..1.0).contains(x)));
+
+            let v: [f32; 2] = Vector2::<f32>::random(&mut rng).into();
+            assert!(v.iter().all(|x| (0.0..1.0).contains(x)));
+
+            let p: [f64; 3] = (Point3::<f64>::random(&mut rng) - Point3::ORIGIN).into();
+            assert!(p.iter().all(|x| (0.0..1.0).contains(x)));
+
+            let p: [f64; 2] = (Point2::<f64>::random(&mut rng) - Point2::ORIGIN).into();
+            assert!(p.iter().all(|x| (0.0..1.0).contains(x)));
+
+            let z = Complex::<f64>::random(&mut rng);
+            assert!((0.0..1.0).contains(&z.real) && (0.0..1.0).contains(&z.imag));
+
+            let angle = Angle::<f32>::random(&mut rng);
+            assert!((0.0..1.0).contains(&angle.to_radians()));
+        }
+
+        kolmogorov_smirnov_test(
+            |rng| {
+                let v: [f64; 3] = Vector3::<f64>::random(rng).into();
+                v[2]
+            },
+            uniform_cdf(0.0, 1.0),
+        );
+    }
+
+    #[test]
+    fn uniform_angle() {
+        kolmogorov_smirnov_test(
+            |rng| {
+                let angle: Angle<f64> = UniformAngle.sample(rng);
+                angle.to_radians()
+            },
+            uniform_cdf(0.0, TAU),
+        );
+
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        assert!((&UniformAngle)
+            .sample_iter(&mut rng)
+            .take(1000)
+            .all(|angle: Angle<f32>| (0.0..core::f32::consts::TAU).contains(&angle.to_radians())));
+    }
+
+    #[test]
+    fn unit_circle() {
+        kolmogorov_smirnov_test(
+            |rng| {
+                let v: Vector2<f64> = UnitCircle.sample(rng);
+                assert!((v.norm() - 1.0).abs() < 1e-12);
+                let [x, y]: [f64; 2] = v.into();
+                y.atan2(x).rem_euclid(TAU)
+            },
+            uniform_cdf(0.0, TAU),
+        );
+    }
+
+    #[test]
+    fn unit_sphere() {
+        for component in 0..3 {
+            kolmogorov_smirnov_test(
+                |rng| {
+                    let v: Vector3<f64> = UnitSphere.sample(rng);
+                    assert!((v.norm() - 1.0).abs() < 1e-12);
+                    <[f64; 3]>::from(v)[component]
+                },
+                uniform_cdf(-1.0, 1.0),
+            );
+        }
+    }
+
+    #[test]
+    fn unit_disk() {
+        kolmogorov_smirnov_test(
+            |rng| {
+                let p: Point2<f64> = UnitDisk.sample(rng);
+                (p - Point2::ORIGIN).norm_square()
+            },
+            uniform_cdf(0.0, 1.0),
+        );
+        kolmogorov_smirnov_test(
+            |rng| {
+                let p: Point2<f64> = UnitDisk.sample(rng);
+                let [x, y]: [f64; 2] = (p - Point2::ORIGIN).into();
+                y.atan2(x).rem_euclid(TAU)
+            },
+            uniform_cdf(0.0, TAU),
+        );
+    }
+
+    #[test]
+    fn unit_ball() {
+        kolmogorov_smirnov_test(
+            |rng| {
+                let p: Point3<f64> = UnitBall.sample(rng);
+                (p - Point3::ORIGIN).norm().powi(3)
+            },
+            uniform_cdf(0.0, 1.0),
+        );
+        kolmogorov_smirnov_test(
+            |rng| {
+                let p: Point3<f64> = UnitBall.sample(rng);
+                let v = p - Point3::ORIGIN;
+                <[f64; 3]>::from(v)[2] / v.norm()
+            },
+            uniform_cdf(-1.0, 1.0),
+        );
+    }
+
+    #[test]
+    fn uniform_box() {
+        let low = [-1.0, 2.0, 0.5];
+        let high = [3.0, 2.5, 10.0];
+        let uniform = UniformBox::new(point3(3.0, 2.0, 10.0), point3(-1.0, 2.5, 0.5));
+        for component in 0..3 {
+            kolmogorov_smirnov_test(
+                |rng| <[f64; 3]>::from(uniform.sample(rng) - Point3::ORIGIN)[component],
+                uniform_cdf(low[component], high[component]),
+            );
+        }
+
+        let flat = UniformBox::new(point3(0.0, 1.0, 2.0), point3(0.0, 3.0, 2.0));
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        let [x, y, z]: [f64; 3] = (flat.sample(&mut rng) - Point3::ORIGIN).into();
+        assert_eq!((x, z), (0.0, 2.0));
+        assert!((1.0..3.0).contains(&y));
+    }
+
+    #[test]
+    fn invalid_box() {
+        assert!(UniformBox::try_new(point3(0.0, 0.0, 0.0), point3(f64::NAN, 1.0, 1.0)).is_none());
+        assert!(
+            UniformBox::try_new(point3(0.0, f64::INFINITY, 0.0), point3(1.0, 1.0, 1.0)).is_none()
+        );
+        assert!(
+            UniformBox::try_new(point3(-f64::MAX, 0.0, 0.0), point3(f64::MAX, 1.0, 1.0)).is_none()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid parameters")]
+    fn invalid_box_panics() {
+        UniformBox::new(point3(0.0, 0.0, 0.0), point3(1.0, f32::NAN, 1.0));
+    }
+}
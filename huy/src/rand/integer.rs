@@ -2,7 +2,7 @@
 
 use core::ops::RangeBounds;
 
-use super::Rng;
+use super::{Distribution, RandomSource};
 
 /// A trait for a type that can represent an integer.
 pub trait Integer: sealed::Integer {}
@@ -16,6 +16,7 @@ macro_rules! impl_integer {
 impl_integer![i8, u8, i16, u16, i32, u32, i64, u64, isize, usize];
 
 /// Sample integers from a uniform distribution.
+#[derive(Clone, Copy, Debug)]
 pub struct UniformInt<T: Integer> {
     uniform: Option<T::Uniform>,
 }
@@ -28,8 +29,17 @@ impl<T: Integer> UniformInt<T> {
     }
 
     /// Generate a random integer using the given source of randomness.
+    ///
+    /// It is the same as [`Distribution::sample`], available without importing the trait.
     #[inline]
-    pub fn sample(&self, rng: &mut Rng) -> T {
+    pub fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
+        Distribution::sample(self, rng)
+    }
+}
+
+impl<T: Integer> Distribution<T> for UniformInt<T> {
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
         match &self.uniform {
             Some(uniform) => T::sample_uniform(rng, uniform),
             None => T::random(rng),
@@ -46,15 +56,16 @@ impl<T: Integer> Default for UniformInt<T> {
 
 mod sealed {
     use core::{
+        fmt::Debug,
         num::NonZero,
         ops::{Bound, RangeBounds},
     };
 
-    use super::{super::Random, Rng};
+    use super::{super::Random, RandomSource};
 
-    pub trait Integer: Random {
+    pub trait Integer: Copy + Debug + Random {
         /// The data structure used for uniform distribution.
-        type Uniform;
+        type Uniform: Copy + Debug;
 
         /// Build a sampler to generate uniform distributed integer in the given range.
         ///
@@ -62,14 +73,35 @@ mod sealed {
         fn build_uniform<R: RangeBounds<Self>>(range: R) -> Option<Self::Uniform>;
 
         /// Sample a value from the given uniform distribution.
-        fn sample_uniform(rng: &mut Rng, uniform: &Self::Uniform) -> Self;
+        fn sample_uniform<R: RandomSource + ?Sized>(rng: &mut R, uniform: &Self::Uniform) -> Self;
+
+        /// Convert the value to `u64`, `None` is returned if it is negative.
+        fn to_u64(self) -> Option<u64>;
+
+        /// Convert a `u64` to the integer, saturating at the maximum value.
+        fn saturating_from_u64(value: u64) -> Self;
+    }
+
+    macro_rules! impl_conversions {
+        ($ty:ty) => {
+            #[inline]
+            fn to_u64(self) -> Option<u64> {
+                u64::try_from(self).ok()
+            }
+
+            #[inline]
+            fn saturating_from_u64(value: u64) -> Self {
+                <$ty>::try_from(value).unwrap_or(<$ty>::MAX)
+            }
+        };
     }
 
     pub trait UniformHelper {
         /// The type used to represent the range of integers (the unsigned type).
-        type Range;
+        type Range: Copy + Debug;
     }
 
+    #[derive(Clone, Copy, Debug)]
     pub struct Uniform<T: UniformHelper> {
         /// The lower bound of the interval.
         lower: T,
@@ -99,7 +131,7 @@ mod sealed {
                     Bound::Unbounded => <$ty>::MAX,
                 };
 
-                if lower >= upper {
+                if lower > upper {
                     empty_range_panic();
                 }
                 if lower == <$ty>::MIN && upper == <$ty>::MAX {
@@ -126,9 +158,13 @@ mod sealed {
             impl Integer for $signed {
                 type Uniform = Uniform<$signed>;
                 build_uniform!($signed, $unsigned);
+                impl_conversions!($signed);
 
                 #[inline]
-                fn sample_uniform(rng: &mut Rng, uniform: &Self::Uniform) -> Self {
+                fn sample_uniform<R: RandomSource + ?Sized>(
+                    rng: &mut R,
+                    uniform: &Self::Uniform,
+                ) -> Self {
                     let rand = sample_u64_in_range(rng, uniform.range.get() as u64) as $unsigned;
                     uniform.lower.checked_add_unsigned(rand).unwrap()
                 }
@@ -141,9 +177,13 @@ mod sealed {
             impl Integer for $unsigned {
                 type Uniform = Uniform<$unsigned>;
                 build_uniform!($unsigned, $unsigned);
+                impl_conversions!($unsigned);
 
                 #[inline]
-                fn sample_uniform(rng: &mut Rng, uniform: &Self::Uniform) -> Self {
+                fn sample_uniform<R: RandomSource + ?Sized>(
+                    rng: &mut R,
+                    uniform: &Self::Uniform,
+                ) -> Self {
                     let rand = sample_u64_in_range(rng, uniform.range.get() as u64) as $unsigned;
                     uniform.lower + rand
                 }
@@ -166,7 +206,7 @@ mod sealed {
     }
 
     #[inline]
-    fn sample_u64_in_range(rng: &mut Rng, range: u64) -> u64 {
+    fn sample_u64_in_range<R: RandomSource + ?Sized>(rng: &mut R, range: u64) -> u64 {
         let x = rng.next_u64();
         let (mut hi, lo) = wide_mul(x, range);
         if lo < range {
@@ -186,6 +226,7 @@ mod sealed {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::rand::Rng;
 
     fn kolmogorov_smirnov_statistic_u8(data: &[u8], start: u8, range: u8) -> f64 {
         // compute the empirical distribution function
@@ -217,6 +258,19 @@ mod tests {
         1.63 / (sample_size as f64).sqrt()
     }
 
+    #[test]
+    fn single_value_range() {
+        let mut rng = Rng::from_random_state();
+        assert_eq!(UniformInt::<i8>::new(-3..-2).sample(&mut rng), -3);
+        assert_eq!(UniformInt::<u64>::new(7..=7).sample(&mut rng), 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty range")]
+    fn empty_range() {
+        UniformInt::<u32>::new(5..5);
+    }
+
     #[test]
     fn kolmogorov_smirnov_test_uniform_u8() {
         let mut rng = Rng::from_random_state();
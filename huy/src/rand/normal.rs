@@ -0,0 +1,246 @@
+//! Normal distribution sampled with the Ziggurat algorithm.
+
+use std::sync::OnceLock;
+
+use super::{Distribution, Float, Random, RandomSource};
+
+/// Sample floating point numbers from a normal distribution.
+///
+/// The standard normal values are generated with the [Ziggurat algorithm] by George
+/// Marsaglia and Wai Wan Tsang, using 256 layers: most of the samples require a single
+/// call to [`RandomSource::next_u64`] and no transcendental function.
+///
+/// [Ziggurat algorithm]: https://doi.org/10.18637/jss.v005.i08
+#[derive(Clone, Copy, Debug)]
+pub struct Normal<T: Float> {
+    mean: T,
+    std_dev: T,
+}
+
+impl<T: Float> Normal<T> {
+    /// Creates a new [`Normal`] distribution with the given mean and standard deviation.
+    ///
+    /// Panic if the parameters are not finite or the standard deviation is negative.
+    pub fn new(mean: T, std_dev: T) -> Self {
+        Self::try_new(mean, std_dev).unwrap_or_else(|| {
+            panic!("invalid parameters: mean {mean:?}, standard deviation {std_dev:?}")
+        })
+    }
+
+    /// Creates a new [`Normal`] distribution with the given mean and standard deviation.
+    ///
+    /// Returns `None` if the parameters are not finite or the standard deviation is negative.
+    pub fn try_new(mean: T, std_dev: T) -> Option<Self> {
+        (mean.is_finite() && std_dev.is_finite() && std_dev >= T::ZERO)
+            .then_some(Normal { mean, std_dev })
+    }
+
+    /// Generate a random float using the given source of randomness.
+    ///
+    /// It is the same as [`Distribution::sample`], available without importing the trait.
+    #[inline]
+    pub fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
+        Distribution::sample(self, rng)
+    }
+
+    /// Fill the slice with random floats using the given source of randomness.
+    ///
+    /// It is the same as [`Distribution::fill`], available without importing the trait.
+    #[inline]
+    pub fn fill<R: RandomSource + ?Sized>(&self, rng: &mut R, data: &mut [T]) {
+        Distribution::fill(self, rng, data);
+    }
+}
+
+impl<T: Float> Distribution<T> for Normal<T> {
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
+        self.mean + self.std_dev * standard_normal(rng)
+    }
+
+    fn fill<R: RandomSource + ?Sized>(&self, rng: &mut R, data: &mut [T]) {
+        // the tables are loaded only once for the whole slice
+        let ziggurat = Ziggurat::get();
+        for x in data.iter_mut() {
+            let z = ziggurat.sample(rng);
+            *x = self.mean + self.std_dev * T::from_f64(z);
+        }
+    }
+}
+
+impl<T: Float> Default for Normal<T> {
+    /// The standard normal distribution, with zero mean and unit standard deviation.
+    fn default() -> Self {
+        Self::new(T::ZERO, T::ONE)
+    }
+}
+
+/// Generate a value from the standard normal distribution.
+#[inline]
+pub(super) fn standard_normal<T: Float>(rng: &mut (impl RandomSource + ?Sized)) -> T {
+    T::from_f64(Ziggurat::get().sample(rng))
+}
+
+/// The number of layers of the ziggurat.
+const LAYERS: usize = 256;
+
+/// The rightmost abscissa of the ziggurat, where the tail begins.
+const TAIL_START: f64 = 3.654_152_885_361_009;
+
+/// The area of each layer of the ziggurat.
+const V: f64 = 4.928_673_233_99e-3;
+
+/// The layers of the ziggurat covering the density `exp(-x²/2)`.
+///
+/// The layer `i` is the rectangle `[0, x[i]] × [f[i], f[i + 1]]`, the base layer
+/// `0` has the same area of the others and it also includes the tail beyond `TAIL_START`.
+struct Ziggurat {
+    x: [f64; LAYERS + 1],
+    f: [f64; LAYERS + 1],
+}
+
+impl Ziggurat {
+    /// Returns the tables, they are computed once on first use.
+    fn get() -> &'static Self {
+        static ZIGGURAT: OnceLock<Ziggurat> = OnceLock::new();
+        ZIGGURAT.get_or_init(Self::build)
+    }
+
+    fn build() -> Self {
+        let mut x = [0.0; LAYERS + 1];
+        x[0] = V / pdf(TAIL_START);
+        x[1] = TAIL_START;
+        for i in 2..LAYERS {
+            x[i] = (-2.0 * (V / x[i - 1] + pdf(x[i - 1])).ln()).sqrt();
+        }
+        x[LAYERS] = 0.0;
+
+        let f = x.map(pdf);
+        Self { x, f }
+    }
+
+    /// Generate a value from the standard normal distribution.
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> f64 {
+        const SCALE: f64 = 1.0 / (1u64 << 52) as f64;
+
+        loop {
+            // the lowest bits select the layer, the highest ones give a uniform in [-1, 1)
+            let bits = rng.next_u64();
+            let i = (bits as usize) % LAYERS;
+            let u = ((bits >> 11) as f64) * SCALE - 1.0;
+
+            let x = u * self.x[i];
+            if x.abs() < self.x[i + 1] {
+                return x;
+            }
+
+            if i == 0 {
+                return tail(rng, u < 0.0);
+            }
+
+            let (f0, f1) = (self.f[i], self.f[i + 1]);
+            if f0 + (f1 - f0) * f64::random(rng) < pdf(x) {
+                return x;
+            }
+        }
+    }
+}
+
+/// The unnormalized density of the standard normal distribution.
+#[inline]
+fn pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp()
+}
+
+/// Generate a value from the tail of the standard normal distribution beyond `TAIL_START`.
+#[cold]
+fn tail<R: RandomSource + ?Sized>(rng: &mut R, negative: bool) -> f64 {
+    loop {
+        // uniform values in (0, 1] to avoid the logarithm of zero
+        let x = -(1.0 - f64::random(rng)).ln() / TAIL_START;
+        let y = -(1.0 - f64::random(rng)).ln();
+        if 2.0 * y >= x * x {
+            return if negative {
+                -TAIL_START - x
+            } else {
+                TAIL_START + x
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rand::{testing::*, Rng};
+
+    #[test]
+    fn ziggurat_tables() {
+        let ziggurat = Ziggurat::get();
+
+        assert!((ziggurat.x[0] - 3.910_757_959_537_09).abs() < 1e-12);
+        assert!(ziggurat.x.windows(2).all(|w| w[0] > w[1]));
+        assert!(ziggurat.f.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(ziggurat.f[LAYERS], 1.0);
+
+        // every layer has the same area
+        for i in 1..LAYERS {
+            let area = ziggurat.x[i] * (ziggurat.f[i + 1] - ziggurat.f[i]);
+            assert!((area - V).abs() < 1e-9 * V);
+        }
+    }
+
+    #[test]
+    fn kolmogorov_smirnov_test_normal_f64() {
+        // fixed seed to avoid spurious failures, the test is repeated for several parameters
+        let mut rng = Rng::seed_from_u64(0x5eed);
+
+        for (mean, std_dev) in [(0.0, 1.0), (-3.0, 0.25), (10.0, 4.0)] {
+            let normal = Normal::<f64>::new(mean, std_dev);
+            let sample_size = 1_000_000;
+            let sample = (0..sample_size)
+                .map(|_| normal.sample(&mut rng))
+                .collect::<Vec<_>>();
+
+            let statistic =
+                kolmogorov_smirnov_statistic(sample, |x| normal_cdf((x - mean) / std_dev));
+            let critical_value = kolmogorov_smirnov_critical_value(sample_size);
+            assert!(statistic < critical_value);
+        }
+    }
+
+    #[test]
+    fn kolmogorov_smirnov_test_normal_fill_f32() {
+        let mut rng = Rng::seed_from_u64(0x5eed);
+
+        let normal = Normal::<f32>::new(1.0, 2.0);
+        let sample_size = 1_000_000;
+        let mut sample = vec![0.0; sample_size];
+        normal.fill(&mut rng, &mut sample);
+
+        let sample = sample.into_iter().map(f64::from).collect();
+        let statistic = kolmogorov_smirnov_statistic(sample, |x| normal_cdf((x - 1.0) / 2.0));
+        let critical_value = kolmogorov_smirnov_critical_value(sample_size);
+        assert!(statistic < critical_value);
+    }
+
+    #[test]
+    fn degenerate() {
+        let mut rng = Rng::from_random_state();
+        let normal = Normal::<f64>::new(5.0, 0.0);
+        assert!((0..100).all(|_| normal.sample(&mut rng) == 5.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_std_dev() {
+        Normal::<f64>::new(0.0, -1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn infinite_mean() {
+        Normal::<f32>::new(f32::INFINITY, 1.0);
+    }
+}
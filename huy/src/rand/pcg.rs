@@ -0,0 +1,115 @@
+use super::{RandomSource, SplitMix64};
+
+/// Random number generator implementing the PCG64 DXSM algorithm.
+///
+/// It is a 128 bits linear congruential generator with a cheap multiplier, whose output
+/// is permuted with the DXSM (double xorshift multiply) function. The `stream`
+/// selects one of the `2^127` independent sequences. This implementation is the
+/// translation of the [reference implementation] of NumPy.
+///
+/// [reference implementation]: https://github.com/numpy/numpy/blob/main/numpy/random/src/pcg64/pcg64.h
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pcg64Dxsm {
+    state: u128,
+    increment: u128,
+}
+
+impl Pcg64Dxsm {
+    /// The multiplier of the linear congruential generator.
+    const MULTIPLIER: u64 = 0xda942042e4dd58b5;
+
+    /// Create a new PRNG with the given initial state and stream.
+    pub fn new(state: u128, stream: u128) -> Self {
+        let increment = (stream << 1) | 1;
+        let mut rng = Self {
+            state: state.wrapping_add(increment),
+            increment,
+        };
+        rng.step();
+        rng
+    }
+
+    /// Create a new PRNG using the given seed, the first half is the initial state and
+    /// the second half is the stream.
+    pub fn from_seed(seed: [u8; 32]) -> Self {
+        let (state, stream) = seed.split_at(16);
+        Self::new(
+            u128::from_le_bytes(state.try_into().unwrap()),
+            u128::from_le_bytes(stream.try_into().unwrap()),
+        )
+    }
+
+    /// Create a new PRNG using a `u64` seed.
+    pub fn seed_from_u64(state: u64) -> Self {
+        let mut splitmix = SplitMix64::seed_from_u64(state);
+        let mut seed = [0; 32];
+        splitmix.fill_bytes(&mut seed);
+        Self::from_seed(seed)
+    }
+
+    #[inline]
+    fn step(&mut self) {
+        self.state = self
+            .state
+            .wrapping_mul(u128::from(Self::MULTIPLIER))
+            .wrapping_add(self.increment);
+    }
+}
+
+impl RandomSource for Pcg64Dxsm {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        // the output function is applied to the state before the step
+        let mut hi = (self.state >> 64) as u64;
+        let lo = self.state as u64 | 1;
+        hi ^= hi >> 32;
+        hi = hi.wrapping_mul(Self::MULTIPLIER);
+        hi ^= hi >> 48;
+        hi = hi.wrapping_mul(lo);
+
+        self.step();
+        hi
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pcg64dxsm_reference_implementation() {
+        let mut rng = Pcg64Dxsm::new(
+            0x0123456789abcdef_fedcba9876543210,
+            0x0011223344556677_8899aabbccddeeff,
+        );
+
+        // These values were produced using the reference implementation:
+        // https://github.com/numpy/numpy/blob/main/numpy/random/src/pcg64/pcg64.h
+        assert_eq!(rng.next_u64(), 12757469675821233111);
+        assert_eq!(rng.next_u64(), 11655708195039918206);
+        assert_eq!(rng.next_u64(), 16981065950407279959);
+        assert_eq!(rng.next_u64(), 17042796864936090333);
+        assert_eq!(rng.next_u64(), 16338321833799614893);
+        assert_eq!(rng.next_u64(), 514295968172724375);
+        assert_eq!(rng.next_u64(), 2404283542825299317);
+        assert_eq!(rng.next_u64(), 122898345930249730);
+        assert_eq!(rng.next_u64(), 3249568698836991687);
+        assert_eq!(rng.next_u64(), 7513317078038586870);
+    }
+
+    #[test]
+    fn streams() {
+        let mut a = Pcg64Dxsm::new(0x5eed, 0);
+        let mut b = Pcg64Dxsm::new(0x5eed, 1);
+        assert_ne!(a.next_u64(), b.next_u64());
+
+        let seed = core::array::from_fn(|i| i as u8);
+        assert_eq!(
+            Pcg64Dxsm::from_seed(seed),
+            Pcg64Dxsm::new(
+                u128::from_le_bytes(seed[..16].try_into().unwrap()),
+                u128::from_le_bytes(seed[16..].try_into().unwrap()),
+            )
+        );
+    }
+}
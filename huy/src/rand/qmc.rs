@@ -0,0 +1,768 @@
+//! Low-discrepancy sequences for quasi-Monte Carlo methods.
+
+use core::{iter::FusedIterator, marker::PhantomData};
+
+use self::directions::DIRECTION_NUMBERS;
+use super::{Random, RandomSource, SliceRandom};
+use crate::math::{Point2, Point3};
+
+mod directions;
+
+/// A trait for a type that can represent a point of a low-discrepancy sequence.
+pub trait QuasiRandom: sealed::QuasiRandom {}
+
+impl<const N: usize> QuasiRandom for [f64; N] {}
+impl QuasiRandom for Point2<f64> {}
+impl QuasiRandom for Point3<f64> {}
+
+/// The Halton sequence, the `j`-th coordinate is the radical inverse of the index in
+/// the base given by the `j`-th prime number.
+///
+/// The sequence starts from the index zero, whose point is the origin. The scrambled
+/// variant applies a random permutation to each digit of the radical inverse.
+#[derive(Clone, Debug)]
+pub struct Halton<P: QuasiRandom> {
+    bases: Box<[u64]>,
+    permutations: Option<Box<[Box<[u64]>]>>,
+    index: u64,
+    _marker: PhantomData<fn() -> P>,
+}
+
+impl<P: QuasiRandom> Halton<P> {
+    /// Creates a new [`Halton`] sequence.
+    pub fn new() -> Self {
+        Self {
+            bases: primes(P::DIMENSION),
+            permutations: None,
+            index: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a new [`Halton`] sequence whose digits are scrambled with random
+    /// permutations, generated using the given source of randomness.
+    pub fn scrambled<R: RandomSource + ?Sized>(rng: &mut R) -> Self {
+        let bases = primes(P::DIMENSION);
+        let permutations = bases
+            .iter()
+            .map(|&base| {
+                let digits = radical_inverse_digits(base);
+                let mut permutations = Vec::with_capacity(digits * base as usize);
+                for _ in 0..digits {
+                    let start = permutations.len();
+                    permutations.extend(0..base);
+                    permutations[start..].shuffle(rng);
+                }
+                permutations.into_boxed_slice()
+            })
+            .collect();
+
+        Self {
+            bases,
+            permutations: Some(permutations),
+            index: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the point of the sequence with the given index.
+    pub fn point(&self, index: u64) -> P {
+        P::from_fn(|j| match &self.permutations {
+            Some(permutations) => scrambled_radical_inverse(index, self.bases[j], &permutations[j]),
+            None => radical_inverse(index, self.bases[j]),
+        })
+    }
+}
+
+impl<P: QuasiRandom> Default for Halton<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: QuasiRandom> Iterator for Halton<P> {
+    type Item = P;
+
+    #[inline]
+    fn next(&mut self) -> Option<P> {
+        let point = self.point(self.index);
+        self.index += 1;
+        Some(point)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<P> {
+        self.index += n as u64;
+        self.next()
+    }
+}
+
+impl<P: QuasiRandom> FusedIterator for Halton<P> {}
+
+/// The Sobol sequence with the direction numbers of [Joe and Kuo].
+///
+/// The sequence contains `2^32` points generated in Gray code order, starting from the
+/// origin, only the first [`Sobol::MAX_DIMENSION`] dimensions are available. The
+/// scrambled variant applies the hash-based Owen scrambling described in [Practical
+/// Hash-based Owen Scrambling], which preserves the net properties of the sequence.
+///
+/// The first 21 dimensions use the direction numbers of Joe and Kuo, file
+/// `new-joe-kuo-6.21201`. The following ones use the next primitive polynomials in the
+/// same order, with initial direction numbers chosen to minimize the t-values of the
+/// two-dimensional projections, they are generated by `scripts/sobol_direction_numbers.py`.
+///
+/// [Joe and Kuo]: https://web.maths.unsw.edu.au/~fkuo/sobol/
+/// [Practical Hash-based Owen Scrambling]: https://jcgt.org/published/0009/04/01/
+#[derive(Clone, Debug)]
+pub struct Sobol<P: QuasiRandom> {
+    directions: Box<[[u32; 32]]>,
+    seeds: Option<Box<[u32]>>,
+    state: Box<[u32]>,
+    index: u64,
+    _marker: PhantomData<fn() -> P>,
+}
+
+impl<P: QuasiRandom> Sobol<P> {
+    /// The maximum dimension supported by the sequence.
+    pub const MAX_DIMENSION: usize = 1 + DIRECTION_NUMBERS.len();
+
+    /// Creates a new [`Sobol`] sequence.
+    ///
+    /// Panic if the dimension is greater than [`Self::MAX_DIMENSION`].
+    pub fn new() -> Self {
+        Self::try_new()
+            .unwrap_or_else(|| panic!("invalid parameters: dimension {:?}", P::DIMENSION))
+    }
+
+    /// Creates a new [`Sobol`] sequence.
+    ///
+    /// Returns `None` if the dimension is greater than [`Self::MAX_DIMENSION`].
+    pub fn try_new() -> Option<Self> {
+        (P::DIMENSION <= Self::MAX_DIMENSION).then(|| Self {
+            directions: (0..P::DIMENSION).map(direction_numbers).collect(),
+            seeds: None,
+            state: vec![0; P::DIMENSION].into_boxed_slice(),
+            index: 0,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Creates a new [`Sobol`] sequence with Owen scrambling, the seeds are generated
+    /// using the given source of randomness.
+    ///
+    /// Panic if the dimension is greater than [`Self::MAX_DIMENSION`].
+    pub fn owen_scrambled<R: RandomSource + ?Sized>(rng: &mut R) -> Self {
+        Self::try_owen_scrambled(rng)
+            .unwrap_or_else(|| panic!("invalid parameters: dimension {:?}", P::DIMENSION))
+    }
+
+    /// Creates a new [`Sobol`] sequence with Owen scrambling, the seeds are generated
+    /// using the given source of randomness.
+    ///
+    /// Returns `None` if the dimension is greater than [`Self::MAX_DIMENSION`].
+    pub fn try_owen_scrambled<R: RandomSource + ?Sized>(rng: &mut R) -> Option<Self> {
+        let mut sobol = Self::try_new()?;
+        let mut seeds = vec![0; P::DIMENSION];
+        u32::fill_random(rng, &mut seeds);
+        sobol.seeds = Some(seeds.into_boxed_slice());
+        Some(sobol)
+    }
+
+    /// Returns the point of the sequence with the given index.
+    ///
+    /// Panic if the index is not less than `2^32`.
+    pub fn point(&self, index: u64) -> P {
+        let index = u32::try_from(index).expect("index out of range");
+        self.to_point(|j| gray_code_point(&self.directions[j], index))
+    }
+
+    #[inline]
+    fn to_point(&self, x: impl Fn(usize) -> u32) -> P {
+        const SCALE: f64 = 1.0 / (1u64 << 32) as f64;
+        P::from_fn(|j| {
+            let x = match &self.seeds {
+                Some(seeds) => nested_uniform_scramble(x(j), seeds[j]),
+                None => x(j),
+            };
+            x as f64 * SCALE
+        })
+    }
+}
+
+impl<P: QuasiRandom> Default for Sobol<P> {
+    /// Creates a new [`Sobol`] sequence, see [`Sobol::new`].
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: QuasiRandom> Iterator for Sobol<P> {
+    type Item = P;
+
+    fn next(&mut self) -> Option<P> {
+        const LEN: u64 = 1 << 32;
+        if self.index >= LEN {
+            return None;
+        }
+
+        if self.index > 0 {
+            let bit = self.index.trailing_zeros() as usize;
+            for (x, directions) in self.state.iter_mut().zip(&self.directions) {
+                *x ^= directions[bit];
+            }
+        }
+        self.index += 1;
+        Some(self.to_point(|j| self.state[j]))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (1u64 << 32).saturating_sub(self.index);
+        match usize::try_from(len) {
+            Ok(len) => (len, Some(len)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<P> {
+        let index = self.index.saturating_add(n as u64);
+        if index >= 1 << 32 {
+            self.index = 1 << 32;
+            return None;
+        }
+
+        for (x, directions) in self.state.iter_mut().zip(&self.directions) {
+            *x = gray_code_point(directions, index as u32);
+        }
+        self.index = index + 1;
+        Some(self.to_point(|j| self.state[j]))
+    }
+}
+
+impl<P: QuasiRandom> FusedIterator for Sobol<P> {}
+
+/// The Kronecker sequence, the `n`-th point is the fractional part of `n α`.
+///
+/// The default vector `α` is the one of the [R-sequence], obtained from the generalized
+/// golden ratio. In two dimensions it is the R2 sequence, based on the plastic number.
+/// The sequence starts from the index zero, whose point is the origin.
+///
+/// [R-sequence]: https://extremelearning.com.au/unreasonable-effectiveness-of-quasirandom-sequences/
+#[derive(Clone, Debug)]
+pub struct Kronecker<P: QuasiRandom> {
+    alpha: Box<[u64]>,
+    state: Box<[u64]>,
+    _marker: PhantomData<fn() -> P>,
+}
+
+impl<P: QuasiRandom> Kronecker<P> {
+    /// Creates a new [`Kronecker`] sequence, using the vector of the R-sequence.
+    pub fn new() -> Self {
+        let dimension = P::DIMENSION as i32;
+        let phi = generalized_golden_ratio(dimension);
+        let alpha = (1..=dimension).map(|j| to_fixed_point(phi.powi(-j)));
+        Self::from_fixed_point(alpha.collect())
+    }
+
+    /// Creates a new [`Kronecker`] sequence with the given vector `α`.
+    ///
+    /// Panic if any component of `α` is not finite.
+    pub fn with_alpha(alpha: P) -> Self {
+        Self::try_with_alpha(alpha).unwrap_or_else(|| panic!("invalid parameters: alpha"))
+    }
+
+    /// Creates a new [`Kronecker`] sequence with the given vector `α`.
+    ///
+    /// Returns `None` if any component of `α` is not finite.
+    pub fn try_with_alpha(alpha: P) -> Option<Self> {
+        let alpha = (0..P::DIMENSION)
+            .map(|j| alpha.get(j))
+            .map(|x| x.is_finite().then(|| to_fixed_point(x.rem_euclid(1.0))))
+            .collect::<Option<_>>()?;
+        Some(Self::from_fixed_point(alpha))
+    }
+
+    fn from_fixed_point(alpha: Box<[u64]>) -> Self {
+        Self {
+            state: vec![0; alpha.len()].into_boxed_slice(),
+            alpha,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the point of the sequence with the given index.
+    pub fn point(&self, index: u64) -> P {
+        P::from_fn(|j| from_fixed_point(self.alpha[j].wrapping_mul(index)))
+    }
+}
+
+impl<P: QuasiRandom> Default for Kronecker<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: QuasiRandom> Iterator for Kronecker<P> {
+    type Item = P;
+
+    #[inline]
+    fn next(&mut self) -> Option<P> {
+        let point = P::from_fn(|j| from_fixed_point(self.state[j]));
+        for (x, alpha) in self.state.iter_mut().zip(&self.alpha) {
+            *x = x.wrapping_add(*alpha);
+        }
+        Some(point)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<P> {
+        for (x, alpha) in self.state.iter_mut().zip(&self.alpha) {
+            *x = x.wrapping_add(alpha.wrapping_mul(n as u64));
+        }
+        self.next()
+    }
+}
+
+impl<P: QuasiRandom> FusedIterator for Kronecker<P> {}
+
+/// The largest `f64` less than one.
+const ONE_MINUS_EPSILON: f64 = 1.0 - f64::EPSILON / 2.0;
+
+/// Returns the first `count` prime numbers.
+fn primes(count: usize) -> Box<[u64]> {
+    let mut primes = Vec::with_capacity(count);
+    let mut candidate = 2;
+    while primes.len() < count {
+        if primes
+            .iter()
+            .take_while(|&&p| p * p <= candidate)
+            .all(|&p| candidate % p != 0)
+        {
+            primes.push(candidate);
+        }
+        candidate += 1;
+    }
+    primes.into_boxed_slice()
+}
+
+/// Computes the radical inverse of the index in the given base.
+fn radical_inverse(mut index: u64, base: u64) -> f64 {
+    let inv_base = (base as f64).recip();
+    let mut inv_base_power = 1.0;
+    let mut reversed = 0.0;
+    while index > 0 {
+        let digit = index % base;
+        index /= base;
+        inv_base_power *= inv_base;
+        reversed += digit as f64 * inv_base_power;
+    }
+    reversed.min(ONE_MINUS_EPSILON)
+}
+
+/// Returns the number of digits of the radical inverse representable by a `f64`.
+fn radical_inverse_digits(base: u64) -> usize {
+    let inv_base = (base as f64).recip();
+    let mut inv_base_power = 1.0;
+    let mut digits = 0;
+    while inv_base_power > f64::EPSILON / 2.0 {
+        inv_base_power *= inv_base;
+        digits += 1;
+    }
+    digits
+}
+
+/// Computes the radical inverse of the index in the given base, each digit is
+/// permuted, including the leading zeros of the reversed representation.
+fn scrambled_radical_inverse(mut index: u64, base: u64, permutations: &[u64]) -> f64 {
+    let inv_base = (base as f64).recip();
+    let mut inv_base_power = 1.0;
+    let mut reversed = 0.0;
+    for permutation in permutations.chunks_exact(base as usize) {
+        let digit = index % base;
+        index /= base;
+        inv_base_power *= inv_base;
+        reversed += permutation[digit as usize] as f64 * inv_base_power;
+    }
+    reversed.min(ONE_MINUS_EPSILON)
+}
+
+/// Computes the direction numbers of the given dimension, aligned to the most
+/// significant bit.
+fn direction_numbers(dimension: usize) -> [u32; 32] {
+    let mut directions = [0; 32];
+    let Some(&(degree, coefficients, initial)) =
+        dimension.checked_sub(1).map(|d| &DIRECTION_NUMBERS[d])
+    else {
+        for (bit, direction) in directions.iter_mut().enumerate() {
+            *direction = 1 << (31 - bit);
+        }
+        return directions;
+    };
+
+    for (bit, m) in initial.iter().enumerate() {
+        directions[bit] = m << (31 - bit);
+    }
+    for bit in degree..32 {
+        let mut direction = directions[bit - degree] ^ (directions[bit - degree] >> degree);
+        for k in 1..degree {
+            if (coefficients >> (degree - 1 - k)) & 1 != 0 {
+                direction ^= directions[bit - k];
+            }
+        }
+        directions[bit] = direction;
+    }
+    directions
+}
+
+/// Computes the coordinate of the point with the given index in Gray code order.
+#[inline]
+fn gray_code_point(directions: &[u32; 32], index: u32) -> u32 {
+    let gray = index ^ (index >> 1);
+    (0..32)
+        .filter(|bit| gray & (1 << bit) != 0)
+        .fold(0, |x, bit| x ^ directions[bit])
+}
+
+/// Scramble the bits of a fixed point number in `[0, 1)`, each bit is flipped
+/// depending on the more significant ones.
+#[inline]
+fn nested_uniform_scramble(x: u32, seed: u32) -> u32 {
+    laine_karras_permutation(x.reverse_bits(), seed).reverse_bits()
+}
+
+/// A hash function where each bit depends only on the less significant ones.
+#[inline]
+fn laine_karras_permutation(mut x: u32, seed: u32) -> u32 {
+    x ^= x.wrapping_mul(0x3d20adea);
+    x = x.wrapping_add(seed);
+    x = x.wrapping_mul((seed >> 16) | 1);
+    x ^= x.wrapping_mul(0x05526c56);
+    x ^= x.wrapping_mul(0x53a22864);
+    x
+}
+
+/// Computes the unique positive root of `x^(d+1) = x + 1`.
+fn generalized_golden_ratio(dimension: i32) -> f64 {
+    let mut phi = 2.0f64;
+    for _ in 0..64 {
+        let f = phi.powi(dimension + 1) - phi - 1.0;
+        let df = f64::from(dimension + 1) * phi.powi(dimension) - 1.0;
+        phi -= f / df;
+    }
+    phi
+}
+
+/// Convert a number in `[0, 1)` to a 64 bits fixed point number.
+#[inline]
+fn to_fixed_point(x: f64) -> u64 {
+    (x * (u64::MAX as f64 + 1.0)) as u64
+}
+
+/// Convert a 64 bits fixed point number to a number in `[0, 1)`.
+#[inline]
+fn from_fixed_point(x: u64) -> f64 {
+    const SCALE: f64 = 1.0 / (1u64 << 53) as f64;
+    (x >> 11) as f64 * SCALE
+}
+
+mod sealed {
+    use crate::math::{Point2, Point3};
+
+    pub trait QuasiRandom {
+        /// The number of coordinates of the point.
+        const DIMENSION: usize;
+
+        /// Creates a point computing each coordinate with the given function.
+        fn from_fn(f: impl FnMut(usize) -> f64) -> Self;
+
+        /// Returns the coordinate with the given index.
+        fn get(&self, index: usize) -> f64;
+    }
+
+    impl<const N: usize> QuasiRandom for [f64; N] {
+        const DIMENSION: usize = N;
+
+        #[inline]
+        fn from_fn(f: impl FnMut(usize) -> f64) -> Self {
+            core::array::from_fn(f)
+        }
+
+        #[inline]
+        fn get(&self, index: usize) -> f64 {
+            self[index]
+        }
+    }
+
+    impl QuasiRandom for Point2<f64> {
+        const DIMENSION: usize = 2;
+
+        #[inline]
+        fn from_fn(mut f: impl FnMut(usize) -> f64) -> Self {
+            Point2::new(f(0), f(1))
+        }
+
+        #[inline]
+        fn get(&self, index: usize) -> f64 {
+            <[f64; 2]>::from(*self - Point2::ORIGIN)[index]
+        }
+    }
+
+    impl QuasiRandom for Point3<f64> {
+        const DIMENSION: usize = 3;
+
+        #[inline]
+        fn from_fn(mut f: impl FnMut(usize) -> f64) -> Self {
+            Point3::new(f(0), f(1), f(2))
+        }
+
+        #[inline]
+        fn get(&self, index: usize) -> f64 {
+            <[f64; 3]>::from(*self - Point3::ORIGIN)[index]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        math::{point2, point3},
+        rand::Rng,
+    };
+
+    /// Check that each of the `count` intervals of equal length contains exactly one of
+    /// the first `count` values.
+    fn assert_stratified(values: impl Iterator<Item = f64>, count: usize) {
+        let mut occupied = vec![false; count];
+        for x in values.take(count) {
+            assert!((0.0..1.0).contains(&x), "{x} out of range");
+            // the points can be on the boundaries of the intervals up to rounding errors
+            let cell = (x * count as f64 + 1e-9) as usize;
+            assert!(!occupied[cell], "{x} in an occupied cell");
+            occupied[cell] = true;
+        }
+        assert!(occupied.into_iter().all(|x| x));
+    }
+
+    /// Check if the polynomial of degree `s` with inner coefficients `a` is primitive
+    /// over GF(2), that is the order of `x` is `2^s - 1`.
+    fn is_primitive(degree: usize, coefficients: u32) -> bool {
+        let modulus = (1u64 << degree) | (u64::from(coefficients) << 1) | 1;
+        let multiply_by_x = |p: u64| {
+            let p = p << 1;
+            if p & (1 << degree) != 0 {
+                p ^ modulus
+            } else {
+                p
+            }
+        };
+
+        let order = (1u64 << degree) - 1;
+        let mut p = 1;
+        for k in 1..=order {
+            p = multiply_by_x(p);
+            if p == 1 {
+                return k == order;
+            }
+        }
+        false
+    }
+
+    #[test]
+    fn halton() {
+        let points = Halton::<[f64; 3]>::new().take(5).collect::<Vec<_>>();
+        let expected = [
+            [0.0, 0.0, 0.0],
+            [1.0 / 2.0, 1.0 / 3.0, 1.0 / 5.0],
+            [1.0 / 4.0, 2.0 / 3.0, 2.0 / 5.0],
+            [3.0 / 4.0, 1.0 / 9.0, 3.0 / 5.0],
+            [1.0 / 8.0, 4.0 / 9.0, 4.0 / 5.0],
+        ];
+        for (point, expected) in points.iter().zip(&expected) {
+            for (x, y) in point.iter().zip(expected) {
+                assert!((x - y).abs() < 1e-15);
+            }
+        }
+
+        let mut halton = Halton::<Point2<f64>>::new();
+        assert_eq!(halton.nth(3), Some(point2(0.75, 1.0 / 9.0)));
+        assert_eq!(halton.next(), Some(point2(0.125, 4.0 / 9.0)));
+        assert_eq!(halton.point(1), point2(0.5, 1.0 / 3.0));
+
+        assert_eq!(&*primes(10), &[2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    }
+
+    #[test]
+    fn halton_stratification() {
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        for halton in [Halton::<[f64; 4]>::new(), Halton::scrambled(&mut rng)] {
+            let bases = [2usize, 3, 5, 7];
+            for (j, base) in bases.into_iter().enumerate() {
+                let count = (0..).map(|k| base.pow(k)).find(|&n| n >= 1000).unwrap();
+                assert_stratified(halton.clone().map(|x| x[j]), count);
+            }
+        }
+    }
+
+    #[test]
+    fn sobol() {
+        let points = Sobol::<[f64; 3]>::new().take(8).collect::<Vec<_>>();
+        let expected = [
+            [0.0, 0.0, 0.0],
+            [0.5, 0.5, 0.5],
+            [0.75, 0.25, 0.25],
+            [0.25, 0.75, 0.75],
+            [0.375, 0.375, 0.625],
+            [0.875, 0.875, 0.125],
+            [0.625, 0.125, 0.875],
+            [0.125, 0.625, 0.375],
+        ];
+        assert_eq!(points, expected);
+
+        let mut sobol = Sobol::<Point3<f64>>::new();
+        assert_eq!(sobol.nth(4), Some(point3(0.375, 0.375, 0.625)));
+        assert_eq!(sobol.next(), Some(point3(0.875, 0.875, 0.125)));
+        assert_eq!(sobol.point(7), point3(0.125, 0.625, 0.375));
+
+        let mut sobol = Sobol::<[f64; 1]>::new();
+        assert_eq!(
+            sobol.nth(u32::MAX as usize),
+            Some([sobol.point(u32::MAX.into())[0]])
+        );
+        assert_eq!(sobol.next(), None);
+    }
+
+    #[test]
+    fn sobol_nth() {
+        let sobol = Sobol::<[f64; 8]>::new();
+        let points = sobol.clone().take(100).collect::<Vec<_>>();
+        for (index, point) in points.iter().enumerate() {
+            assert_eq!(&sobol.point(index as u64), point);
+            assert_eq!(sobol.clone().nth(index).as_ref(), Some(point));
+        }
+
+        let mut skipped = sobol.clone();
+        skipped.nth(10);
+        assert!(skipped.take(89).eq(sobol.skip(11).take(89)));
+    }
+
+    #[test]
+    fn sobol_direction_numbers() {
+        // the rows use all the primitive polynomials sorted by degree and coefficients
+        let polynomials = (1..)
+            .flat_map(|degree| (0..1 << (degree - 1)).map(move |a| (degree, a)))
+            .filter(|&(degree, a)| is_primitive(degree, a));
+        for ((degree, coefficients, initial), polynomial) in
+            DIRECTION_NUMBERS.iter().zip(polynomials)
+        {
+            assert_eq!((*degree, *coefficients), polynomial);
+            assert_eq!(initial.len(), *degree);
+            for (bit, m) in initial.iter().enumerate() {
+                assert!(m % 2 == 1 && *m < 1 << (bit + 1));
+            }
+        }
+
+        // the last row of Joe and Kuo
+        assert_eq!(
+            DIRECTION_NUMBERS[19],
+            (7, 4, &[1, 3, 7, 13, 13, 15, 69][..])
+        );
+    }
+
+    #[test]
+    fn sobol_stratification() {
+        const DIMENSION: usize = Sobol::<[f64; 0]>::MAX_DIMENSION;
+
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        let sequences = [
+            Sobol::<[f64; DIMENSION]>::new(),
+            Sobol::owen_scrambled(&mut rng),
+        ];
+        for sobol in sequences {
+            // every dimension is stratified, up to the last one
+            let points = sobol.clone().take(1024).collect::<Vec<_>>();
+            for j in 0..DIMENSION {
+                assert_stratified(points.iter().map(|x| x[j]), 1024);
+            }
+
+            // the first two dimensions form a (0, m, 2)-net
+            for k in 0..=6 {
+                let mut occupied = [false; 64];
+                for [x, y, ..] in sobol.clone().take(64) {
+                    let cell = ((x * (1 << k) as f64) as usize) << (6 - k)
+                        | (y * (1 << (6 - k)) as f64) as usize;
+                    assert!(!occupied[cell]);
+                    occupied[cell] = true;
+                }
+            }
+        }
+
+        assert!(Sobol::<[f64; DIMENSION + 1]>::try_new().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid parameters: dimension")]
+    fn sobol_invalid_dimension() {
+        Sobol::<[f64; 1000]>::new();
+    }
+
+    #[test]
+    fn kronecker() {
+        let mut r2 = Kronecker::<Point2<f64>>::new();
+        assert_eq!(r2.next(), Some(Point2::ORIGIN));
+        let alpha = [0.754_877_666_246_692_7, 0.569_840_290_998_053_2];
+        let [x, y]: [f64; 2] = (r2.next().unwrap() - Point2::ORIGIN).into();
+        assert!((x - alpha[0]).abs() < 1e-15 && (y - alpha[1]).abs() < 1e-15);
+
+        let kronecker = Kronecker::<[f64; 5]>::new();
+        let points = kronecker.clone().take(100).collect::<Vec<_>>();
+        for (index, point) in points.iter().enumerate() {
+            assert_eq!(&kronecker.point(index as u64), point);
+            assert_eq!(kronecker.clone().nth(index).as_ref(), Some(point));
+        }
+
+        let mut kronecker = Kronecker::with_alpha([0.25, 1.5]);
+        assert_eq!(kronecker.nth(3), Some([0.75, 0.5]));
+        assert!(Kronecker::try_with_alpha([0.5, f64::NAN]).is_none());
+    }
+
+    #[test]
+    fn integration() {
+        // the integral of the product of the coordinates over the unit cube is 2^-d
+        fn integrate(points: impl Iterator<Item = [f64; 5]>) -> f64 {
+            let count = 1 << 12;
+            points
+                .take(count)
+                .map(|x| x.iter().product::<f64>())
+                .sum::<f64>()
+                / count as f64
+        }
+
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        let exact = 1.0 / 32.0;
+        // the Kronecker sequence converges slower for non-periodic functions
+        let estimates = [
+            (integrate(Halton::new()), 1e-2),
+            (integrate(Halton::scrambled(&mut rng)), 1e-2),
+            (integrate(Sobol::new()), 1e-2),
+            (integrate(Sobol::owen_scrambled(&mut rng)), 1e-2),
+            (integrate(Kronecker::new()), 3e-2),
+        ];
+        for (estimate, tolerance) in estimates {
+            assert!((estimate - exact).abs() < tolerance * exact, "{estimate}");
+        }
+    }
+}
@@ -0,0 +1,350 @@
+//! The direction numbers of the Sobol sequence.
+//!
+//! Generated by `scripts/sobol_direction_numbers.py`, do not edit.
+
+/// The primitive polynomials and the initial direction numbers, starting from the
+/// second dimension.
+///
+/// Each row contains the degree `s` of the polynomial, the bits `a` of its inner
+/// coefficients and the initial direction numbers `m`. The first 20 rows are the ones of
+/// Joe and Kuo, the following rows use the next polynomials in the same order, with
+/// initial direction numbers minimizing the t-values of the two-dimensional projections.
+#[rustfmt::skip]
+pub(super) const DIRECTION_NUMBERS: &[(usize, u32, &[u32])] = &[
+    (1, 0, &[1]),
+    (2, 1, &[1, 3]),
+    (3, 1, &[1, 3, 1]),
+    (3, 2, &[1, 1, 1]),
+    (4, 1, &[1, 1, 3, 3]),
+    (4, 4, &[1, 3, 5, 13]),
+    (5, 2, &[1, 1, 5, 5, 17]),
+    (5, 4, &[1, 1, 5, 5, 5]),
+    (5, 7, &[1, 1, 7, 11, 19]),
+    (5, 11, &[1, 1, 5, 1, 1]),
+    (5, 13, &[1, 1, 1, 3, 11]),
+    (5, 14, &[1, 3, 5, 5, 31]),
+    (6, 1, &[1, 3, 3, 9, 7, 49]),
+    (6, 13, &[1, 1, 1, 15, 21, 21]),
+    (6, 16, &[1, 3, 1, 13, 27, 49]),
+    (6, 19, &[1, 1, 1, 15, 7, 5]),
+    (6, 22, &[1, 3, 1, 15, 13, 25]),
+    (6, 25, &[1, 1, 5, 5, 19, 61]),
+    (7, 1, &[1, 3, 7, 11, 23, 15, 103]),
+    (7, 4, &[1, 3, 7, 13, 13, 15, 69]),
+    (7, 7, &[1, 1, 1, 9, 7, 63, 117]),
+    (7, 8, &[1, 3, 1, 1, 13, 43, 115]),
+    (7, 14, &[1, 3, 7, 1, 5, 47, 57]),
+    (7, 19, &[1, 3, 5, 7, 19, 17, 103]),
+    (7, 21, &[1, 3, 7, 9, 11, 15, 21]),
+    (7, 28, &[1, 3, 3, 7, 17, 55, 17]),
+    (7, 31, &[1, 1, 7, 9, 13, 5, 55]),
+    (7, 32, &[1, 1, 7, 7, 13, 57, 123]),
+    (7, 37, &[1, 3, 7, 5, 1, 21, 101]),
+    (7, 41, &[1, 3, 1, 3, 3, 61, 33]),
+    (7, 42, &[1, 1, 3, 5, 11, 59, 19]),
+    (7, 50, &[1, 1, 3, 13, 15, 19, 5]),
+    (7, 55, &[1, 1, 7, 7, 1, 1, 61]),
+    (7, 56, &[1, 1, 7, 11, 11, 59, 101]),
+    (7, 59, &[1, 3, 1, 3, 23, 25, 5]),
+    (7, 62, &[1, 1, 5, 15, 27, 3, 115]),
+    (8, 14, &[1, 1, 5, 3, 25, 55, 1, 157]),
+    (8, 21, &[1, 1, 1, 15, 11, 57, 35, 213]),
+    (8, 22, &[1, 1, 3, 5, 21, 61, 111, 189]),
+    (8, 38, &[1, 3, 7, 7, 31, 51, 45, 103]),
+    (8, 47, &[1, 3, 5, 3, 25, 19, 5, 89]),
+    (8, 49, &[1, 3, 3, 7, 15, 13, 53, 223]),
+    (8, 50, &[1, 3, 7, 15, 13, 47, 109, 9]),
+    (8, 52, &[1, 3, 5, 11, 21, 45, 7, 193]),
+    (8, 56, &[1, 1, 3, 9, 25, 43, 13, 39]),
+    (8, 67, &[1, 1, 7, 11, 21, 57, 3, 217]),
+    (8, 70, &[1, 1, 5, 3, 27, 19, 69, 153]),
+    (8, 84, &[1, 3, 1, 15, 15, 61, 87, 181]),
+    (8, 97, &[1, 3, 3, 9, 27, 45, 123, 57]),
+    (8, 103, &[1, 1, 7, 13, 5, 33, 49, 201]),
+    (8, 115, &[1, 3, 7, 11, 19, 31, 33, 135]),
+    (8, 122, &[1, 3, 7, 15, 19, 59, 5, 61]),
+    (9, 8, &[1, 1, 5, 3, 19, 53, 97, 69, 355]),
+    (9, 13, &[1, 3, 5, 3, 17, 7, 123, 125, 215]),
+    (9, 16, &[1, 3, 3, 7, 15, 3, 69, 105, 221]),
+    (9, 22, &[1, 1, 3, 7, 25, 25, 61, 161, 239]),
+    (9, 25, &[1, 3, 3, 3, 17, 9, 47, 43, 77]),
+    (9, 44, &[1, 3, 3, 1, 31, 55, 115, 67, 399]),
+    (9, 47, &[1, 3, 1, 3, 7, 53, 61, 155, 507]),
+    (9, 52, &[1, 1, 1, 9, 1, 29, 49, 49, 333]),
+    (9, 55, &[1, 1, 5, 11, 19, 11, 59, 13, 171]),
+    (9, 59, &[1, 1, 3, 3, 3, 5, 1, 209, 311]),
+    (9, 62, &[1, 1, 5, 7, 3, 43, 75, 131, 297]),
+    (9, 67, &[1, 3, 7, 3, 11, 61, 93, 5, 67]),
+    (9, 74, &[1, 1, 3, 15, 7, 57, 11, 99, 267]),
+    (9, 81, &[1, 1, 3, 7, 29, 17, 33, 95, 501]),
+    (9, 82, &[1, 1, 1, 11, 27, 11, 125, 65, 377]),
+    (9, 87, &[1, 1, 7, 13, 5, 47, 81, 25, 69]),
+    (9, 91, &[1, 3, 7, 5, 5, 15, 123, 247, 53]),
+    (9, 94, &[1, 1, 1, 7, 1, 47, 29, 111, 435]),
+    (9, 103, &[1, 1, 3, 11, 19, 23, 21, 1, 195]),
+    (9, 104, &[1, 1, 5, 15, 3, 19, 127, 105, 499]),
+    (9, 109, &[1, 3, 5, 3, 19, 33, 121, 185, 285]),
+    (9, 122, &[1, 3, 7, 5, 31, 27, 67, 205, 85]),
+    (9, 124, &[1, 3, 1, 5, 11, 29, 71, 137, 115]),
+    (9, 137, &[1, 1, 7, 5, 23, 25, 47, 165, 41]),
+    (9, 138, &[1, 3, 3, 9, 29, 13, 29, 231, 291]),
+    (9, 143, &[1, 3, 3, 9, 9, 5, 121, 43, 181]),
+    (9, 145, &[1, 1, 5, 5, 3, 1, 115, 231, 205]),
+    (9, 152, &[1, 1, 3, 5, 3, 9, 85, 43, 479]),
+    (9, 157, &[1, 1, 3, 1, 25, 7, 117, 3, 467]),
+    (9, 167, &[1, 1, 3, 11, 29, 55, 87, 83, 319]),
+    (9, 173, &[1, 1, 7, 15, 31, 49, 77, 135, 349]),
+    (9, 176, &[1, 1, 5, 1, 3, 3, 49, 245, 489]),
+    (9, 181, &[1, 3, 5, 9, 31, 25, 55, 81, 5]),
+    (9, 182, &[1, 3, 5, 13, 9, 23, 11, 69, 81]),
+    (9, 185, &[1, 3, 5, 3, 9, 53, 33, 161, 453]),
+    (9, 191, &[1, 3, 1, 13, 1, 45, 3, 95, 203]),
+    (9, 194, &[1, 3, 1, 15, 25, 33, 81, 217, 455]),
+    (9, 199, &[1, 3, 5, 13, 23, 35, 101, 25, 139]),
+    (9, 218, &[1, 3, 1, 3, 29, 33, 87, 237, 341]),
+    (9, 220, &[1, 1, 3, 15, 3, 7, 113, 209, 121]),
+    (9, 227, &[1, 1, 5, 9, 23, 49, 1, 25, 167]),
+    (9, 229, &[1, 1, 5, 13, 13, 5, 113, 135, 419]),
+    (9, 230, &[1, 3, 3, 11, 31, 15, 111, 209, 383]),
+    (9, 234, &[1, 1, 3, 7, 3, 55, 69, 219, 203]),
+    (9, 236, &[1, 3, 7, 7, 9, 5, 119, 73, 397]),
+    (9, 241, &[1, 3, 1, 9, 17, 45, 51, 119, 403]),
+    (9, 244, &[1, 3, 5, 3, 27, 35, 35, 201, 327]),
+    (9, 253, &[1, 3, 1, 3, 19, 33, 53, 241, 83]),
+    (10, 4, &[1, 1, 1, 15, 15, 3, 29, 175, 277, 911]),
+    (10, 13, &[1, 3, 3, 5, 9, 41, 73, 189, 501, 893]),
+    (10, 19, &[1, 3, 7, 9, 3, 61, 41, 101, 295, 513]),
+    (10, 22, &[1, 3, 1, 13, 7, 25, 93, 181, 175, 965]),
+    (10, 50, &[1, 3, 3, 5, 25, 61, 9, 99, 265, 347]),
+    (10, 55, &[1, 1, 1, 15, 11, 63, 111, 227, 369, 433]),
+    (10, 64, &[1, 3, 7, 1, 7, 21, 5, 191, 121, 265]),
+    (10, 69, &[1, 1, 1, 1, 3, 9, 75, 157, 379, 451]),
+    (10, 98, &[1, 1, 3, 7, 23, 61, 51, 111, 29, 763]),
+    (10, 107, &[1, 1, 7, 1, 25, 63, 85, 21, 215, 939]),
+    (10, 115, &[1, 3, 5, 3, 21, 49, 93, 19, 447, 427]),
+    (10, 121, &[1, 1, 5, 15, 27, 23, 45, 155, 59, 419]),
+    (10, 127, &[1, 3, 5, 3, 25, 23, 69, 81, 473, 953]),
+    (10, 134, &[1, 3, 3, 9, 21, 7, 113, 53, 319, 27]),
+    (10, 140, &[1, 3, 1, 1, 9, 55, 41, 1, 475, 415]),
+    (10, 145, &[1, 1, 1, 13, 29, 7, 57, 231, 323, 227]),
+    (10, 152, &[1, 3, 1, 11, 23, 41, 15, 89, 163, 329]),
+    (10, 158, &[1, 3, 1, 13, 3, 63, 85, 59, 101, 335]),
+    (10, 161, &[1, 1, 1, 5, 23, 37, 111, 107, 443, 43]),
+    (10, 171, &[1, 1, 1, 1, 1, 13, 15, 63, 325, 579]),
+    (10, 181, &[1, 3, 3, 7, 27, 11, 65, 71, 461, 453]),
+    (10, 194, &[1, 1, 5, 5, 11, 47, 17, 159, 57, 505]),
+    (10, 199, &[1, 1, 5, 1, 15, 43, 35, 101, 461, 105]),
+    (10, 203, &[1, 1, 5, 1, 11, 19, 53, 23, 61, 551]),
+    (10, 208, &[1, 1, 3, 9, 21, 33, 115, 235, 333, 229]),
+    (10, 227, &[1, 1, 5, 13, 17, 3, 73, 67, 273, 201]),
+    (10, 242, &[1, 3, 1, 7, 9, 1, 9, 93, 305, 433]),
+    (10, 251, &[1, 1, 7, 5, 23, 35, 65, 233, 33, 709]),
+    (10, 253, &[1, 1, 3, 15, 17, 47, 9, 29, 149, 119]),
+    (10, 265, &[1, 3, 7, 15, 23, 13, 67, 223, 483, 639]),
+    (10, 266, &[1, 1, 1, 13, 23, 19, 69, 97, 25, 43]),
+    (10, 274, &[1, 1, 1, 7, 31, 37, 81, 179, 317, 581]),
+    (10, 283, &[1, 1, 5, 11, 5, 3, 57, 123, 37, 805]),
+    (10, 289, &[1, 1, 7, 7, 3, 1, 103, 135, 349, 303]),
+    (10, 295, &[1, 1, 3, 11, 13, 41, 47, 165, 69, 65]),
+    (10, 301, &[1, 1, 3, 1, 5, 21, 43, 195, 197, 161]),
+    (10, 316, &[1, 3, 5, 1, 15, 37, 79, 241, 191, 443]),
+    (10, 319, &[1, 1, 5, 15, 19, 51, 111, 127, 505, 381]),
+    (10, 324, &[1, 3, 5, 5, 11, 53, 63, 59, 387, 527]),
+    (10, 346, &[1, 1, 7, 13, 7, 43, 55, 81, 139, 1015]),
+    (10, 352, &[1, 1, 3, 9, 9, 17, 83, 31, 229, 573]),
+    (10, 361, &[1, 3, 3, 5, 11, 43, 67, 55, 277, 613]),
+    (10, 367, &[1, 3, 7, 9, 15, 23, 121, 255, 421, 685]),
+    (10, 382, &[1, 1, 3, 11, 19, 41, 95, 55, 225, 861]),
+    (10, 395, &[1, 1, 7, 9, 21, 59, 59, 219, 7, 513]),
+    (10, 398, &[1, 3, 7, 3, 23, 17, 7, 143, 169, 475]),
+    (10, 400, &[1, 3, 5, 9, 31, 15, 93, 41, 321, 331]),
+    (10, 412, &[1, 3, 5, 9, 7, 37, 27, 133, 199, 113]),
+    (10, 419, &[1, 1, 7, 7, 23, 25, 79, 87, 401, 573]),
+    (10, 422, &[1, 1, 5, 5, 31, 37, 27, 7, 253, 803]),
+    (10, 426, &[1, 1, 7, 5, 15, 23, 115, 115, 459, 119]),
+    (10, 428, &[1, 3, 1, 9, 15, 31, 7, 71, 369, 683]),
+    (10, 433, &[1, 3, 5, 11, 3, 43, 119, 81, 277, 931]),
+    (10, 446, &[1, 1, 5, 5, 3, 55, 23, 109, 177, 899]),
+    (10, 454, &[1, 1, 7, 11, 13, 53, 119, 191, 243, 1019]),
+    (10, 457, &[1, 1, 7, 9, 9, 25, 87, 45, 17, 217]),
+    (10, 472, &[1, 3, 7, 15, 1, 51, 73, 185, 187, 711]),
+    (10, 493, &[1, 3, 1, 13, 13, 53, 33, 61, 423, 633]),
+    (10, 505, &[1, 1, 7, 7, 7, 35, 37, 157, 419, 307]),
+    (10, 508, &[1, 1, 1, 5, 23, 13, 85, 253, 229, 615]),
+    (11, 2, &[1, 1, 7, 3, 23, 13, 89, 137, 13, 61, 1749]),
+    (11, 11, &[1, 1, 3, 7, 7, 63, 9, 115, 175, 727, 1755]),
+    (11, 21, &[1, 3, 1, 7, 21, 13, 119, 253, 203, 985, 989]),
+    (11, 22, &[1, 1, 3, 3, 25, 63, 39, 179, 51, 687, 969]),
+    (11, 35, &[1, 3, 3, 3, 15, 57, 11, 141, 221, 483, 1265]),
+    (11, 49, &[1, 1, 7, 15, 13, 63, 45, 239, 491, 845, 1387]),
+    (11, 50, &[1, 1, 7, 11, 5, 41, 111, 103, 443, 479, 1345]),
+    (11, 56, &[1, 3, 1, 15, 21, 9, 7, 7, 155, 47, 633]),
+    (11, 61, &[1, 3, 5, 1, 9, 25, 15, 143, 277, 325, 233]),
+    (11, 70, &[1, 1, 3, 1, 19, 1, 103, 31, 215, 3, 589]),
+    (11, 74, &[1, 3, 5, 11, 23, 37, 89, 203, 135, 701, 363]),
+    (11, 79, &[1, 1, 1, 9, 1, 17, 33, 63, 205, 33, 57]),
+    (11, 84, &[1, 3, 3, 11, 3, 59, 119, 47, 453, 861, 1575]),
+    (11, 88, &[1, 3, 1, 9, 25, 43, 67, 3, 325, 565, 559]),
+    (11, 103, &[1, 1, 1, 11, 9, 33, 127, 25, 197, 343, 399]),
+    (11, 104, &[1, 3, 1, 7, 11, 57, 51, 243, 419, 753, 1023]),
+    (11, 112, &[1, 1, 3, 15, 21, 15, 103, 233, 319, 965, 605]),
+    (11, 115, &[1, 1, 3, 7, 13, 41, 77, 175, 313, 857, 1483]),
+    (11, 117, &[1, 3, 3, 3, 19, 57, 21, 61, 233, 757, 1475]),
+    (11, 122, &[1, 3, 3, 11, 13, 1, 89, 201, 481, 639, 371]),
+    (11, 134, &[1, 3, 3, 9, 5, 27, 53, 197, 45, 515, 847]),
+    (11, 137, &[1, 1, 7, 15, 21, 1, 13, 217, 373, 755, 515]),
+    (11, 146, &[1, 3, 5, 15, 13, 15, 39, 31, 481, 379, 1381]),
+    (11, 148, &[1, 1, 5, 15, 17, 43, 61, 93, 489, 313, 405]),
+    (11, 157, &[1, 1, 1, 3, 31, 15, 37, 1, 65, 951, 873]),
+    (11, 158, &[1, 3, 7, 7, 23, 49, 37, 237, 117, 369, 1365]),
+    (11, 162, &[1, 3, 7, 13, 11, 13, 109, 103, 97, 37, 199]),
+    (11, 164, &[1, 3, 7, 9, 21, 23, 77, 189, 141, 375, 1767]),
+    (11, 168, &[1, 1, 3, 5, 29, 19, 109, 145, 511, 613, 589]),
+    (11, 173, &[1, 1, 3, 7, 7, 19, 97, 231, 3, 551, 1091]),
+    (11, 185, &[1, 3, 5, 1, 21, 25, 79, 245, 47, 173, 283]),
+    (11, 186, &[1, 3, 5, 3, 27, 27, 59, 135, 499, 605, 1217]),
+    (11, 191, &[1, 3, 3, 1, 19, 39, 79, 17, 159, 711, 1977]),
+    (11, 193, &[1, 3, 3, 3, 25, 25, 51, 223, 289, 619, 55]),
+    (11, 199, &[1, 3, 1, 13, 19, 41, 13, 235, 171, 575, 629]),
+    (11, 213, &[1, 1, 7, 3, 9, 29, 63, 129, 41, 343, 1923]),
+    (11, 214, &[1, 1, 3, 1, 9, 55, 13, 161, 439, 257, 497]),
+    (11, 220, &[1, 3, 5, 5, 21, 55, 41, 111, 311, 757, 99]),
+    (11, 227, &[1, 3, 3, 1, 9, 41, 107, 35, 213, 495, 1687]),
+    (11, 236, &[1, 1, 5, 5, 31, 55, 21, 187, 145, 233, 9]),
+    (11, 242, &[1, 1, 5, 15, 27, 7, 79, 227, 283, 107, 239]),
+    (11, 251, &[1, 3, 5, 5, 7, 7, 25, 75, 195, 671, 1155]),
+    (11, 256, &[1, 1, 5, 1, 19, 25, 47, 91, 275, 169, 995]),
+    (11, 259, &[1, 1, 3, 5, 9, 63, 107, 93, 187, 5, 245]),
+    (11, 265, &[1, 1, 5, 15, 5, 27, 21, 213, 413, 257, 721]),
+    (11, 266, &[1, 1, 1, 11, 1, 7, 77, 67, 79, 49, 559]),
+    (11, 276, &[1, 3, 1, 7, 7, 11, 101, 13, 389, 575, 733]),
+    (11, 292, &[1, 3, 5, 7, 21, 27, 33, 71, 353, 885, 1199]),
+    (11, 304, &[1, 3, 5, 1, 13, 49, 91, 249, 435, 1015, 1249]),
+    (11, 310, &[1, 1, 1, 15, 1, 35, 17, 101, 63, 625, 161]),
+    (11, 316, &[1, 1, 7, 9, 5, 9, 107, 235, 193, 183, 1743]),
+    (11, 319, &[1, 1, 5, 15, 23, 61, 75, 239, 13, 877, 1425]),
+    (11, 322, &[1, 1, 3, 15, 11, 23, 23, 235, 121, 449, 1889]),
+    (11, 328, &[1, 3, 5, 7, 17, 33, 123, 227, 217, 375, 601]),
+    (11, 334, &[1, 3, 7, 1, 15, 51, 25, 201, 145, 919, 139]),
+    (11, 339, &[1, 3, 3, 5, 13, 25, 3, 23, 117, 665, 871]),
+    (11, 341, &[1, 3, 3, 9, 11, 31, 103, 7, 485, 473, 905]),
+    (11, 345, &[1, 3, 7, 11, 19, 35, 5, 71, 355, 983, 15]),
+    (11, 346, &[1, 1, 3, 11, 25, 39, 55, 95, 407, 15, 691]),
+    (11, 362, &[1, 3, 3, 5, 25, 11, 61, 61, 365, 439, 1579]),
+    (11, 367, &[1, 3, 1, 11, 23, 11, 17, 249, 115, 597, 681]),
+    (11, 372, &[1, 1, 3, 5, 29, 63, 111, 43, 21, 991, 945]),
+    (11, 375, &[1, 3, 7, 15, 23, 23, 65, 107, 73, 725, 1315]),
+    (11, 376, &[1, 1, 3, 15, 15, 35, 121, 183, 231, 577, 701]),
+    (11, 381, &[1, 1, 7, 1, 11, 29, 41, 45, 451, 845, 1365]),
+    (11, 385, &[1, 3, 3, 11, 9, 11, 95, 9, 153, 393, 1939]),
+    (11, 388, &[1, 3, 5, 15, 13, 23, 7, 219, 331, 817, 143]),
+    (11, 392, &[1, 3, 3, 1, 21, 55, 83, 149, 105, 751, 685]),
+    (11, 409, &[1, 1, 1, 1, 15, 49, 45, 75, 459, 829, 1455]),
+    (11, 415, &[1, 1, 1, 15, 19, 25, 17, 203, 35, 349, 291]),
+    (11, 416, &[1, 3, 7, 5, 23, 39, 75, 85, 441, 957, 1669]),
+    (11, 421, &[1, 3, 5, 7, 9, 17, 29, 31, 497, 519, 951]),
+    (11, 428, &[1, 3, 3, 5, 27, 37, 107, 75, 493, 205, 1881]),
+    (11, 431, &[1, 1, 5, 7, 1, 41, 79, 59, 103, 77, 1351]),
+    (11, 434, &[1, 1, 7, 7, 25, 35, 125, 199, 417, 391, 131]),
+    (11, 439, &[1, 1, 7, 3, 31, 59, 119, 149, 21, 277, 1405]),
+    (11, 446, &[1, 3, 3, 13, 29, 39, 69, 75, 105, 285, 261]),
+    (11, 451, &[1, 3, 3, 13, 31, 11, 111, 159, 409, 281, 1563]),
+    (11, 453, &[1, 3, 5, 9, 29, 53, 19, 143, 235, 387, 675]),
+    (11, 457, &[1, 3, 3, 13, 21, 3, 53, 35, 391, 213, 1779]),
+    (11, 458, &[1, 3, 1, 13, 25, 1, 115, 85, 7, 395, 381]),
+    (11, 471, &[1, 1, 5, 9, 7, 41, 27, 19, 427, 999, 377]),
+    (11, 475, &[1, 3, 1, 3, 29, 47, 31, 187, 49, 267, 1237]),
+    (11, 478, &[1, 1, 1, 11, 19, 47, 37, 255, 271, 119, 39]),
+    (11, 484, &[1, 3, 3, 3, 5, 1, 19, 209, 305, 219, 155]),
+    (11, 493, &[1, 1, 1, 3, 3, 31, 69, 9, 403, 571, 265]),
+    (11, 494, &[1, 3, 1, 1, 19, 19, 111, 45, 17, 255, 493]),
+    (11, 499, &[1, 3, 1, 11, 5, 49, 43, 241, 127, 589, 1029]),
+    (11, 502, &[1, 1, 5, 11, 19, 21, 45, 5, 477, 509, 245]),
+    (11, 517, &[1, 3, 7, 15, 29, 21, 41, 11, 339, 51, 383]),
+    (11, 518, &[1, 3, 7, 15, 17, 55, 103, 39, 385, 693, 693]),
+    (11, 524, &[1, 3, 1, 9, 31, 55, 89, 157, 395, 73, 967]),
+    (11, 527, &[1, 3, 3, 3, 17, 31, 105, 57, 473, 903, 569]),
+    (11, 555, &[1, 3, 3, 3, 29, 57, 11, 105, 463, 663, 1031]),
+    (11, 560, &[1, 3, 3, 3, 1, 43, 9, 9, 307, 647, 209]),
+    (11, 565, &[1, 3, 3, 15, 1, 5, 21, 193, 325, 713, 257]),
+    (11, 569, &[1, 1, 5, 1, 9, 55, 85, 71, 471, 21, 465]),
+    (11, 578, &[1, 3, 7, 9, 3, 47, 85, 195, 511, 361, 433]),
+    (11, 580, &[1, 3, 3, 13, 7, 57, 89, 149, 433, 213, 1621]),
+    (11, 587, &[1, 1, 5, 7, 23, 7, 23, 181, 67, 745, 563]),
+    (11, 589, &[1, 3, 1, 11, 3, 17, 1, 51, 211, 117, 773]),
+    (11, 590, &[1, 3, 5, 13, 21, 39, 57, 33, 117, 177, 1581]),
+    (11, 601, &[1, 3, 5, 3, 1, 25, 69, 103, 3, 799, 1905]),
+    (11, 607, &[1, 1, 1, 5, 25, 3, 25, 205, 383, 909, 1627]),
+    (11, 611, &[1, 1, 3, 13, 21, 1, 37, 13, 433, 621, 193]),
+    (11, 614, &[1, 1, 5, 5, 31, 21, 41, 173, 299, 657, 1167]),
+    (11, 617, &[1, 1, 3, 13, 9, 53, 3, 249, 197, 903, 501]),
+    (11, 618, &[1, 3, 1, 13, 21, 29, 93, 143, 115, 473, 1479]),
+    (11, 625, &[1, 3, 5, 5, 15, 55, 37, 231, 331, 559, 1311]),
+    (11, 628, &[1, 1, 5, 1, 23, 41, 49, 55, 325, 495, 273]),
+    (11, 635, &[1, 1, 5, 9, 19, 33, 49, 181, 493, 79, 1695]),
+    (11, 641, &[1, 1, 1, 9, 9, 25, 73, 181, 391, 943, 1825]),
+    (11, 647, &[1, 1, 1, 1, 25, 63, 63, 197, 449, 761, 1479]),
+    (11, 654, &[1, 1, 5, 9, 21, 39, 79, 45, 193, 239, 1247]),
+    (11, 659, &[1, 1, 3, 3, 31, 61, 63, 15, 351, 833, 759]),
+    (11, 662, &[1, 3, 7, 5, 29, 21, 83, 75, 449, 115, 911]),
+    (11, 672, &[1, 1, 5, 11, 9, 1, 63, 11, 285, 385, 2035]),
+    (11, 675, &[1, 3, 7, 9, 5, 49, 33, 35, 191, 91, 1441]),
+    (11, 682, &[1, 3, 7, 7, 5, 51, 21, 39, 297, 507, 2013]),
+    (11, 684, &[1, 1, 7, 15, 11, 25, 33, 221, 81, 185, 965]),
+    (11, 689, &[1, 1, 5, 3, 13, 61, 65, 167, 349, 855, 1831]),
+    (11, 695, &[1, 3, 3, 7, 29, 47, 87, 133, 345, 305, 209]),
+    (11, 696, &[1, 1, 7, 3, 13, 1, 37, 49, 3, 331, 1065]),
+    (11, 713, &[1, 1, 3, 9, 31, 9, 95, 163, 475, 477, 299]),
+    (11, 719, &[1, 3, 1, 5, 27, 31, 75, 247, 291, 747, 1897]),
+    (11, 724, &[1, 1, 5, 13, 23, 21, 107, 131, 249, 765, 1307]),
+    (11, 733, &[1, 1, 3, 5, 3, 45, 59, 59, 23, 189, 1109]),
+    (11, 734, &[1, 1, 1, 5, 13, 25, 39, 47, 125, 573, 403]),
+    (11, 740, &[1, 1, 7, 9, 23, 3, 117, 27, 189, 123, 959]),
+    (11, 747, &[1, 1, 3, 7, 7, 23, 3, 205, 413, 299, 1219]),
+    (11, 749, &[1, 3, 5, 9, 19, 5, 101, 183, 439, 313, 1787]),
+    (11, 752, &[1, 3, 5, 13, 29, 13, 39, 127, 203, 449, 1127]),
+    (11, 755, &[1, 3, 5, 9, 27, 11, 25, 219, 323, 803, 1369]),
+    (11, 762, &[1, 3, 1, 5, 13, 23, 111, 199, 343, 975, 665]),
+    (11, 770, &[1, 3, 5, 11, 15, 7, 65, 201, 107, 965, 1643]),
+    (11, 782, &[1, 3, 7, 5, 17, 13, 19, 33, 101, 349, 1887]),
+    (11, 784, &[1, 1, 1, 13, 1, 25, 59, 163, 399, 163, 583]),
+    (11, 787, &[1, 1, 1, 9, 17, 57, 103, 131, 159, 827, 1367]),
+    (11, 789, &[1, 3, 5, 7, 1, 59, 119, 37, 471, 199, 161]),
+    (11, 793, &[1, 1, 7, 1, 3, 59, 117, 177, 241, 879, 151]),
+    (11, 796, &[1, 1, 3, 3, 17, 37, 101, 169, 387, 289, 929]),
+    (11, 803, &[1, 3, 7, 5, 15, 45, 65, 7, 199, 267, 107]),
+    (11, 805, &[1, 3, 1, 11, 29, 45, 99, 213, 195, 57, 561]),
+    (11, 810, &[1, 3, 7, 1, 9, 15, 71, 251, 401, 545, 273]),
+    (11, 815, &[1, 1, 5, 7, 7, 5, 69, 123, 187, 41, 195]),
+    (11, 824, &[1, 1, 1, 3, 15, 9, 121, 63, 81, 435, 429]),
+    (11, 829, &[1, 1, 1, 9, 21, 35, 53, 161, 61, 665, 1319]),
+    (11, 830, &[1, 3, 5, 13, 5, 51, 39, 133, 309, 541, 523]),
+    (11, 832, &[1, 1, 7, 1, 23, 31, 107, 245, 417, 861, 1597]),
+    (11, 841, &[1, 3, 1, 3, 27, 7, 13, 17, 233, 147, 1813]),
+    (11, 847, &[1, 3, 5, 5, 13, 11, 5, 121, 497, 457, 1179]),
+    (11, 849, &[1, 1, 1, 11, 27, 27, 45, 253, 107, 679, 779]),
+    (11, 861, &[1, 3, 3, 15, 31, 37, 19, 165, 437, 737, 359]),
+    (11, 871, &[1, 1, 3, 9, 15, 41, 63, 185, 511, 785, 815]),
+    (11, 878, &[1, 1, 1, 13, 7, 63, 77, 231, 425, 871, 705]),
+    (11, 889, &[1, 3, 5, 15, 5, 57, 33, 183, 143, 803, 143]),
+    (11, 892, &[1, 3, 3, 3, 13, 47, 37, 247, 27, 577, 171]),
+    (11, 901, &[1, 3, 1, 13, 5, 39, 1, 163, 399, 505, 1671]),
+    (11, 908, &[1, 1, 3, 15, 21, 21, 99, 205, 119, 735, 2023]),
+    (11, 920, &[1, 1, 3, 13, 15, 27, 89, 125, 411, 527, 1963]),
+    (11, 923, &[1, 1, 5, 13, 9, 5, 9, 157, 317, 603, 1643]),
+    (11, 942, &[1, 3, 1, 11, 27, 17, 13, 59, 165, 873, 989]),
+    (11, 949, &[1, 1, 7, 1, 23, 23, 69, 111, 23, 555, 1521]),
+    (11, 950, &[1, 3, 1, 15, 1, 1, 61, 135, 239, 331, 759]),
+    (11, 954, &[1, 1, 3, 1, 21, 35, 39, 75, 405, 507, 1647]),
+    (11, 961, &[1, 3, 3, 11, 7, 7, 95, 119, 451, 679, 1159]),
+    (11, 968, &[1, 3, 7, 9, 23, 51, 33, 215, 375, 241, 553]),
+    (11, 971, &[1, 3, 1, 3, 11, 41, 73, 125, 89, 685, 881]),
+    (11, 973, &[1, 1, 7, 13, 29, 29, 33, 127, 83, 5, 1009]),
+    (11, 979, &[1, 3, 1, 11, 19, 27, 13, 25, 479, 801, 77]),
+    (11, 982, &[1, 3, 1, 7, 9, 35, 31, 125, 1, 625, 1983]),
+    (11, 986, &[1, 1, 3, 5, 9, 51, 69, 169, 41, 495, 1995]),
+    (11, 998, &[1, 3, 5, 15, 13, 61, 115, 119, 25, 133, 257]),
+    (11, 1001, &[1, 1, 1, 3, 21, 51, 73, 23, 471, 763, 1147]),
+    (11, 1010, &[1, 3, 7, 5, 31, 35, 55, 143, 469, 595, 1109]),
+    (11, 1012, &[1, 1, 7, 13, 29, 39, 81, 253, 339, 361, 949]),
+];
@@ -2,13 +2,13 @@
 
 use core::{mem::MaybeUninit, slice};
 
-use super::Rng;
+use super::RandomSource;
 
 /// Generate a random value using the given source of randomness.
 ///
 /// See [`Random`] trait for more information about the distribution of the generated values.
 #[inline]
-pub fn random<T: Random>(rng: &mut Rng) -> T {
+pub fn random<T: Random>(rng: &mut (impl RandomSource + ?Sized)) -> T {
     Random::random(rng)
 }
 
@@ -16,7 +16,7 @@ pub fn random<T: Random>(rng: &mut Rng) -> T {
 ///
 /// See [`Random`] trait for more information about the distribution of the generated values.
 #[inline]
-pub fn fill_random<T: Random>(rng: &mut Rng, data: &mut [T]) {
+pub fn fill_random<T: Random>(rng: &mut (impl RandomSource + ?Sized), data: &mut [T]) {
     Random::fill_random(rng, data);
 }
 
@@ -28,13 +28,17 @@ pub fn fill_random<T: Random>(rng: &mut Rng, data: &mut [T]) {
 /// * Floating-point numbers are uniformly distributed over the half-open interval `[0, 1)`.
 /// * Tuple types are generated by recursively generating each field.
 /// * Arrays are generated by recursively generating each element.
+/// * Vectors, points and complex numbers have each component uniformly distributed over
+///   the half-open interval `[0, 1)`, angles have their measure in radians in `[0, 1)`.
+///   See [`UniformAngle`](super::UniformAngle) and the other geometric distributions for
+///   more meaningful sampling.
 pub trait Random: Sized {
     /// Generate a random value.
-    fn random(rng: &mut Rng) -> Self;
+    fn random<R: RandomSource + ?Sized>(rng: &mut R) -> Self;
 
     /// Fill the slice with random values.
     #[inline]
-    fn fill_random(rng: &mut Rng, data: &mut [Self]) {
+    fn fill_random<R: RandomSource + ?Sized>(rng: &mut R, data: &mut [Self]) {
         for x in data.iter_mut() {
             *x = Self::random(rng);
         }
@@ -43,7 +47,7 @@ pub trait Random: Sized {
 
 impl Random for bool {
     #[inline]
-    fn random(rng: &mut Rng) -> Self {
+    fn random<R: RandomSource + ?Sized>(rng: &mut R) -> Self {
         i64::random(rng) > 0
     }
 }
@@ -53,13 +57,13 @@ macro_rules! impl_random_integers {
         $(
             impl Random for $ty {
                 #[inline]
-                fn random(rng: &mut Rng) -> Self {
+                fn random<R: RandomSource + ?Sized>(rng: &mut R) -> Self {
                     const OFFSET: usize = 8 * (size_of::<u64>() - size_of::<$ty>());
                     (rng.next_u64() >> OFFSET) as $ty
                 }
 
                 #[inline]
-                fn fill_random(rng: &mut Rng, data: &mut [Self]) {
+                fn fill_random<R: RandomSource + ?Sized>(rng: &mut R, data: &mut [Self]) {
                     rng.fill_bytes(as_byte_slice(data));
                 }
             }
@@ -72,14 +76,14 @@ macro_rules! imlp_random_large_integers {
         $(
             impl Random for $ty {
                 #[inline]
-                fn random(rng: &mut Rng) -> Self {
+                fn random<R: RandomSource + ?Sized>(rng: &mut R) -> Self {
                     let lo = u64::random(rng) as $ty;
                     let hi = u64::random(rng) as $ty;
                     (hi << 64) | lo
                 }
 
                 #[inline]
-                fn fill_random(rng: &mut Rng, data: &mut [Self]) {
+                fn fill_random<R: RandomSource + ?Sized>(rng: &mut R, data: &mut [Self]) {
                     rng.fill_bytes(as_byte_slice(data));
                 }
             }
@@ -98,7 +102,7 @@ macro_rules! impl_random_float {
     ($ty:ty, $offset:expr) => {
         impl Random for $ty {
             #[inline]
-            fn random(rng: &mut Rng) -> Self {
+            fn random<R: RandomSource + ?Sized>(rng: &mut R) -> Self {
                 const SCALE: $ty = 1.0 / ((1u64 << (64 - $offset)) as $ty);
                 let unsigned = rng.next_u64() >> $offset;
                 (unsigned as $ty) * SCALE
@@ -118,7 +122,7 @@ macro_rules! impl_random_tuple {
     ([$($xi:ident)*] [$y0:ident $($yi:ident)*]) => {
         impl<$($xi: Random,)* $y0: Random> Random for ($($xi,)* $y0,) {
             #[inline]
-            fn random(rng: &mut Rng) -> Self {
+            fn random<R: RandomSource + ?Sized>(rng: &mut R) -> Self {
                 ($($xi::random(rng),)* $y0::random(rng), )
             }
         }
@@ -133,7 +137,7 @@ impl_random_tuple!(A B C D E F G H I J K L);
 
 impl<T: Random, const N: usize> Random for [T; N] {
     #[inline]
-    fn random(rng: &mut Rng) -> Self {
+    fn random<R: RandomSource + ?Sized>(rng: &mut R) -> Self {
         let mut data: [MaybeUninit<T>; N] = unsafe { MaybeUninit::uninit().assume_init() };
 
         for x in data.iter_mut() {
@@ -147,6 +151,7 @@ impl<T: Random, const N: usize> Random for [T; N] {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::rand::Rng;
 
     #[test]
     fn chi_squred_test_for_bool() {
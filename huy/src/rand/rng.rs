@@ -1,3 +1,50 @@
+use core::iter::FusedIterator;
+
+/// A source of random bits, the common interface of the random number generators.
+pub trait RandomSource {
+    /// Return the next random `u64`.
+    fn next_u64(&mut self) -> u64;
+
+    /// Return the next random `u32`.
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Fill a slice of bytes with random data.
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let mut chunks = dest.chunks_exact_mut(size_of::<u64>());
+        for chunk in chunks.by_ref() {
+            chunk.copy_from_slice(&self.next_u64().to_le_bytes());
+        }
+        let remainder = chunks.into_remainder();
+        if !remainder.is_empty() {
+            let bytes = self.next_u64().to_le_bytes();
+            remainder.copy_from_slice(&bytes[..remainder.len()]);
+        }
+    }
+}
+
+impl<R: RandomSource + ?Sized> RandomSource for &mut R {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (**self).next_u32()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        (**self).fill_bytes(dest);
+    }
+}
+
+/// The xoshiro256++ random number generator, see [`Rng`].
+pub type Xoshiro256PlusPlus = Rng;
+
 /// Random number generator.
 ///
 /// The random number generator is implemented using the [xoshiro256++ algorithm],
@@ -12,6 +59,24 @@ pub struct Rng {
 }
 
 impl Rng {
+    /// Create a new PRNG seeded by the operating system.
+    ///
+    /// The whole state is filled using [`getrandom`], if the `getrandom` feature is
+    /// disabled or the operating system fails to provide the entropy it falls back to
+    /// [`from_random_state`](Rng::from_random_state).
+    ///
+    /// [`getrandom`]: https://docs.rs/getrandom
+    pub fn from_entropy() -> Self {
+        #[cfg(feature = "getrandom")]
+        {
+            let mut seed = [0; 32];
+            if getrandom::fill(&mut seed).is_ok() {
+                return Self::from_seed(seed);
+            }
+        }
+        Self::from_random_state()
+    }
+
     /// Create a new PRNG from [`RandomState`].
     ///
     /// [`RandomState`]: std::hash::RandomState
@@ -49,7 +114,7 @@ impl Rng {
 
     /// Create a new PRNG using a `u64` seed.
     pub fn seed_from_u64(state: u64) -> Self {
-        let mut splitmix = SplitMix64 { state };
+        let mut splitmix = SplitMix64::seed_from_u64(state);
         let state = [
             splitmix.next_u64(),
             splitmix.next_u64(),
@@ -60,8 +125,79 @@ impl Rng {
     }
 
     /// Return the next random `u64`.
+    ///
+    /// It is the same as [`RandomSource::next_u64`], available without importing the
+    /// trait.
     #[inline]
     pub fn next_u64(&mut self) -> u64 {
+        RandomSource::next_u64(self)
+    }
+
+    /// Fill a slice of bytes with random data.
+    ///
+    /// It is the same as [`RandomSource::fill_bytes`], available without importing the
+    /// trait.
+    #[inline]
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        RandomSource::fill_bytes(self, dest);
+    }
+
+    /// Serialize the state of the PRNG.
+    ///
+    /// The first byte is the version of the format, currently `1`, followed by the four
+    /// words of the state in little-endian order.
+    pub fn to_bytes(&self) -> [u8; 33] {
+        let mut bytes = [0; 33];
+        bytes[0] = STATE_FORMAT_VERSION;
+        for (chunk, word) in bytes[1..].chunks_exact_mut(8).zip(self.state) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Restore a PRNG from the bytes returned by [`to_bytes`](Rng::to_bytes).
+    ///
+    /// Returns `None` if the length or the version is not valid, or if the state is zero.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (&version, words) = bytes.split_first()?;
+        if version != STATE_FORMAT_VERSION || words.len() != 32 {
+            return None;
+        }
+
+        let mut state = [0; 4];
+        for (word, chunk) in state.iter_mut().zip(words.chunks_exact(8)) {
+            *word = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        state.iter().any(|&x| x != 0).then_some(Self { state })
+    }
+}
+
+/// The version of the format used by [`Rng::to_bytes`].
+const STATE_FORMAT_VERSION: u8 = 1;
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Rng {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.state.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Rng {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let state = <[u64; 4]>::deserialize(deserializer)?;
+        if state.iter().all(|&x| x == 0) {
+            return Err(serde::de::Error::custom(
+                "invalid state: all words are zero",
+            ));
+        }
+        Ok(Self { state })
+    }
+}
+
+impl RandomSource for Rng {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
         let result = self.state[0]
             .wrapping_add(self.state[3])
             .rotate_left(23)
@@ -80,73 +216,183 @@ impl Rng {
 
         result
     }
+}
 
-    /// Fill a slice of bytes with random data.
-    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
-        let mut chunks = dest.chunks_exact_mut(std::mem::size_of::<u64>());
-        for chunk in chunks.by_ref() {
-            chunk.copy_from_slice(&self.next_u64().to_le_bytes());
+impl Rng {
+    /// Jump forward, it is equivalent to 2^128 calls to `next_u64`.
+    pub fn jump(&mut self) {
+        self.jump_polynomial([
+            0x180ec6d33cfd0aba,
+            0xd5a61266f0c9392c,
+            0xa9582618e03fc9aa,
+            0x39abdc4529b1661c,
+        ]);
+    }
+
+    /// Jump forward, it is equivalent to 2^192 calls to `next_u64`.
+    pub fn long_jump(&mut self) {
+        self.jump_polynomial([
+            0x76e15d3efefdcbbf,
+            0xc5004e441c522fb3,
+            0x77710069854ee241,
+            0x39109bb02acbe635,
+        ]);
+    }
+
+    /// Jump forward, it is equivalent to `n` calls to `next_u64`.
+    ///
+    /// The jump polynomial `x^n mod p(x)`, where `p` is the characteristic polynomial
+    /// of the linear engine, is computed in `O(log n)` steps.
+    pub fn advance(&mut self, n: u128) {
+        if n < 256 {
+            for _ in 0..n {
+                self.next_u64();
+            }
+            return;
         }
-        let remainder = chunks.into_remainder();
-        if !remainder.is_empty() {
-            let bytes = self.next_u64().to_le_bytes();
-            remainder.copy_from_slice(&bytes[..remainder.len()]);
+
+        let mut polynomial = [1, 0, 0, 0];
+        for bit in (0..128 - n.leading_zeros()).rev() {
+            polynomial = polynomial_mul_mod(polynomial, polynomial);
+            if (n >> bit) & 1 != 0 {
+                polynomial = polynomial_mul_x_mod(polynomial);
+            }
         }
+        self.jump_polynomial(polynomial);
     }
-}
 
-/// Xoshiro256++ jump implementation.
-macro_rules! jump_impl {
-    ($self:expr, [$j0:literal, $j1: literal, $j2:literal, $j3:literal]) => {
-        const JUMP: [u64; 4] = [$j0, $j1, $j2, $j3];
+    /// Replace the state with `q(T) state`, where `T` is the transition of the linear
+    /// engine and `q` is the given polynomial of degree less than 256.
+    fn jump_polynomial(&mut self, polynomial: [u64; 4]) {
         let mut state = [0_u64; 4];
-        for jump in JUMP {
+        for jump in polynomial {
             for bit in 0..64 {
                 if (jump & (1_u64 << bit)) != 0 {
-                    state[0] ^= $self.state[0];
-                    state[1] ^= $self.state[1];
-                    state[2] ^= $self.state[2];
-                    state[3] ^= $self.state[3];
+                    state[0] ^= self.state[0];
+                    state[1] ^= self.state[1];
+                    state[2] ^= self.state[2];
+                    state[3] ^= self.state[3];
+                }
+                self.next_u64();
+            }
+        }
+
+        self.state = state;
+    }
+
+    /// Split the generator in two non-overlapping streams.
+    ///
+    /// Returns a copy of the current generator, which can generate 2^128 values before
+    /// overlapping with `self`, then `self` jumps forward.
+    pub fn split(&mut self) -> Rng {
+        let rng = self.clone();
+        self.jump();
+        rng
+    }
+
+    /// Returns an infinite iterator of non-overlapping generators.
+    ///
+    /// The first item is a copy of the current generator, each of the following ones is
+    /// obtained from the previous with a [`jump`](Rng::jump), then each generator can
+    /// generate 2^128 values before overlapping with the next one.
+    #[inline]
+    pub fn substreams(&self) -> Substreams {
+        Substreams { rng: self.clone() }
+    }
+}
+
+/// The characteristic polynomial of the xoshiro256 linear engine without the leading
+/// term `x^256`, the coefficient of `x^i` is the bit `i % 64` of the word `i / 64`.
+const CHARACTERISTIC_POLYNOMIAL: [u64; 4] = [
+    0x9d116f2bb0f0f001,
+    0x0280002bcefd1a5e,
+    0x04b4edcf26259f85,
+    0x0003c03c3f3ecb19,
+];
+
+/// Multiply a polynomial over GF(2) by `x`, modulo the characteristic polynomial.
+#[inline]
+fn polynomial_mul_x_mod(a: [u64; 4]) -> [u64; 4] {
+    let carry = a[3] >> 63;
+    let mut result = [
+        a[0] << 1,
+        (a[1] << 1) | (a[0] >> 63),
+        (a[2] << 1) | (a[1] >> 63),
+        (a[3] << 1) | (a[2] >> 63),
+    ];
+    if carry != 0 {
+        for (x, p) in result.iter_mut().zip(CHARACTERISTIC_POLYNOMIAL) {
+            *x ^= p;
+        }
+    }
+    result
+}
+
+/// Multiply two polynomials over GF(2), modulo the characteristic polynomial.
+fn polynomial_mul_mod(a: [u64; 4], mut b: [u64; 4]) -> [u64; 4] {
+    let mut result = [0; 4];
+    for word in a {
+        for bit in 0..64 {
+            if (word >> bit) & 1 != 0 {
+                for (x, y) in result.iter_mut().zip(b) {
+                    *x ^= y;
                 }
-                $self.next_u64();
             }
+            b = polynomial_mul_x_mod(b);
         }
+    }
+    result
+}
 
-        $self.state = state;
-    };
+/// An infinite iterator of non-overlapping generators, see [`Rng::substreams`].
+#[derive(Debug, Clone)]
+pub struct Substreams {
+    rng: Rng,
 }
 
-impl Rng {
-    /// Jump forward, it is equivalent to 2^128 calls to `next_u64`.
-    pub fn jump(&mut self) {
-        #[rustfmt::skip]
-        jump_impl!(self, [
-            0x180ec6d33cfd0aba, 0xd5a61266f0c9392c,
-            0xa9582618e03fc9aa, 0x39abdc4529b1661c
-        ]);
+impl Iterator for Substreams {
+    type Item = Rng;
+
+    #[inline]
+    fn next(&mut self) -> Option<Rng> {
+        Some(self.rng.split())
     }
 
-    /// Jump forward, it is equivalent to 2^192 calls to `next_u64`.
-    pub fn long_jump(&mut self) {
-        #[rustfmt::skip]
-        jump_impl!(self, [
-            0x76e15d3efefdcbbf, 0xc5004e441c522fb3,
-            0x77710069854ee241, 0x39109bb02acbe635
-        ]);
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
     }
 }
 
+impl FusedIterator for Substreams {}
+
 /// Random number generator implementing SplitMix64 algorithm.
 ///
 /// This implementation is the translation of the [reference implementation] by Sebastiano Vigna.
+/// It has a small state and it is mainly used to initialize the state of other generators.
 ///
 /// [reference implementation]: https://xorshift.di.unimi.it/splitmix64.c
-struct SplitMix64 {
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitMix64 {
     state: u64,
 }
 
 impl SplitMix64 {
-    /// Return the next random `u64`.
+    /// Create a new PRNG using the given seed.
+    #[inline]
+    pub fn from_seed(seed: [u8; 8]) -> Self {
+        Self::seed_from_u64(u64::from_le_bytes(seed))
+    }
+
+    /// Create a new PRNG using a `u64` seed.
+    #[inline]
+    pub fn seed_from_u64(state: u64) -> Self {
+        Self { state }
+    }
+}
+
+impl RandomSource for SplitMix64 {
+    #[inline]
     fn next_u64(&mut self) -> u64 {
         self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
 
@@ -231,7 +477,7 @@ mod tests {
 
     #[test]
     fn splitmix64_reference_implementation() {
-        let mut rng = SplitMix64 { state: 0 };
+        let mut rng = SplitMix64::seed_from_u64(0);
 
         // These values were produced using the reference implementation:
         // https://prng.di.unimi.it/xoshiro256plusplus.c
@@ -254,4 +500,170 @@ mod tests {
 
         assert_ne!(rng1.next_u64(), rng2.next_u64());
     }
+
+    #[test]
+    fn split() {
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        let expected = rng.clone();
+        let mut jumped = rng.clone();
+        jumped.jump();
+
+        let child = rng.split();
+        assert_eq!(child, expected);
+        assert_eq!(rng, jumped);
+    }
+
+    #[test]
+    fn substreams() {
+        let rng = Rng::seed_from_u64(0x5eed);
+        let substreams = rng.substreams().take(8).collect::<Vec<_>>();
+
+        let mut expected = rng.clone();
+        for substream in &substreams {
+            assert_eq!(substream, &expected);
+            expected.jump();
+        }
+
+        // the first values of different substreams do not overlap
+        let mut values = substreams
+            .into_iter()
+            .flat_map(|mut rng| (0..10_000).map(move |_| rng.next_u64()))
+            .collect::<Vec<_>>();
+        values.sort_unstable();
+        values.dedup();
+        assert_eq!(values.len(), 8 * 10_000);
+    }
+
+    #[test]
+    fn advance() {
+        let rng = Rng::seed_from_u64(0x5eed);
+        let mut expected = rng.clone();
+        for n in 0..1000 {
+            let mut advanced = rng.clone();
+            advanced.advance(n);
+            assert_eq!(advanced, expected);
+            expected.next_u64();
+        }
+
+        let mut advanced = rng.clone();
+        advanced.advance(12345);
+        advanced.advance(54321);
+        let mut expected = rng.clone();
+        for _ in 0..12345 + 54321 {
+            expected.next_u64();
+        }
+        assert_eq!(advanced, expected);
+    }
+
+    #[test]
+    fn advance_jump() {
+        let rng = Rng::seed_from_u64(0x5eed);
+
+        // 2^128 - 1 steps followed by one step
+        let mut advanced = rng.clone();
+        advanced.advance(u128::MAX);
+        advanced.advance(1);
+        let mut expected = rng.clone();
+        expected.jump();
+        assert_eq!(advanced, expected);
+
+        let mut advanced = rng.clone();
+        advanced.advance(u128::MAX);
+        advanced.advance(1000);
+        for _ in 0..999 {
+            expected.next_u64();
+        }
+        assert_eq!(advanced, expected);
+    }
+
+    #[test]
+    fn characteristic_polynomial() {
+        // x^(2^128) and x^(2^192) are the polynomials of jump and long_jump
+        let mut polynomial = [2, 0, 0, 0];
+        for _ in 0..128 {
+            polynomial = polynomial_mul_mod(polynomial, polynomial);
+        }
+        assert_eq!(
+            polynomial,
+            [
+                0x180ec6d33cfd0aba,
+                0xd5a61266f0c9392c,
+                0xa9582618e03fc9aa,
+                0x39abdc4529b1661c
+            ]
+        );
+        for _ in 128..192 {
+            polynomial = polynomial_mul_mod(polynomial, polynomial);
+        }
+        assert_eq!(
+            polynomial,
+            [
+                0x76e15d3efefdcbbf,
+                0xc5004e441c522fb3,
+                0x77710069854ee241,
+                0x39109bb02acbe635
+            ]
+        );
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        rng.advance(1000);
+
+        let bytes = rng.to_bytes();
+        assert_eq!(bytes[0], 1);
+        let mut restored = Rng::from_bytes(&bytes).unwrap();
+        assert_eq!(restored, rng);
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), rng.next_u64());
+        }
+
+        let rng = Rng::from_seed(core::array::from_fn(|i| i as u8 + 1));
+        assert_eq!(
+            rng.to_bytes()[1..],
+            core::array::from_fn::<u8, 32, _>(|i| i as u8 + 1)
+        );
+    }
+
+    #[test]
+    fn invalid_bytes() {
+        let bytes = Rng::seed_from_u64(0x5eed).to_bytes();
+        assert!(Rng::from_bytes(&[]).is_none());
+        assert!(Rng::from_bytes(&bytes[..32]).is_none());
+        assert!(Rng::from_bytes(&[bytes.as_slice(), &[0]].concat()).is_none());
+
+        let mut wrong_version = bytes;
+        wrong_version[0] = 2;
+        assert!(Rng::from_bytes(&wrong_version).is_none());
+
+        let mut zero = [0; 33];
+        zero[0] = 1;
+        assert!(Rng::from_bytes(&zero).is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        rng.advance(1000);
+
+        let json = serde_json::to_string(&rng).unwrap();
+        let mut restored: Rng = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, rng);
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), rng.next_u64());
+        }
+
+        assert!(serde_json::from_str::<Rng>("[0,0,0,0]").is_err());
+        assert!(serde_json::from_str::<Rng>("[1,2,3]").is_err());
+    }
+
+    #[test]
+    fn from_entropy() {
+        let mut a = Rng::from_entropy();
+        let mut b = Rng::from_entropy();
+        assert_ne!(a, b);
+        assert_ne!(a.next_u64(), b.next_u64());
+    }
 }
@@ -0,0 +1,140 @@
+//! Hierarchical seeding of random number generators.
+
+use super::{RandomSource, SplitMix64};
+
+/// A hierarchical seed, given by a root entropy and a path of indices.
+///
+/// Each sequence can derive any number of children identified by an index, then the
+/// seed of a task depends only on its position in the hierarchy and not on the order
+/// in which the seeds are generated, for example the results of a parallel computation
+/// do not depend on the number of threads if each work item uses the generator seeded
+/// by `root.child(item)`.
+///
+/// The seeds are derived using the SplitMix64 mixing function, they are well
+/// distributed but not suitable for cryptographic purposes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SeedSequence {
+    entropy: u64,
+    path: Vec<u64>,
+    hash: u64,
+}
+
+impl SeedSequence {
+    /// Create a new root sequence from the given entropy.
+    pub fn new(entropy: u64) -> Self {
+        Self {
+            entropy,
+            path: Vec::new(),
+            hash: mix(entropy ^ 0x243f6a8885a308d3),
+        }
+    }
+
+    /// Returns the entropy of the root sequence.
+    #[inline]
+    pub fn entropy(&self) -> u64 {
+        self.entropy
+    }
+
+    /// Returns the path of indices from the root sequence.
+    #[inline]
+    pub fn path(&self) -> &[u64] {
+        &self.path
+    }
+
+    /// Returns the child sequence with the given index.
+    pub fn child(&self, index: u64) -> Self {
+        let depth = self.path.len() as u64 + 1;
+        let mut path = Vec::with_capacity(self.path.len() + 1);
+        path.extend_from_slice(&self.path);
+        path.push(index);
+        Self {
+            entropy: self.entropy,
+            path,
+            hash: mix(self.hash ^ mix(index.wrapping_add(depth.wrapping_mul(GOLDEN_GAMMA)))),
+        }
+    }
+
+    /// Returns an infinite iterator of the children sequences, starting from index
+    /// zero.
+    #[inline]
+    pub fn children(&self) -> impl Iterator<Item = SeedSequence> + '_ {
+        (0..).map(|index| self.child(index))
+    }
+
+    /// Generate the seed of this sequence, usable with the `from_seed` constructor of
+    /// the random number generators.
+    pub fn generate_seed<const N: usize>(&self) -> [u8; N] {
+        let mut seed = [0; N];
+        SplitMix64::seed_from_u64(self.hash).fill_bytes(&mut seed);
+        seed
+    }
+}
+
+const GOLDEN_GAMMA: u64 = 0x9e3779b97f4a7c15;
+
+/// The finalizer of SplitMix64, a bijective mixing function.
+#[inline]
+fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rand::Rng;
+
+    #[test]
+    fn reproducible() {
+        let root = SeedSequence::new(0x5eed);
+        let child = root.child(3).child(7);
+        assert_eq!(child.entropy(), 0x5eed);
+        assert_eq!(child.path(), [3, 7]);
+
+        let expected = SeedSequence::new(0x5eed).child(3).child(7);
+        assert_eq!(child, expected);
+        assert_eq!(child.generate_seed::<32>(), expected.generate_seed::<32>());
+
+        // the seeds do not depend on the order of generation
+        let forward = root
+            .children()
+            .take(16)
+            .map(|seq| seq.generate_seed::<32>());
+        let backward = (0..16).rev().map(|i| root.child(i).generate_seed::<32>());
+        assert!(forward.eq(backward.collect::<Vec<_>>().into_iter().rev()));
+
+        let mut rng = Rng::from_seed(child.generate_seed());
+        let mut expected = Rng::from_seed(expected.generate_seed());
+        assert_eq!(rng.next_u64(), expected.next_u64());
+    }
+
+    #[test]
+    fn distinct_seeds() {
+        let root = SeedSequence::new(0x5eed);
+        let mut seeds = vec![
+            root.generate_seed::<8>(),
+            root.child(0).child(0).generate_seed(),
+            root.child(1).child(0).generate_seed(),
+            root.child(0).child(1).generate_seed(),
+            SeedSequence::new(0x5eee).generate_seed(),
+            SeedSequence::new(0x5eee).child(0).generate_seed(),
+        ];
+        for seq in root.children().take(1000) {
+            seeds.push(seq.generate_seed());
+            seeds.push(seq.child(2).generate_seed());
+        }
+        let count = seeds.len();
+        seeds.sort_unstable();
+        seeds.dedup();
+        assert_eq!(seeds.len(), count);
+    }
+
+    #[test]
+    fn seed_length() {
+        let seq = SeedSequence::new(42).child(1);
+        let long: [u8; 32] = seq.generate_seed();
+        let short: [u8; 13] = seq.generate_seed();
+        assert_eq!(short, long[..13]);
+    }
+}
@@ -0,0 +1,355 @@
+//! Random operations on slices and iterators.
+
+use super::{Distribution, RandomSource, UniformInt, Weight, WeightedIndex};
+
+/// Extension trait for random operations on slices.
+pub trait SliceRandom {
+    /// The type of the elements of the slice.
+    type Item;
+
+    /// Shuffle the slice in place, all the permutations are equally likely.
+    ///
+    /// The permutation is generated with the Fisher-Yates algorithm.
+    fn shuffle<R: RandomSource + ?Sized>(&mut self, rng: &mut R);
+
+    /// Move `amount` elements chosen at random, in random order, to the beginning of the
+    /// slice. Returns the slice of the chosen elements and the slice of the remaining
+    /// ones, which are in an unspecified order.
+    ///
+    /// If `amount` is greater than the length of the slice, the whole slice is shuffled.
+    fn partial_shuffle<R: RandomSource + ?Sized>(
+        &mut self,
+        rng: &mut R,
+        amount: usize,
+    ) -> (&mut [Self::Item], &mut [Self::Item]);
+
+    /// Returns a reference to an element chosen uniformly at random, or `None` if the
+    /// slice is empty.
+    fn choose<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Option<&Self::Item>;
+
+    /// Returns a mutable reference to an element chosen uniformly at random, or `None` if
+    /// the slice is empty.
+    fn choose_mut<R: RandomSource + ?Sized>(&mut self, rng: &mut R) -> Option<&mut Self::Item>;
+
+    /// Returns `amount` distinct elements chosen uniformly at random, in random order.
+    ///
+    /// If `amount` is greater than the length of the slice, all the elements are
+    /// returned. The indices are sampled with the Floyd's algorithm when `amount` is
+    /// small, otherwise with a partial shuffle.
+    fn choose_multiple<R: RandomSource + ?Sized>(
+        &self,
+        rng: &mut R,
+        amount: usize,
+    ) -> Vec<&Self::Item>;
+
+    /// Returns a reference to an element chosen at random with probability
+    /// proportional to its weight.
+    ///
+    /// Returns `None` if the slice is empty or the weights are not valid, see
+    /// [`WeightedIndex::try_new`].
+    fn choose_weighted<W, F, R: RandomSource + ?Sized>(
+        &self,
+        rng: &mut R,
+        weight: F,
+    ) -> Option<&Self::Item>
+    where
+        W: Weight,
+        F: Fn(&Self::Item) -> W;
+}
+
+impl<T> SliceRandom for [T] {
+    type Item = T;
+
+    fn shuffle<R: RandomSource + ?Sized>(&mut self, rng: &mut R) {
+        for i in (1..self.len()).rev() {
+            self.swap(i, uniform_index(rng, i + 1));
+        }
+    }
+
+    fn partial_shuffle<R: RandomSource + ?Sized>(
+        &mut self,
+        rng: &mut R,
+        amount: usize,
+    ) -> (&mut [T], &mut [T]) {
+        let amount = amount.min(self.len());
+        for i in 0..amount {
+            let j = i + uniform_index(rng, self.len() - i);
+            self.swap(i, j);
+        }
+        self.split_at_mut(amount)
+    }
+
+    #[inline]
+    fn choose<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Option<&T> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(&self[uniform_index(rng, self.len())])
+    }
+
+    #[inline]
+    fn choose_mut<R: RandomSource + ?Sized>(&mut self, rng: &mut R) -> Option<&mut T> {
+        if self.is_empty() {
+            return None;
+        }
+        let index = uniform_index(rng, self.len());
+        Some(&mut self[index])
+    }
+
+    fn choose_multiple<R: RandomSource + ?Sized>(&self, rng: &mut R, amount: usize) -> Vec<&T> {
+        sample_indices(rng, self.len(), amount)
+            .into_iter()
+            .map(|index| &self[index])
+            .collect()
+    }
+
+    fn choose_weighted<W, F, R: RandomSource + ?Sized>(&self, rng: &mut R, weight: F) -> Option<&T>
+    where
+        W: Weight,
+        F: Fn(&T) -> W,
+    {
+        let weighted = WeightedIndex::try_new(self.iter().map(weight))?;
+        Some(&self[weighted.sample(rng)])
+    }
+}
+
+/// Extension trait for random operations on iterators.
+pub trait IteratorRandom: Iterator + Sized {
+    /// Returns an element chosen uniformly at random, or `None` if the iterator is
+    /// empty.
+    ///
+    /// When the iterator reports its exact length only one element is generated,
+    /// otherwise the whole iterator is consumed with the reservoir sampling.
+    fn choose<R: RandomSource + ?Sized>(mut self, rng: &mut R) -> Option<Self::Item> {
+        let (lower, upper) = self.size_hint();
+        if upper == Some(lower) {
+            return match lower {
+                0 => None,
+                len => self.nth(uniform_index(rng, len)),
+            };
+        }
+
+        let mut chosen = None;
+        for (count, item) in self.enumerate() {
+            if uniform_index(rng, count + 1) == 0 {
+                chosen = Some(item);
+            }
+        }
+        chosen
+    }
+
+    /// Returns `amount` distinct elements chosen uniformly at random, the whole iterator
+    /// is consumed with the reservoir sampling.
+    ///
+    /// If the iterator has less than `amount` elements, all of them are returned. The
+    /// order of the elements is not random.
+    fn choose_multiple<R: RandomSource + ?Sized>(
+        self,
+        rng: &mut R,
+        amount: usize,
+    ) -> Vec<Self::Item> {
+        let mut reservoir = Vec::with_capacity(amount);
+        for (count, item) in self.enumerate() {
+            if count < amount {
+                reservoir.push(item);
+            } else {
+                let index = uniform_index(rng, count + 1);
+                if index < amount {
+                    reservoir[index] = item;
+                }
+            }
+        }
+        reservoir
+    }
+}
+
+impl<I: Iterator> IteratorRandom for I {}
+
+/// Generate a random index in the half-open interval `[0, len)`.
+#[inline]
+fn uniform_index<R: RandomSource + ?Sized>(rng: &mut R, len: usize) -> usize {
+    UniformInt::new(0..len).sample(rng)
+}
+
+/// Returns `amount` distinct indices in `[0, len)` chosen uniformly at random, in
+/// random order.
+fn sample_indices<R: RandomSource + ?Sized>(rng: &mut R, len: usize, amount: usize) -> Vec<usize> {
+    let amount = amount.min(len);
+
+    // the Floyd's algorithm takes quadratic time in `amount`, but no memory proportional
+    // to `len`
+    if amount.saturating_mul(amount) <= len {
+        let mut indices = Vec::with_capacity(amount);
+        for j in (len - amount)..len {
+            let t = uniform_index(rng, j + 1);
+            // inserting `j` before `t` makes the order of the indices random as well
+            match indices.iter().position(|&index| index == t) {
+                Some(position) => indices.insert(position, j),
+                None => indices.push(t),
+            }
+        }
+        indices
+    } else {
+        let mut indices = (0..len).collect::<Vec<_>>();
+        indices.partial_shuffle(rng, amount);
+        indices.truncate(amount);
+        indices
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rand::{testing::*, Rng};
+
+    /// Returns the probability mass function of the minimum of `amount` distinct values
+    /// chosen uniformly in `[0, len)`.
+    fn minimum_pmf(len: u64, amount: u64) -> impl Fn(u64) -> f64 {
+        let binomial = |n: u64, k: u64| -> f64 {
+            if k > n {
+                return 0.0;
+            }
+            (0..k).map(|i| (n - i) as f64 / (i + 1) as f64).product()
+        };
+        move |m| {
+            if m >= len {
+                return 0.0;
+            }
+            binomial(len - 1 - m, amount - 1) / binomial(len, amount)
+        }
+    }
+
+    fn uniform_pmf(len: u64) -> impl Fn(u64) -> f64 {
+        move |x| if x < len { 1.0 / len as f64 } else { 0.0 }
+    }
+
+    #[test]
+    fn shuffle() {
+        // the permutations of three elements are mapped to 0..6
+        chi_squared_test(
+            |rng| {
+                let mut data = [0, 1, 2];
+                data.shuffle(rng);
+                2 * data[0] + u64::from(data[1] > data[2])
+            },
+            uniform_pmf(6),
+        );
+
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        let mut data = (0..100).collect::<Vec<_>>();
+        data.shuffle(&mut rng);
+        assert_ne!(data, (0..100).collect::<Vec<_>>());
+        data.sort();
+        assert_eq!(data, (0..100).collect::<Vec<_>>());
+
+        let mut empty: [u8; 0] = [];
+        empty.shuffle(&mut rng);
+    }
+
+    #[test]
+    fn partial_shuffle() {
+        chi_squared_test(
+            |rng| {
+                let mut data = [0, 1, 2, 3, 4, 5, 6];
+                let (chosen, _) = data.partial_shuffle(rng, 2);
+                chosen[1]
+            },
+            uniform_pmf(7),
+        );
+
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        let mut data = (0..20).collect::<Vec<_>>();
+        let (chosen, rest) = data.partial_shuffle(&mut rng, 5);
+        assert_eq!((chosen.len(), rest.len()), (5, 15));
+        data.sort();
+        assert_eq!(data, (0..20).collect::<Vec<_>>());
+
+        let (chosen, rest) = data.partial_shuffle(&mut rng, 100);
+        assert_eq!((chosen.len(), rest.len()), (20, 0));
+    }
+
+    #[test]
+    fn choose() {
+        let data = [0u64, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        chi_squared_test(|rng| *data.choose(rng).unwrap(), uniform_pmf(10));
+
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        let mut data = [0u8; 4];
+        *data.choose_mut(&mut rng).unwrap() = 1;
+        assert_eq!(data.iter().sum::<u8>(), 1);
+
+        let empty: [u8; 0] = [];
+        assert!(empty.choose(&mut rng).is_none());
+    }
+
+    #[test]
+    fn choose_multiple() {
+        let data = (0..100u64).collect::<Vec<_>>();
+
+        // Floyd's algorithm
+        chi_squared_test(
+            |rng| **data.choose_multiple(rng, 4).iter().min().unwrap(),
+            minimum_pmf(100, 4),
+        );
+        chi_squared_test(|rng| *data.choose_multiple(rng, 4)[3], uniform_pmf(100));
+
+        // partial shuffle
+        chi_squared_test(
+            |rng| **data.choose_multiple(rng, 40).iter().min().unwrap(),
+            minimum_pmf(100, 40),
+        );
+
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        for amount in [0, 1, 5, 10, 50, 100, 200] {
+            let mut chosen = data.choose_multiple(&mut rng, amount);
+            assert_eq!(chosen.len(), amount.min(100));
+            chosen.sort();
+            chosen.dedup();
+            assert_eq!(chosen.len(), amount.min(100));
+        }
+    }
+
+    #[test]
+    fn choose_weighted() {
+        let data = [(0u64, 1u32), (1, 0), (2, 3), (3, 6)];
+        chi_squared_test(
+            |rng| data.choose_weighted(rng, |item| item.1).unwrap().0,
+            |x| [0.1, 0.0, 0.3, 0.6].get(x as usize).copied().unwrap_or(0.0),
+        );
+
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        assert!(data.choose_weighted(&mut rng, |_| 0.0).is_none());
+        assert!(data[..0].choose_weighted(&mut rng, |item| item.1).is_none());
+    }
+
+    #[test]
+    fn iterator_choose() {
+        // exact size
+        chi_squared_test(|rng| (0..10).choose(rng).unwrap(), uniform_pmf(10));
+
+        // unknown size
+        chi_squared_test(
+            |rng| (0..20).filter(|x| x % 2 == 0).choose(rng).unwrap() / 2,
+            uniform_pmf(10),
+        );
+
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        assert!((0..0).choose(&mut rng).is_none());
+        assert!((0..10).filter(|&x| x > 10).choose(&mut rng).is_none());
+    }
+
+    #[test]
+    fn iterator_choose_multiple() {
+        chi_squared_test(
+            |rng| *(0..30).choose_multiple(rng, 3).iter().min().unwrap(),
+            minimum_pmf(30, 3),
+        );
+
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        assert_eq!((0..3).choose_multiple(&mut rng, 5).len(), 3);
+        let mut chosen = (0..100).choose_multiple(&mut rng, 10);
+        chosen.sort();
+        chosen.dedup();
+        assert_eq!(chosen.len(), 10);
+    }
+}
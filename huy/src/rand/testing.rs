@@ -0,0 +1,124 @@
+//! Statistical helpers shared by the tests of the distributions.
+
+use std::collections::BTreeMap;
+
+use super::Rng;
+
+/// The cumulative distribution function of the standard normal distribution.
+pub fn normal_cdf(x: f64) -> f64 {
+    0.5 * erfc(-x / core::f64::consts::SQRT_2)
+}
+
+/// Complementary error function with fractional error less than 1.2e-7.
+pub fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let poly = [
+        -1.26551223,
+        1.00002368,
+        0.37409196,
+        0.09678418,
+        -0.18628806,
+        0.27886807,
+        -1.13520398,
+        1.48851587,
+        -0.82215223,
+        0.17087277,
+    ]
+    .into_iter()
+    .rev()
+    .fold(0.0, |acc, c| acc * t + c);
+    let r = t * (-z * z + poly).exp();
+    if x >= 0.0 {
+        r
+    } else {
+        2.0 - r
+    }
+}
+
+pub fn kolmogorov_smirnov_statistic(mut data: Vec<f64>, cdf: impl Fn(f64) -> f64) -> f64 {
+    data.sort_by(f64::total_cmp);
+
+    let n = data.len() as f64;
+    data.into_iter()
+        .enumerate()
+        .map(|(i, x)| {
+            let c = cdf(x);
+            f64::max((i + 1) as f64 / n - c, c - i as f64 / n)
+        })
+        .max_by(f64::total_cmp)
+        .unwrap()
+}
+
+pub fn kolmogorov_smirnov_critical_value(sample_size: usize) -> f64 {
+    1.63 / (sample_size as f64).sqrt()
+}
+
+/// Checks that the samples follow the distribution with the given cumulative
+/// distribution function.
+pub fn kolmogorov_smirnov_test(mut sample: impl FnMut(&mut Rng) -> f64, cdf: impl Fn(f64) -> f64) {
+    let mut rng = Rng::seed_from_u64(0x5eed);
+    let sample_size = 100_000;
+    let sample = (0..sample_size).map(|_| sample(&mut rng)).collect();
+
+    let statistic = kolmogorov_smirnov_statistic(sample, cdf);
+    let critical_value = kolmogorov_smirnov_critical_value(sample_size);
+    assert!(statistic < critical_value);
+}
+
+/// Computes the mean and the unbiased variance of the samples.
+pub fn sample_mean_and_variance(mut sample: impl FnMut(&mut Rng) -> f64) -> (f64, f64) {
+    let mut rng = Rng::seed_from_u64(0x5eed);
+    let sample_size = 100_000;
+    let data = (0..sample_size)
+        .map(|_| sample(&mut rng))
+        .collect::<Vec<_>>();
+
+    let n = sample_size as f64;
+    let mean = data.iter().sum::<f64>() / n;
+    let variance = data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    (mean, variance)
+}
+
+/// Checks with the Pearson's chi-squared test that the samples follow the distribution
+/// with the given probability mass function.
+pub fn chi_squared_test(mut sample: impl FnMut(&mut Rng) -> u64, pmf: impl Fn(u64) -> f64) {
+    let mut rng = Rng::seed_from_u64(0x5eed);
+    let sample_size = 100_000;
+    let mut counts = BTreeMap::<u64, f64>::new();
+    for _ in 0..sample_size {
+        *counts.entry(sample(&mut rng)).or_default() += 1.0;
+    }
+
+    // merge the consecutive values until the expected count is large enough
+    let n = sample_size as f64;
+    let max = *counts.keys().last().unwrap();
+    let mut bins = Vec::new();
+    let (mut observed, mut expected, mut total_probability) = (0.0, 0.0, 0.0);
+    for k in 0..=max {
+        let p = pmf(k);
+        total_probability += p;
+        observed += counts.get(&k).copied().unwrap_or_default();
+        expected += n * p;
+        if expected >= 5.0 {
+            bins.push((observed, expected));
+            (observed, expected) = (0.0, 0.0);
+        }
+    }
+
+    // the remaining values, including the ones never sampled, are merged with the last bin
+    let last = bins.last_mut().unwrap();
+    last.0 += observed;
+    last.1 += expected + n * (1.0 - total_probability).max(0.0);
+
+    let statistic = bins
+        .iter()
+        .map(|(observed, expected)| (observed - expected).powi(2) / expected)
+        .sum::<f64>();
+
+    // Wilson-Hilferty approximation of the critical value at 0.01 significance level
+    let dof = (bins.len() - 1) as f64;
+    let h = 2.0 / (9.0 * dof);
+    let critical_value = dof * (1.0 - h + 2.326 * h.sqrt()).powi(3);
+    assert!(statistic < critical_value);
+}
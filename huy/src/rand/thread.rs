@@ -0,0 +1,84 @@
+//! Thread-local random number generator.
+
+use core::{cell::RefCell, marker::PhantomData};
+
+use super::{RandomSource, Rng};
+
+thread_local! {
+    static THREAD_RNG: RefCell<Rng> = RefCell::new(Rng::from_entropy());
+}
+
+/// Returns a handle to the random number generator of the current thread.
+///
+/// The generator is seeded with [`Rng::from_entropy`] the first time it is used in each
+/// thread, all the handles of the same thread share the same state.
+#[inline]
+pub fn thread_rng() -> ThreadRng {
+    ThreadRng {
+        _marker: PhantomData,
+    }
+}
+
+/// A handle to the random number generator of the current thread, see [`thread_rng`].
+///
+/// The handle cannot be sent to another thread.
+#[derive(Debug, Clone, Copy)]
+pub struct ThreadRng {
+    _marker: PhantomData<*const ()>,
+}
+
+impl RandomSource for ThreadRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        THREAD_RNG.with(|rng| rng.borrow_mut().next_u32())
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        THREAD_RNG.with(|rng| rng.borrow_mut().next_u64())
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        THREAD_RNG.with(|rng| rng.borrow_mut().fill_bytes(dest));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rand::{Random, UniformInt};
+
+    #[test]
+    fn shared_state() {
+        let mut a = thread_rng();
+        let mut b = thread_rng();
+        let values = (0..100).map(|_| a.next_u64()).collect::<Vec<_>>();
+
+        // the second handle continues the stream of the first one
+        let expected = THREAD_RNG.with(|rng| {
+            let mut rng = rng.borrow().clone();
+            (0..100).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        });
+        assert_eq!((0..100).map(|_| b.next_u64()).collect::<Vec<_>>(), expected);
+        assert_ne!(values, expected);
+    }
+
+    #[test]
+    fn distinct_threads() {
+        let values = (0..4)
+            .map(|_| std::thread::spawn(|| u64::random(&mut thread_rng())))
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>();
+        let mut distinct = values.clone();
+        distinct.sort_unstable();
+        distinct.dedup();
+        assert_eq!(distinct.len(), values.len());
+    }
+
+    #[test]
+    fn sample() {
+        let uniform = UniformInt::<u8>::new(10..20);
+        assert!((0..100).all(|_| (10..20).contains(&uniform.sample(&mut thread_rng()))));
+    }
+}
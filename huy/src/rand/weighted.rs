@@ -0,0 +1,422 @@
+//! Sample indices according to a table of weights.
+
+use super::{Distribution, Random, RandomSource, UniformInt};
+
+/// A trait for a type that can represent a weight.
+pub trait Weight: sealed::Weight {}
+
+macro_rules! impl_weight {
+    ($($ty:ty),+) => {
+        $(impl Weight for $ty {})+
+    };
+}
+
+impl_weight![i8, u8, i16, u16, i32, u32, i64, u64, isize, usize, f32, f64];
+
+/// Sample indices with probability proportional to the given weights.
+///
+/// The cumulative sums of the weights are stored, a sample requires a binary search,
+/// then it takes `O(log n)` time. The weights can be updated after the construction.
+#[derive(Clone, Debug)]
+pub struct WeightedIndex<W: Weight> {
+    weights: Vec<W>,
+    cumulative: Vec<W>,
+}
+
+impl<W: Weight> WeightedIndex<W> {
+    /// Creates a new [`WeightedIndex`] distribution from the given weights.
+    ///
+    /// Panic if the weights are empty, any weight is negative or not finite, their sum
+    /// is zero or it overflows.
+    pub fn new<I: IntoIterator<Item = W>>(weights: I) -> Self {
+        Self::try_new(weights).unwrap_or_else(|| panic!("invalid weights"))
+    }
+
+    /// Creates a new [`WeightedIndex`] distribution from the given weights.
+    ///
+    /// Returns `None` if the weights are empty, any weight is negative or not finite,
+    /// their sum is zero or it overflows.
+    pub fn try_new<I: IntoIterator<Item = W>>(weights: I) -> Option<Self> {
+        let weights = weights.into_iter().collect::<Vec<_>>();
+        let cumulative = cumulative_sum(&weights)?;
+        Some(Self {
+            weights,
+            cumulative,
+        })
+    }
+
+    /// Returns the number of weights.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.weights.len()
+    }
+
+    /// Returns `true` if there are no weights.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.weights.is_empty()
+    }
+
+    /// Returns the weights.
+    #[inline]
+    pub fn weights(&self) -> &[W] {
+        &self.weights
+    }
+
+    /// Returns the sum of the weights.
+    #[inline]
+    pub fn total_weight(&self) -> W {
+        *self.cumulative.last().unwrap()
+    }
+
+    /// Replaces the weights at the given indices, the cumulative sums are recomputed
+    /// starting from the smallest updated index.
+    ///
+    /// Panic if an index is out of bounds, or the new weights are not valid, in this case
+    /// the distribution is not modified.
+    pub fn update_weights(&mut self, updates: &[(usize, W)]) {
+        let Some(start) = updates.iter().map(|&(index, _)| index).min() else {
+            return;
+        };
+        if let Some(&(index, _)) = updates.iter().find(|&&(index, _)| index >= self.len()) {
+            panic!("index out of bounds: {index}");
+        }
+
+        let mut weights = self.weights[start..].to_vec();
+        for &(index, weight) in updates {
+            weights[index - start] = weight;
+        }
+
+        let offset = start
+            .checked_sub(1)
+            .map_or(W::ZERO, |index| self.cumulative[index]);
+        let cumulative = cumulative_sum_from(offset, &weights)
+            .filter(|cumulative| *cumulative.last().unwrap() > W::ZERO)
+            .unwrap_or_else(|| panic!("invalid weights"));
+
+        self.weights[start..].copy_from_slice(&weights);
+        self.cumulative[start..].copy_from_slice(&cumulative);
+    }
+}
+
+impl<W: Weight> Distribution<usize> for WeightedIndex<W> {
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> usize {
+        let total = self.total_weight();
+        loop {
+            let x = W::sample_below(rng, total);
+            let index = self.cumulative.partition_point(|&c| c <= x);
+            // the rounding of floats can produce the total weight itself
+            if index < self.len() {
+                return index;
+            }
+        }
+    }
+}
+
+/// Sample indices with probability proportional to the given weights.
+///
+/// The table is built with the [alias method] by Walker, in the variant of Vose, in
+/// `O(n)` time. Then a sample takes constant time, it requires a uniform index and a
+/// uniform float. The weights are converted to `f64`, and they cannot be updated.
+///
+/// [alias method]: https://doi.org/10.1109/32.92917
+#[derive(Clone, Debug)]
+pub struct AliasTable {
+    /// The probability to keep the selected index instead of its alias.
+    probability: Vec<f64>,
+    alias: Vec<usize>,
+    uniform: UniformInt<usize>,
+}
+
+impl AliasTable {
+    /// Creates a new [`AliasTable`] distribution from the given weights.
+    ///
+    /// Panic if the weights are empty, any weight is negative or not finite, their sum
+    /// is zero or it overflows.
+    pub fn new<W: Weight, I: IntoIterator<Item = W>>(weights: I) -> Self {
+        Self::try_new(weights).unwrap_or_else(|| panic!("invalid weights"))
+    }
+
+    /// Creates a new [`AliasTable`] distribution from the given weights.
+    ///
+    /// Returns `None` if the weights are empty, any weight is negative or not finite,
+    /// their sum is zero or it overflows.
+    pub fn try_new<W: Weight, I: IntoIterator<Item = W>>(weights: I) -> Option<Self> {
+        let weights = weights.into_iter().collect::<Vec<_>>();
+        let total = *cumulative_sum(&weights)?.last().unwrap();
+
+        // the weights are scaled such that their mean is one
+        let n = weights.len();
+        let scale = n as f64 / total.to_f64();
+        let mut probability = weights
+            .iter()
+            .map(|w| w.to_f64() * scale)
+            .collect::<Vec<_>>();
+        let mut alias = (0..n).collect::<Vec<_>>();
+
+        let (mut small, mut large): (Vec<_>, Vec<_>) = (0..n).partition(|&i| probability[i] < 1.0);
+        while let (Some(&less), Some(&more)) = (small.last(), large.last()) {
+            small.pop();
+            alias[less] = more;
+            probability[more] = (probability[more] + probability[less]) - 1.0;
+            if probability[more] < 1.0 {
+                large.pop();
+                small.push(more);
+            }
+        }
+
+        // the remaining entries are equal to one, up to rounding errors
+        for index in small.into_iter().chain(large) {
+            probability[index] = 1.0;
+        }
+
+        Some(Self {
+            probability,
+            alias,
+            uniform: UniformInt::new(0..n),
+        })
+    }
+
+    /// Returns the number of weights.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.probability.len()
+    }
+
+    /// Returns `true` if there are no weights.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.probability.is_empty()
+    }
+}
+
+impl Distribution<usize> for AliasTable {
+    #[inline]
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> usize {
+        let index = self.uniform.sample(rng);
+        if f64::random(rng) < self.probability[index] {
+            index
+        } else {
+            self.alias[index]
+        }
+    }
+}
+
+/// Computes the cumulative sums of the weights, `None` is returned if they are not valid.
+fn cumulative_sum<W: Weight>(weights: &[W]) -> Option<Vec<W>> {
+    cumulative_sum_from(W::ZERO, weights)
+        .filter(|cumulative| cumulative.last().is_some_and(|&total| total > W::ZERO))
+}
+
+/// Computes the cumulative sums of the weights starting from the given offset.
+fn cumulative_sum_from<W: Weight>(offset: W, weights: &[W]) -> Option<Vec<W>> {
+    let mut sum = offset;
+    weights
+        .iter()
+        .map(|&weight| {
+            if !weight.is_valid() {
+                return None;
+            }
+            sum = sum.checked_add(weight)?;
+            Some(sum)
+        })
+        .collect()
+}
+
+mod sealed {
+    use core::fmt::Debug;
+
+    use super::{Random, RandomSource, UniformInt};
+
+    pub trait Weight: Copy + Debug + PartialOrd {
+        /// The zero weight.
+        const ZERO: Self;
+
+        /// Check if the weight is non negative and finite.
+        fn is_valid(self) -> bool;
+
+        /// Computes the sum, `None` is returned if the result overflows.
+        fn checked_add(self, rhs: Self) -> Option<Self>;
+
+        /// Generate a random weight in the half-open interval `[0, total)`.
+        fn sample_below<R: RandomSource + ?Sized>(rng: &mut R, total: Self) -> Self;
+
+        /// Convert the weight to `f64`.
+        fn to_f64(self) -> f64;
+    }
+
+    macro_rules! impl_integer_weight {
+        ($ty:ty, $x:ident => $is_valid:expr) => {
+            impl Weight for $ty {
+                const ZERO: Self = 0;
+
+                #[inline]
+                fn is_valid(self) -> bool {
+                    let $x = self;
+                    $is_valid
+                }
+
+                #[inline]
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    self.checked_add(rhs)
+                }
+
+                #[inline]
+                fn sample_below<R: RandomSource + ?Sized>(rng: &mut R, total: Self) -> Self {
+                    UniformInt::new(0..total).sample(rng)
+                }
+
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        };
+    }
+
+    macro_rules! impl_signed_weight {
+        ($($ty:ty),+) => {
+            $(impl_integer_weight!($ty, x => x >= 0);)+
+        };
+    }
+
+    macro_rules! impl_unsigned_weight {
+        ($($ty:ty),+) => {
+            $(impl_integer_weight!($ty, _x => true);)+
+        };
+    }
+
+    impl_signed_weight![i8, i16, i32, i64, isize];
+    impl_unsigned_weight![u8, u16, u32, u64, usize];
+
+    macro_rules! impl_float_weight {
+        ($($ty:ty),+) => {
+            $(
+                impl Weight for $ty {
+                    const ZERO: Self = 0.0;
+
+                    #[inline]
+                    fn is_valid(self) -> bool {
+                        self >= 0.0 && self.is_finite()
+                    }
+
+                    #[inline]
+                    fn checked_add(self, rhs: Self) -> Option<Self> {
+                        let sum = self + rhs;
+                        sum.is_finite().then_some(sum)
+                    }
+
+                    #[inline]
+                    fn sample_below<R: RandomSource + ?Sized>(rng: &mut R, total: Self) -> Self {
+                        total * <$ty>::random(rng)
+                    }
+
+                    #[inline]
+                    fn to_f64(self) -> f64 {
+                        self as f64
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_float_weight![f32, f64];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rand::{testing::*, Rng};
+
+    /// Returns the probability mass function of the indices.
+    fn pmf<W: Weight>(weights: &[W]) -> impl Fn(u64) -> f64 {
+        let weights = weights.iter().map(|w| w.to_f64()).collect::<Vec<_>>();
+        let total = weights.iter().sum::<f64>();
+        move |index| weights.get(index as usize).map_or(0.0, |w| w / total)
+    }
+
+    #[test]
+    fn weighted_index() {
+        let weights = [1u32, 2, 3, 0, 4, 10];
+        let weighted = WeightedIndex::new(weights);
+        chi_squared_test(|rng| weighted.sample(rng) as u64, pmf(&weights));
+
+        let weights = [0.5, 0.0, 2.25, 1.0, 0.25];
+        let weighted = WeightedIndex::new(weights);
+        chi_squared_test(|rng| weighted.sample(rng) as u64, pmf(&weights));
+
+        assert_eq!(weighted.len(), 5);
+        assert_eq!(weighted.total_weight(), 4.0);
+        assert_eq!(weighted.weights(), &weights);
+    }
+
+    #[test]
+    fn weighted_index_update() {
+        let mut weighted = WeightedIndex::new([1i64, 2, 3, 4, 5]);
+        weighted.update_weights(&[(3, 0), (1, 10), (4, 1)]);
+        assert_eq!(weighted.weights(), &[1, 10, 3, 0, 1]);
+        assert_eq!(weighted.total_weight(), 15);
+        chi_squared_test(|rng| weighted.sample(rng) as u64, pmf(&[1, 10, 3, 0, 1]));
+
+        let mut weighted = WeightedIndex::new([1.0f32, 2.0, 3.0]);
+        weighted.update_weights(&[(2, 0.0)]);
+        weighted.update_weights(&[]);
+        chi_squared_test(|rng| weighted.sample(rng) as u64, pmf(&[1.0f32, 2.0, 0.0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid weights")]
+    fn weighted_index_invalid_update() {
+        let mut weighted = WeightedIndex::new([1u8, 2, 3]);
+        weighted.update_weights(&[(0, 0), (1, 0), (2, 0)]);
+    }
+
+    #[test]
+    fn alias_table() {
+        let weights = [1u32, 2, 3, 0, 4, 10];
+        let alias = AliasTable::new(weights);
+        chi_squared_test(|rng| alias.sample(rng) as u64, pmf(&weights));
+
+        let weights = [0.5f32, 0.0, 2.25, 1.0, 0.25];
+        let alias = AliasTable::new(weights);
+        chi_squared_test(|rng| alias.sample(rng) as u64, pmf(&weights));
+
+        let weights = (1..=100).map(|x| (x * x) as f64).collect::<Vec<_>>();
+        let alias = AliasTable::new(weights.iter().copied());
+        chi_squared_test(|rng| alias.sample(rng) as u64, pmf(&weights));
+        assert_eq!(alias.len(), 100);
+    }
+
+    #[test]
+    fn zero_weights_are_never_sampled() {
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        let weights = [0u16, 5, 0, 0, 1, 0];
+        let weighted = WeightedIndex::new(weights);
+        let alias = AliasTable::new(weights);
+        for _ in 0..10_000 {
+            assert!(weights[weighted.sample(&mut rng)] > 0);
+            assert!(weights[alias.sample(&mut rng)] > 0);
+        }
+    }
+
+    #[test]
+    fn single_weight() {
+        let mut rng = Rng::seed_from_u64(0x5eed);
+        assert_eq!(WeightedIndex::new([3u8]).sample(&mut rng), 0);
+        assert_eq!(AliasTable::new([0.5]).sample(&mut rng), 0);
+    }
+
+    #[test]
+    fn invalid_weights() {
+        assert!(WeightedIndex::<u32>::try_new([]).is_none());
+        assert!(WeightedIndex::try_new([0u32, 0]).is_none());
+        assert!(WeightedIndex::try_new([1i32, -1, 3]).is_none());
+        assert!(WeightedIndex::try_new([200u8, 100]).is_none());
+        assert!(WeightedIndex::try_new([1.0, f64::NAN]).is_none());
+        assert!(WeightedIndex::try_new([1.0, f64::INFINITY]).is_none());
+        assert!(WeightedIndex::try_new([f64::MAX, f64::MAX]).is_none());
+        assert!(AliasTable::try_new([0.0f32]).is_none());
+        assert!(AliasTable::try_new([-1.0f32, 2.0]).is_none());
+        assert!(AliasTable::try_new(Vec::<u8>::new()).is_none());
+    }
+}
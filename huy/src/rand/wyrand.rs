@@ -0,0 +1,69 @@
+use super::RandomSource;
+
+/// Random number generator implementing the WyRand algorithm.
+///
+/// It has a 64 bits state and it is one of the fastest generators available on 64-bit
+/// targets with a fast 128 bits multiplication. This implementation is the translation
+/// of the final version of the [reference implementation] by Wang Yi.
+///
+/// [reference implementation]: https://github.com/wangyi-fudan/wyhash
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WyRand {
+    state: u64,
+}
+
+impl WyRand {
+    /// Create a new PRNG using the given seed.
+    #[inline]
+    pub fn from_seed(seed: [u8; 8]) -> Self {
+        Self::seed_from_u64(u64::from_le_bytes(seed))
+    }
+
+    /// Create a new PRNG using a `u64` seed.
+    #[inline]
+    pub fn seed_from_u64(state: u64) -> Self {
+        Self { state }
+    }
+}
+
+impl RandomSource for WyRand {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x2d358dccaa6c78a5);
+        let t = u128::from(self.state) * u128::from(self.state ^ 0x8bb84b93962eacc9);
+        (t as u64) ^ (t >> 64) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wyrand_reference_implementation() {
+        let mut rng = WyRand::seed_from_u64(0);
+
+        // These values were produced using the reference implementation:
+        // https://github.com/wangyi-fudan/wyhash
+        assert_eq!(rng.next_u64(), 11116517241604665558);
+        assert_eq!(rng.next_u64(), 91298403691422709);
+        assert_eq!(rng.next_u64(), 1747996488805885078);
+        assert_eq!(rng.next_u64(), 5609826828018144761);
+        assert_eq!(rng.next_u64(), 1300770403864259764);
+        assert_eq!(rng.next_u64(), 5587870177061292450);
+        assert_eq!(rng.next_u64(), 12191985649294975902);
+        assert_eq!(rng.next_u64(), 6244844470525052898);
+        assert_eq!(rng.next_u64(), 13260212397374288549);
+        assert_eq!(rng.next_u64(), 13181932218647368960);
+    }
+
+    #[test]
+    fn fastrand() {
+        // fastrand is based on the same algorithm
+        let mut rng = WyRand::seed_from_u64(42);
+        let mut expected = fastrand::Rng::with_seed(42);
+        for _ in 0..100 {
+            assert_eq!(rng.next_u64(), expected.u64(..));
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use super::{RandomSource, SplitMix64};
+
+/// Random number generator implementing the xoshiro128++ algorithm.
+///
+/// It has a 128 bits state and it works on 32 bits words, then it is the preferred
+/// generator for 32-bit targets. This implementation is the translation of the
+/// [reference implementation] by David Blackman and Sebastiano Vigna.
+///
+/// [reference implementation]: https://prng.di.unimi.it/xoshiro128plusplus.c
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Xoshiro128PlusPlus {
+    state: [u32; 4],
+}
+
+impl Xoshiro128PlusPlus {
+    /// Create a new PRNG using the given seed.
+    pub fn from_seed(seed: [u8; 16]) -> Self {
+        if seed.iter().all(|&x| x == 0) {
+            return Self::seed_from_u64(0);
+        }
+
+        let mut state = [0; 4];
+        for (x, chunk) in state.iter_mut().zip(seed.chunks_exact(4)) {
+            *x = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Self { state }
+    }
+
+    /// Create a new PRNG using a `u64` seed.
+    pub fn seed_from_u64(state: u64) -> Self {
+        let mut splitmix = SplitMix64::seed_from_u64(state);
+        let (a, b) = (splitmix.next_u64(), splitmix.next_u64());
+        Self {
+            state: [a as u32, (a >> 32) as u32, b as u32, (b >> 32) as u32],
+        }
+    }
+}
+
+impl RandomSource for Xoshiro128PlusPlus {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        let result = self.state[0]
+            .wrapping_add(self.state[3])
+            .rotate_left(7)
+            .wrapping_add(self.state[0]);
+
+        let t = self.state[1] << 9;
+
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+
+        self.state[2] ^= t;
+
+        self.state[3] = self.state[3].rotate_left(11);
+
+        result
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let lo = u64::from(self.next_u32());
+        let hi = u64::from(self.next_u32());
+        (hi << 32) | lo
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xoshiro128plusplus_reference_implementation() {
+        #[rustfmt::skip]
+        let mut rng = Xoshiro128PlusPlus::from_seed([
+            1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0,
+        ]);
+
+        // These values were produced using the reference implementation:
+        // https://prng.di.unimi.it/xoshiro128plusplus.c
+        assert_eq!(rng.next_u32(), 641);
+        assert_eq!(rng.next_u32(), 1573767);
+        assert_eq!(rng.next_u32(), 3222811527);
+        assert_eq!(rng.next_u32(), 3517856514);
+        assert_eq!(rng.next_u32(), 836907274);
+        assert_eq!(rng.next_u32(), 4247214768);
+        assert_eq!(rng.next_u32(), 3867114732);
+        assert_eq!(rng.next_u32(), 1355841295);
+        assert_eq!(rng.next_u32(), 495546011);
+        assert_eq!(rng.next_u32(), 621204420);
+    }
+
+    #[test]
+    fn next_u64() {
+        let mut rng = Xoshiro128PlusPlus::seed_from_u64(0x5eed);
+        let mut expected = rng.clone();
+
+        let lo = u64::from(expected.next_u32());
+        let hi = u64::from(expected.next_u32());
+        assert_eq!(rng.next_u64(), (hi << 32) | lo);
+    }
+
+    #[test]
+    fn zero_seed() {
+        let rng = Xoshiro128PlusPlus::from_seed([0; 16]);
+        assert_eq!(rng, Xoshiro128PlusPlus::seed_from_u64(0));
+    }
+}
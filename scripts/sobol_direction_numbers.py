@@ -0,0 +1,181 @@
+"""Generate the direction numbers of the Sobol sequence.
+
+The first rows are the ones of Joe and Kuo, file `new-joe-kuo-6.21201`. The following
+rows use the next primitive polynomials, in the same order of Joe and Kuo, and their
+initial direction numbers are chosen among pseudo-random candidates minimizing the
+t-values of the two-dimensional projections with all the previous dimensions, for the
+first `2^m` points with `m <= 10`.
+
+Usage: python scripts/sobol_direction_numbers.py > huy/src/rand/qmc/directions.rs
+"""
+
+import sys
+
+# The number of rows, the Sobol sequence has one more dimension.
+COUNT = 336
+# The number of bits of the direction numbers used by the search.
+BITS = 12
+# The largest power of two of the number of points used by the search.
+MAX_M = 10
+# The number of candidates of each row.
+CANDIDATES = 48
+
+JOE_KUO = [
+    [1],
+    [1, 3],
+    [1, 3, 1],
+    [1, 1, 1],
+    [1, 1, 3, 3],
+    [1, 3, 5, 13],
+    [1, 1, 5, 5, 17],
+    [1, 1, 5, 5, 5],
+    [1, 1, 7, 11, 19],
+    [1, 1, 5, 1, 1],
+    [1, 1, 1, 3, 11],
+    [1, 3, 5, 5, 31],
+    [1, 3, 3, 9, 7, 49],
+    [1, 1, 1, 15, 21, 21],
+    [1, 3, 1, 13, 27, 49],
+    [1, 1, 1, 15, 7, 5],
+    [1, 3, 1, 15, 13, 25],
+    [1, 1, 5, 5, 19, 61],
+    [1, 3, 7, 11, 23, 15, 103],
+    [1, 3, 7, 13, 13, 15, 69],
+]
+
+
+def is_primitive(s, a):
+    """Check if `x^s + a_1 x^(s-1) + ... + a_(s-1) x + 1` is primitive over GF(2)."""
+    modulus = (1 << s) | (a << 1) | 1
+    order = (1 << s) - 1
+    x = 1
+    for k in range(1, order + 1):
+        x <<= 1
+        if x >> s & 1:
+            x ^= modulus
+        if x == 1:
+            return k == order
+    return False
+
+
+def primitive_polynomials(count):
+    """The first primitive polynomials, sorted by degree and then by coefficients."""
+    polynomials = []
+    s = 1
+    while len(polynomials) < count:
+        polynomials += [(s, a) for a in range(1 << (s - 1)) if is_primitive(s, a)]
+        s += 1
+    return polynomials[:count]
+
+
+def directions(s, a, m):
+    """The first direction numbers, aligned to the most significant bit."""
+    v = [0] * BITS
+    for k in range(min(s, BITS)):
+        v[k] = m[k] << (BITS - 1 - k)
+    for k in range(s, BITS):
+        d = v[k - s] ^ (v[k - s] >> s)
+        for i in range(1, s):
+            if (a >> (s - 1 - i)) & 1:
+                d ^= v[k - i]
+        v[k] = d
+    return v
+
+
+def generator_rows(v):
+    """The rows of the generator matrices with `m` columns, as bit masks, for each `m`."""
+    return [
+        [sum(((v[k] >> (BITS - 1 - i)) & 1) << k for k in range(m)) for i in range(m)]
+        for m in range(MAX_M + 1)
+    ]
+
+
+def independent(vectors):
+    """Check if the vectors are linearly independent over GF(2)."""
+    basis = []
+    for x in vectors:
+        # the basis is sorted by decreasing leading bit, so that each one is cleared once
+        for b in basis:
+            x = min(x, x ^ b)
+        if x == 0:
+            return False
+        basis.append(x)
+        basis.sort(reverse=True)
+    return True
+
+
+def t_value(rows1, rows2, m):
+    """The t-value of the two-dimensional projection of the first `2^m` points."""
+    for rho in range(m, 0, -1):
+        if all(independent(rows1[:d] + rows2[: rho - d]) for d in range(rho + 1)):
+            return m - rho
+    return m
+
+
+def splitmix64(state):
+    """The SplitMix64 pseudo-random generator."""
+    mask = (1 << 64) - 1
+    while True:
+        state = (state + 0x9E3779B97F4A7C15) & mask
+        z = state
+        z = ((z ^ (z >> 30)) * 0xBF58476D1CE4E5B9) & mask
+        z = ((z ^ (z >> 27)) * 0x94D049BB133111EB) & mask
+        yield z ^ (z >> 31)
+
+
+def search(s, a, previous, seed):
+    """Choose the initial direction numbers, returns them with the generator rows."""
+    rng = splitmix64(seed)
+    best = None
+    for _ in range(CANDIDATES):
+        m = [(next(rng) % (1 << k)) | 1 for k in range(1, s + 1)]
+        rows = generator_rows(directions(s, a, m))
+
+        # minimize the sum of the worst t-values, then the sum of all of them
+        worst, total = 0, 0
+        for size in range(1, MAX_M + 1):
+            t = [t_value(other[size], rows[size], size) for other in previous]
+            worst += max(t)
+            total += sum(t)
+            if best is not None and worst > best[0][0]:
+                break
+        else:
+            if best is None or (worst, total) < best[0]:
+                best = ((worst, total), m, rows)
+    return best[1], best[2]
+
+
+def main():
+    # the first dimension is the van der Corput sequence
+    previous = [generator_rows([1 << (BITS - 1 - k) for k in range(BITS)])]
+    table = []
+    for index, (s, a) in enumerate(primitive_polynomials(COUNT)):
+        if index < len(JOE_KUO):
+            m = JOE_KUO[index]
+            rows = generator_rows(directions(s, a, m))
+        else:
+            m, rows = search(s, a, previous, index)
+            print(f"dimension {index + 2}: {m}", file=sys.stderr, flush=True)
+        previous.append(rows)
+        table.append((s, a, m))
+
+    print("//! The direction numbers of the Sobol sequence.")
+    print("//!")
+    print("//! Generated by `scripts/sobol_direction_numbers.py`, do not edit.")
+    print()
+    print("/// The primitive polynomials and the initial direction numbers, starting from the")
+    print("/// second dimension.")
+    print("///")
+    print("/// Each row contains the degree `s` of the polynomial, the bits `a` of its inner")
+    print(f"/// coefficients and the initial direction numbers `m`. The first {len(JOE_KUO)} rows are the ones of")
+    print("/// Joe and Kuo, the following rows use the next polynomials in the same order, with")
+    print("/// initial direction numbers minimizing the t-values of the two-dimensional projections.")
+    print("#[rustfmt::skip]")
+    print("pub(super) const DIRECTION_NUMBERS: &[(usize, u32, &[u32])] = &[")
+    for s, a, m in table:
+        print(f"    ({s}, {a}, &[{', '.join(map(str, m))}]),")
+    print("];")
+
+
+if __name__ == "__main__":
+    main()